@@ -0,0 +1,61 @@
+//! Compares calling a method by `&str` name against calling it with a
+//! `LazyId`, as is common in callback heavy code.
+//!
+//! Run with `cargo bench --bench funcall`.
+
+use std::time::Instant;
+
+use magnus::{embed::init, prelude::*, value::LazyId, Error, Ruby, Value};
+
+const ITERATIONS: u32 = 1_000_000;
+
+static ON_TOKEN: LazyId = LazyId::new("on_token");
+
+fn bench<F>(name: &str, mut f: F) -> Result<(), Error>
+where
+    F: FnMut() -> Result<(), Error>,
+{
+    // warm up Ruby's method cache and the `LazyId`
+    for _ in 0..1000 {
+        f()?;
+    }
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f()?;
+    }
+    let elapsed = start.elapsed();
+    println!(
+        "{:<16} {:>10.2?} total, {:>8.1?} per call",
+        name,
+        elapsed,
+        elapsed / ITERATIONS,
+    );
+    Ok(())
+}
+
+fn run(ruby: &Ruby) -> Result<(), Error> {
+    let handler: Value = ruby.eval(
+        r#"
+        class Handler
+          def on_token(token)
+            token
+          end
+        end
+        Handler.new
+        "#,
+    )?;
+
+    bench("funcall(&str)", || {
+        handler.funcall::<_, _, Value>("on_token", (1,))?;
+        Ok(())
+    })?;
+    bench("funcall(LazyId)", || {
+        handler.funcall::<_, _, Value>(*ON_TOKEN, (1,))?;
+        Ok(())
+    })
+}
+
+fn main() {
+    let ruby = unsafe { init() };
+    run(&ruby).unwrap();
+}
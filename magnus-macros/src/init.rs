@@ -1,8 +1,13 @@
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
-use syn::{Error, ItemFn};
+use syn::{Error, Expr, ItemFn};
 
-pub fn expand(name: Option<String>, input: ItemFn) -> Result<TokenStream, Error> {
+pub fn expand(
+    name: Option<String>,
+    registry: bool,
+    panic: Option<Expr>,
+    input: ItemFn,
+) -> Result<TokenStream, Error> {
     let crate_name = match name {
         Some(v) => v,
         None => match std::env::var("CARGO_PKG_NAME") {
@@ -22,6 +27,19 @@ pub fn expand(name: Option<String>, input: ItemFn) -> Result<TokenStream, Error>
     );
     let init_name = input.sig.ident.clone();
 
+    let define_registered = if registry {
+        quote! { magnus::Ruby::define_registered.call_handle_error(); }
+    } else {
+        quote! {}
+    };
+
+    let set_panic_policy = match panic {
+        Some(policy) => quote! {
+            (|ruby: &magnus::Ruby| ruby.set_panic_policy(#policy)).call_handle_error();
+        },
+        None => quote! {},
+    };
+
     Ok(quote! {
         #input
 
@@ -29,6 +47,8 @@ pub fn expand(name: Option<String>, input: ItemFn) -> Result<TokenStream, Error>
         #[no_mangle]
         pub unsafe extern "C" fn #extern_init_name() {
             use magnus::method::{Init, RubyInit};
+            #set_panic_policy
+            #define_registered
             #init_name.call_handle_error()
         }
     })
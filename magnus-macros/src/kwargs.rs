@@ -0,0 +1,99 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{ext::IdentExt, spanned::Spanned, Data, DataStruct, DeriveInput, Error, Fields, LitStr};
+
+use crate::util;
+
+pub fn expand_derive_from_kwargs(input: DeriveInput) -> Result<TokenStream, Error> {
+    let fields = match input.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(ref fields),
+            ..
+        }) => &fields.named,
+        _ => {
+            return Err(Error::new(
+                input.span(),
+                "FromKwArgs can only be derived for structs with named fields",
+            ))
+        }
+    };
+
+    let mut required = Vec::new();
+    let mut optional = Vec::new();
+    let mut splat = None;
+    for field in fields {
+        let ident = field.ident.as_ref().unwrap();
+        let mut name = None;
+        let mut default = false;
+        let mut is_splat = false;
+        if let Some(attrs) = util::get_magnus_attrubute(&field.attrs)? {
+            attrs.parse_nested_meta(|meta| {
+                if meta.path.is_ident("name") {
+                    name = Some(meta.value()?.parse::<LitStr>()?.value());
+                    Ok(())
+                } else if meta.path.is_ident("default") {
+                    default = true;
+                    Ok(())
+                } else if meta.path.is_ident("splat") {
+                    is_splat = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported attribute"))
+                }
+            })?;
+        }
+        if is_splat {
+            if splat.is_some() {
+                return Err(Error::new(field.span(), "only one field can be `splat`"));
+            }
+            splat = Some(ident);
+            continue;
+        }
+        let name = name.unwrap_or_else(|| ident.unraw().to_string());
+        if default || util::is_option(&field.ty) {
+            optional.push((ident, name));
+        } else {
+            required.push((ident, name));
+        }
+    }
+
+    let required_names = required.iter().map(|(_, name)| name);
+    let optional_names = optional.iter().map(|(_, name)| name);
+    let required_fields = required.iter().enumerate().map(|(i, (ident, _))| {
+        quote! {
+            #ident: magnus::TryConvert::try_convert(values[#i].unwrap())?
+        }
+    });
+    let optional_fields = optional.iter().enumerate().map(|(i, (ident, _))| {
+        let i = i + required.len();
+        quote! {
+            #ident: match values[#i] {
+                Some(v) => magnus::TryConvert::try_convert(v)?,
+                None => Default::default(),
+            }
+        }
+    });
+    let splat_field = splat.map(|ident| quote! { #ident: kw, });
+    let has_splat = splat.is_some();
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics magnus::scan_args::FromKwArgs for #ident #ty_generics #where_clause {
+            fn from_kwargs(kw: magnus::RHash) -> Result<Self, magnus::Error> {
+                #[allow(unused_variables)]
+                let values = magnus::scan_args::get_kwargs_values(
+                    kw,
+                    &[#(#required_names),*],
+                    &[#(#optional_names),*],
+                    #has_splat,
+                )?;
+                Ok(Self {
+                    #(#required_fields,)*
+                    #(#optional_fields,)*
+                    #splat_field
+                })
+            }
+        }
+    })
+}
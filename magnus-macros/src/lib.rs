@@ -20,6 +20,11 @@ use proc_macro::TokenStream;
 use syn::parse_macro_input;
 
 mod init;
+mod kwargs;
+mod methods;
+mod register;
+mod ruby_error;
+mod scan_args;
 mod typed_data;
 mod util;
 
@@ -35,6 +40,14 @@ mod util;
 ///   This default's to the current crate's name. The name will be prepended
 ///   with `Init_` and `-` will be replaced with `_`. This (minus the `Init_`
 ///   prefix) must match the name of the final `.so`/`.bundle` file.
+/// * `registry` - define all classes and methods registered with the
+///   `register` attribute before running the init function. Requires the
+///   `registry` feature. See `magnus::registry`.
+/// * `panic = ...` - an expression evaluating to a
+///   `magnus::error::PanicPolicy`, set before running the init function.
+///   This configures how panics in Rust code called from Ruby are handled.
+///   The expression is evaluated with the same panic handling as the init
+///   function.
 ///
 /// # Examples
 ///
@@ -89,21 +102,212 @@ mod util;
 ///     ()
 /// }
 /// ```
+/// Setting the panic policy.
+/// ```
+/// use magnus::error::PanicPolicy;
+///
+/// const PANIC_POLICY: PanicPolicy = PanicPolicy::new()
+///     .exception_class(|ruby| ruby.exception_runtime_error())
+///     .abort_in_debug(true);
+///
+/// #[magnus::init(panic = PANIC_POLICY)]
+/// fn init() {
+///     ()
+/// }
+/// ```
 #[proc_macro_attribute]
 pub fn init(attrs: TokenStream, item: TokenStream) -> TokenStream {
     let mut name = None;
+    let mut registry = false;
+    let mut panic = None;
+    if !attrs.is_empty() {
+        let attr_parser = syn::meta::parser(|meta| {
+            if meta.path.is_ident("name") {
+                name = Some(meta.value()?.parse::<syn::LitStr>()?.value());
+                Ok(())
+            } else if meta.path.is_ident("registry") {
+                registry = true;
+                Ok(())
+            } else if meta.path.is_ident("panic") {
+                panic = Some(meta.value()?.parse::<syn::Expr>()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported attribute"))
+            }
+        });
+        parse_macro_input!(attrs with attr_parser);
+    }
+    match init::expand(name, registry, panic, parse_macro_input!(item)) {
+        Ok(tokens) => tokens,
+        Err(e) => e.into_compile_error(),
+    }
+    .into()
+}
+
+/// Generate a function to bind the methods in an `impl` block as Ruby methods.
+///
+/// Adds a `define_methods(class: &RClass) -> Result<(), Error>` associated
+/// function to the type, which defines each function in the `impl` block as a
+/// method on `class`.
+///
+/// Functions taking `self` are bound with `magnus::method!`, functions without
+/// `self` are bound with `magnus::function!`.
+/// The arity is inferred from the number of arguments, not counting `self`, a
+/// leading `&Ruby` argument, or a trailing `KwArgs<T>` argument receiving
+/// keyword arguments. A function taking a single `&[Value]` argument will be
+/// bound with an arity of `-1`.
+///
+/// Functions that do not take `self` are defined as singleton methods (i.e.
+/// class methods), so a function named `new` will be used as the constructor.
+///
+/// # Attributes
+///
+/// * `register` - register the methods to be defined on the type's
+///   `TypedData::class` automatically. Requires the `registry` feature. See
+///   `magnus::registry`.
+///
+/// # Function Attributes
+///
+/// The `#[magnus(...)]` attribute can be set on functions in the `impl` block
+/// with the following values:
+///
+/// * `name = "..."` - sets the name of the Ruby method. Defaults to the name
+///   of the Rust function.
+/// * `singleton` - define as a singleton method (i.e. a class method). This
+///   is the default for functions not taking `self`, and is not supported for
+///   functions taking `self`.
+/// * `optional` - make trailing `Option<T>` arguments optional, passing `None`
+///   when they are not given. Without this all arguments are required.
+/// * `private` - define as a private method.
+/// * `protected` - define as a protected method.
+/// * `skip` - do not define this function as a Ruby method.
+///
+/// # Examples
+///
+/// ```
+/// use magnus::{class, define_class, Error};
+///
+/// #[magnus::wrap(class = "Point", free_immediately, size)]
+/// struct Point {
+///     x: isize,
+///     y: isize,
+/// }
+///
+/// #[magnus::methods]
+/// impl Point {
+///     fn new(x: isize, y: isize) -> Self {
+///         Self { x, y }
+///     }
+///
+///     fn origin() -> Self {
+///         Self { x: 0, y: 0 }
+///     }
+///
+///     fn x(&self) -> isize {
+///         self.x
+///     }
+///
+///     fn y(&self) -> isize {
+///         self.y
+///     }
+///
+///     #[magnus(name = "distance_to")]
+///     fn distance(&self, other: &Point) -> f64 {
+///         (((other.x - self.x).pow(2) + (other.y - self.y).pow(2)) as f64).sqrt()
+///     }
+///
+///     #[magnus(skip)]
+///     fn helper(&self) {}
+/// }
+///
+/// #[magnus::init]
+/// fn init() -> Result<(), Error> {
+///     let class = define_class("Point", class::object())?;
+///     Point::define_methods(&class)?;
+///     Ok(())
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn methods(attrs: TokenStream, item: TokenStream) -> TokenStream {
+    let mut register = false;
+    if !attrs.is_empty() {
+        let attr_parser = syn::meta::parser(|meta| {
+            if meta.path.is_ident("register") {
+                register = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported attribute"))
+            }
+        });
+        parse_macro_input!(attrs with attr_parser);
+    }
+    match methods::expand(register, parse_macro_input!(item)) {
+        Ok(tokens) => tokens,
+        Err(e) => e.into_compile_error(),
+    }
+    .into()
+}
+
+/// Register a function to be defined as a Ruby function by
+/// `Ruby::define_registered`.
+///
+/// Requires the `registry` feature. See `magnus::registry`.
+///
+/// The arity is inferred from the function's arguments, as with
+/// [`macro@methods`].
+///
+/// # Attributes
+///
+/// * `name = "..."` - sets the name of the Ruby function. Defaults to the name
+///   of the Rust function.
+/// * `module = "..."` - the path of a module, e.g. `"Foo::Bar"`, to define
+///   the function on as a module function. Modules in the path are defined if
+///   needed. If the path is a class the function is defined as a singleton
+///   method. Defaults to defining a global function.
+/// * `optional` - make trailing `Option<T>` arguments optional, passing `None`
+///   when they are not given. Without this all arguments are required.
+///
+/// # Examples
+///
+/// ```
+/// #[magnus::register(module = "Euclid")]
+/// fn distance(a: (f64, f64), b: (f64, f64)) -> f64 {
+///     ((b.0 - a.0).powi(2) + (b.1 - a.1).powi(2)).sqrt()
+/// }
+///
+/// #[magnus::register(name = "hello")]
+/// fn hello_world() -> &'static str {
+///     "Hello, world!"
+/// }
+///
+/// #[magnus::init(registry)]
+/// fn init() {
+///     // `Euclid.distance` and `hello` have already been defined
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn register(attrs: TokenStream, item: TokenStream) -> TokenStream {
+    let mut name = None;
+    let mut module = None;
+    let mut optional = false;
     if !attrs.is_empty() {
         let attr_parser = syn::meta::parser(|meta| {
             if meta.path.is_ident("name") {
                 name = Some(meta.value()?.parse::<syn::LitStr>()?.value());
                 Ok(())
+            } else if meta.path.is_ident("module") {
+                module = Some(meta.value()?.parse::<syn::LitStr>()?.value());
+                Ok(())
+            } else if meta.path.is_ident("optional") {
+                optional = true;
+                Ok(())
             } else {
                 Err(meta.error("unsupported attribute"))
             }
         });
         parse_macro_input!(attrs with attr_parser);
     }
-    match init::expand(name, parse_macro_input!(item)) {
+    match register::expand(name, module, optional, parse_macro_input!(item)) {
         Ok(tokens) => tokens,
         Err(e) => e.into_compile_error(),
     }
@@ -130,6 +334,13 @@ pub fn init(attrs: TokenStream, item: TokenStream) -> TokenStream {
 ///   guaranteed to be correct for types with generics. If you are sure it is
 ///   for your type this attribute can be used to override the compile time
 ///   error usually generated for types with generics.
+/// * `parent = "..."` - path to a Rust type implementing `TypedData` that this
+///   type is a child of. The type must be `#[repr(C)]` with the parent type as
+///   its first field. See [`TypedData`] for more details.
+/// * `register` - register the class to be defined automatically. Requires
+///   the `registry` feature. See `magnus::registry`.
+/// * `superclass = "..."` - the superclass to use when the class is defined
+///   with `register`. Supports module paths. Defaults to `Object`.
 ///
 /// # Variant Attributes
 ///
@@ -246,6 +457,15 @@ pub fn derive_data_type_functions(input: TokenStream) -> TokenStream {
 ///   guaranteed to be correct for types with generics. If you are sure it is
 ///   for your type this attribute can be used to override the compile time
 ///   error usually generated for types with generics.
+/// * `parent = "..."` - path to a Rust type implementing `TypedData` that this
+///   type is a child of. Ruby objects wrapping this type will convert to a
+///   reference to the parent type, e.g. `&Parent`. The type must be
+///   `#[repr(C)]` with the parent type as its first field. The Ruby class
+///   should be a subclass of the parent's class.
+/// * `register` - register the class to be defined automatically. Requires
+///   the `registry` feature. See `magnus::registry`.
+/// * `superclass = "..."` - the superclass to use when the class is defined
+///   with `register`. Supports module paths. Defaults to `Object`.
 ///
 /// # Field Attributes
 ///
@@ -316,6 +536,51 @@ pub fn derive_data_type_functions(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// With a parent type:
+///
+/// ```
+/// use magnus::{class, define_class, function, method, prelude::*, DataTypeFunctions, TypedData};
+///
+/// #[magnus::wrap(class = "Shape")]
+/// struct Shape {
+///     name: &'static str,
+/// }
+///
+/// impl Shape {
+///     fn name(&self) -> &'static str {
+///         self.name
+///     }
+/// }
+///
+/// #[derive(DataTypeFunctions, TypedData)]
+/// #[magnus(class = "Circle", parent = "Shape")]
+/// #[repr(C)]
+/// struct Circle {
+///     shape: Shape,
+///     r: f64,
+/// }
+///
+/// impl Circle {
+///     fn new(r: f64) -> Self {
+///         Self {
+///             shape: Shape { name: "circle" },
+///             r,
+///         }
+///     }
+/// }
+///
+/// #[magnus::init]
+/// fn init() -> Result<(), magnus::Error> {
+///     let shape = define_class("Shape", class::object())?;
+///     // `Circle` objects will be converted to `&Shape` to call this method
+///     shape.define_method("name", method!(Shape::name, 0))?;
+///
+///     let circle = define_class("Circle", shape)?;
+///     circle.define_singleton_method("new", function!(Circle::new, 1))?;
+///     Ok(())
+/// }
+/// ```
+///
 /// Defining a custom `DataType` function:
 ///
 /// ```
@@ -399,3 +664,238 @@ pub fn derive_typed_data(input: TokenStream) -> TokenStream {
     }
     .into()
 }
+
+/// Derives `FromKwArgs`, allowing the type to be created from a method's
+/// keyword arguments.
+///
+/// The type can then be wrapped in `KwArgs` and used as the final argument of
+/// a function wrapped with the `method!` or `function!` macros, e.g.
+/// `kwargs: KwArgs<MyOpts>`.
+///
+/// Fields with an `Option<T>` type are optional keywords, all other fields
+/// are required. Missing required keywords, or keywords that don't match a
+/// field, raise an `ArgumentError`.
+///
+/// # Field Attributes
+///
+/// The `#[magnus(...)]` attribute can be set on struct fields with the
+/// following values:
+///
+/// * `name = "..."` - the name of the keyword. Defaults to the field name.
+/// * `default` - makes the keyword optional, using the [`Default`] value of the
+///   field's type when the keyword is not given.
+/// * `splat` - an `RHash` field that collects any keywords that don't match
+///   another field, rather than raising an error.
+///
+/// # Examples
+///
+/// ```
+/// use magnus::{define_global_function, function, scan_args::KwArgs, FromKwArgs, RHash};
+///
+/// #[derive(FromKwArgs)]
+/// struct Options {
+///     host: String,
+///     port: Option<u16>,
+///     #[magnus(name = "async", default)]
+///     is_async: bool,
+///     #[magnus(splat)]
+///     rest: RHash,
+/// }
+///
+/// fn connect(opts: KwArgs<Options>) -> String {
+///     format!("{}:{}", opts.host, opts.port.unwrap_or(80))
+/// }
+///
+/// #[magnus::init]
+/// fn init() {
+///     define_global_function("connect", function!(connect, 0));
+/// }
+/// ```
+#[proc_macro_derive(FromKwArgs, attributes(magnus))]
+pub fn derive_from_kwargs(input: TokenStream) -> TokenStream {
+    match kwargs::expand_derive_from_kwargs(parse_macro_input!(input)) {
+        Ok(tokens) => tokens,
+        Err(e) => e.into_compile_error(),
+    }
+    .into()
+}
+
+/// Derives `ScanArgs`, allowing the type to be created from a method's
+/// arguments.
+///
+/// The type can then be used as the only argument (other than `self` or
+/// `&Ruby`) of a function wrapped with the `method!` or `function!` macros
+/// with an arity of `-1`, e.g. `method!(Foo::bar, -1)`.
+///
+/// Fields describe the method's arguments in order, following Ruby's rules
+/// for argument order. Fields without an attribute are required arguments.
+/// Required arguments after an optional or splat argument are trailing
+/// required arguments. Up to 9 each of required, optional, and trailing
+/// required arguments are supported.
+///
+/// # Field Attributes
+///
+/// The `#[magnus(...)]` attribute can be set on struct fields with the
+/// following values:
+///
+/// * `required` - a required positional argument.
+/// * `optional` - an optional positional argument, using the [`Default`]
+///   value of the field's type when the argument is not given.
+/// * `default = ...` - the expression to use when an optional argument is not
+///   given. Implies `optional`.
+/// * `splat` - collects any remaining positional arguments. The field's type
+///   must be convertible from an `RArray`, e.g. `RArray` or `Vec<T>`.
+/// * `kw` - the method's keyword arguments. The field's type must implement
+///   `FromKwArgs`, e.g. `RHash` or a struct deriving `FromKwArgs`.
+/// * `block` - the method's block. Use `Option<Proc>` for an optional block.
+///
+/// # Examples
+///
+/// ```
+/// use magnus::{block::Proc, function, FromKwArgs, RArray, ScanArgs};
+///
+/// #[derive(FromKwArgs)]
+/// struct Options {
+///     timeout: Option<f64>,
+/// }
+///
+/// #[derive(ScanArgs)]
+/// struct Args {
+///     #[magnus(required)]
+///     host: String,
+///     #[magnus(optional, default = 80)]
+///     port: u16,
+///     #[magnus(splat)]
+///     rest: RArray,
+///     #[magnus(kw)]
+///     opts: Options,
+///     #[magnus(block)]
+///     block: Option<Proc>,
+/// }
+///
+/// fn connect(args: Args) -> String {
+///     format!("{}:{}", args.host, args.port)
+/// }
+///
+/// #[magnus::init]
+/// fn init() {
+///     magnus::define_global_function("connect", function!(connect, -1));
+/// }
+/// ```
+#[proc_macro_derive(ScanArgs, attributes(magnus))]
+pub fn derive_scan_args(input: TokenStream) -> TokenStream {
+    match scan_args::expand_derive_scan_args(parse_macro_input!(input)) {
+        Ok(tokens) => tokens,
+        Err(e) => e.into_compile_error(),
+    }
+    .into()
+}
+
+/// Derives `RubyError`, mapping an error enum to a hierarchy of Ruby exception
+/// classes.
+///
+/// A base exception class is defined, with a subclass for each variant, nested
+/// under the base class. `From<T> for magnus::Error` is implemented, so
+/// returning the error to Ruby raises an instance of the variant's class, with
+/// the error's [`Display`](std::fmt::Display) output as its message.
+///
+/// Variant fields are set as instance variables on the exception, with
+/// readers defined on the variant's class.
+///
+/// The exception classes are defined on first use, or can be defined ahead of
+/// time with `magnus::error::RubyError::define_exceptions`, so that they're
+/// available to Ruby code before any error has been raised. If a class of the
+/// same name already exists with a different superclass converting the error
+/// results in a `TypeError`.
+///
+/// # Panics
+///
+/// The implementation of `From<T> for magnus::Error` panics if called from a
+/// non-Ruby thread.
+///
+/// # Attributes
+///
+/// The `#[magnus(...)]` attribute can be set with the following values:
+///
+/// * `class = "..."` - required, sets the Ruby class of the base exception.
+///   Supports module paths, e.g. `Foo::Bar::Error`. Any modules in the path
+///   that don't exist will be defined.
+/// * `superclass = "..."` - the superclass of the base exception. Supports
+///   module paths. Defaults to `StandardError`.
+///
+/// # Variant Attributes
+///
+/// The `#[magnus(...)]` attribute can be set on enum variants with the
+/// following values:
+///
+/// * `class = "..."` - the name of the variant's class, defined under the base
+///   class. Defaults to the variant name.
+///
+/// # Field Attributes
+///
+/// The `#[magnus(...)]` attribute can be set on variant fields with the
+/// following values:
+///
+/// * `attr = "..."` - the name of the attribute reader for the field. Defaults
+///   to the field name. Fields of tuple variants are only exposed when this is
+///   set.
+/// * `skip` - don't expose the field. Fields must implement `IntoValue` unless
+///   skipped.
+///
+/// # Examples
+///
+/// ```
+/// use std::fmt;
+///
+/// use magnus::{error::RubyError, function, Error, Ruby};
+///
+/// #[derive(Debug, magnus::RubyError)]
+/// #[magnus(class = "Parser::ParseError")]
+/// enum ParseError {
+///     UnexpectedToken {
+///         token: String,
+///         line: usize,
+///     },
+///     #[magnus(class = "EndOfInput")]
+///     Eof,
+///     Io(#[magnus(skip)] std::io::Error),
+/// }
+///
+/// impl fmt::Display for ParseError {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         match self {
+///             Self::UnexpectedToken { token, line } => {
+///                 write!(f, "unexpected {} on line {}", token, line)
+///             }
+///             Self::Eof => write!(f, "unexpected end of input"),
+///             Self::Io(e) => write!(f, "{}", e),
+///         }
+///     }
+/// }
+///
+/// fn parse(src: String) -> Result<(), Error> {
+///     if src.is_empty() {
+///         return Err(ParseError::Eof.into());
+///     }
+///     Err(ParseError::UnexpectedToken {
+///         token: src,
+///         line: 1,
+///     }
+///     .into())
+/// }
+///
+/// #[magnus::init]
+/// fn init(ruby: &Ruby) -> Result<(), Error> {
+///     ParseError::define_exceptions(ruby)?;
+///     ruby.define_global_function("parse", function!(parse, 1));
+///     Ok(())
+/// }
+/// ```
+#[proc_macro_derive(RubyError, attributes(magnus))]
+pub fn derive_ruby_error(input: TokenStream) -> TokenStream {
+    match ruby_error::expand_derive_ruby_error(parse_macro_input!(input)) {
+        Ok(tokens) => tokens,
+        Err(e) => e.into_compile_error(),
+    }
+    .into()
+}
@@ -0,0 +1,207 @@
+use proc_macro2::{Literal, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{
+    spanned::Spanned, Error, FnArg, ImplItem, ImplItemFn, ItemImpl, LitStr, Type, TypePath,
+    TypeReference,
+};
+
+use crate::util;
+
+enum Visibility {
+    Public,
+    Private,
+    Protected,
+}
+
+pub fn expand(register: bool, mut input: ItemImpl) -> Result<TokenStream, Error> {
+    if let Some((_, path, _)) = &input.trait_ {
+        return Err(Error::new_spanned(
+            path,
+            "#[magnus::methods] is not supported on trait implementations",
+        ));
+    }
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "#[magnus::methods] is not supported on generic impl blocks",
+        ));
+    }
+    let self_ty = input.self_ty.clone();
+
+    let mut defs = Vec::new();
+    for item in input.items.iter_mut() {
+        let func = match item {
+            ImplItem::Fn(func) => func,
+            _ => continue,
+        };
+        if let Some(def) = method_def(&self_ty, func)? {
+            defs.push(def);
+        }
+        func.attrs.retain(|attr| !attr.path().is_ident("magnus"));
+    }
+
+    let registration = if register {
+        let name = self_ty.to_token_stream().to_string();
+        quote! {
+            magnus::registry::inventory::submit! {
+                magnus::registry::Registration::methods(#name, |ruby| {
+                    <#self_ty>::define_methods(
+                        &magnus::registry::typed_data_class::<#self_ty>(ruby)?,
+                    )
+                })
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    Ok(quote! {
+        #input
+
+        #registration
+
+        impl #self_ty {
+            /// Define the methods from the `#[magnus::methods]` `impl` block
+            /// on `class`.
+            pub fn define_methods(class: &magnus::RClass) -> Result<(), magnus::Error> {
+                #(#defs)*
+                Ok(())
+            }
+        }
+    })
+}
+
+fn method_def(self_ty: &Type, func: &ImplItemFn) -> Result<Option<TokenStream>, Error> {
+    let ident = &func.sig.ident;
+    let mut name = None;
+    let mut singleton = false;
+    let mut optional = false;
+    let mut visibility = Visibility::Public;
+    if let Some(attrs) = util::get_magnus_attrubute(&func.attrs)? {
+        let mut skip = false;
+        attrs.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                name = Some(meta.value()?.parse::<LitStr>()?.value());
+                Ok(())
+            } else if meta.path.is_ident("singleton") {
+                singleton = true;
+                Ok(())
+            } else if meta.path.is_ident("optional") {
+                optional = true;
+                Ok(())
+            } else if meta.path.is_ident("private") {
+                visibility = Visibility::Private;
+                Ok(())
+            } else if meta.path.is_ident("protected") {
+                visibility = Visibility::Protected;
+                Ok(())
+            } else if meta.path.is_ident("skip") {
+                skip = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported attribute"))
+            }
+        })?;
+        if skip {
+            return Ok(None);
+        }
+    }
+    let name = name.unwrap_or_else(|| ident.to_string());
+
+    let mut inputs = func.sig.inputs.iter().peekable();
+    let receiver = match inputs.peek() {
+        Some(FnArg::Receiver(_)) => inputs.next(),
+        _ => None,
+    };
+    // `&Ruby` can only come before any other arguments, so is not supported
+    // along with `self`
+    if receiver.is_none()
+        && matches!(inputs.peek(), Some(FnArg::Typed(arg)) if is_ruby_ref(&arg.ty))
+    {
+        inputs.next();
+    }
+    let args = inputs.collect::<Vec<_>>();
+    let arity = arity(&args, optional);
+
+    // associated functions without `self` can only be class methods
+    let singleton = singleton || receiver.is_none();
+    if singleton {
+        if let Some(receiver) = receiver {
+            return Err(Error::new(
+                receiver.span(),
+                "singleton methods can not take `self`",
+            ));
+        }
+        if !matches!(visibility, Visibility::Public) {
+            return Err(Error::new(
+                func.sig.span(),
+                "singleton methods can not be private or protected",
+            ));
+        }
+    }
+
+    let wrapper = if receiver.is_some() {
+        quote! { magnus::method!(<#self_ty>::#ident, #arity) }
+    } else {
+        quote! { magnus::function!(<#self_ty>::#ident, #arity) }
+    };
+    let define = if singleton {
+        quote! { magnus::Object::define_singleton_method }
+    } else {
+        match visibility {
+            Visibility::Public => quote! { magnus::Module::define_method },
+            Visibility::Private => quote! { magnus::Module::define_private_method },
+            Visibility::Protected => quote! { magnus::Module::define_protected_method },
+        }
+    };
+    Ok(Some(quote! {
+        #define(class, #name, #wrapper)?;
+    }))
+}
+
+/// Infers the arity for the arguments of a function, not including `self`, a
+/// leading `&Ruby`, or trailing `KwArgs`.
+///
+/// If `optional` is set trailing `Option<T>` arguments are optional, giving a
+/// range arity.
+pub fn arity(args: &[&FnArg], optional: bool) -> TokenStream {
+    let args = match args.split_last() {
+        Some((FnArg::Typed(arg), rest)) if util::is_kwargs(&arg.ty) => rest,
+        _ => args,
+    };
+    let optional = if optional {
+        args.iter()
+            .rev()
+            .take_while(|arg| matches!(arg, FnArg::Typed(arg) if util::is_option(&arg.ty)))
+            .count()
+    } else {
+        0
+    };
+    match args {
+        [FnArg::Typed(arg)] if is_slice_ref(&arg.ty) => quote! { -1 },
+        _ if optional > 0 => {
+            let min = Literal::usize_unsuffixed(args.len() - optional);
+            let max = Literal::usize_unsuffixed(args.len());
+            quote! { #min..=#max }
+        }
+        _ => Literal::usize_unsuffixed(args.len()).into_token_stream(),
+    }
+}
+
+pub fn is_ruby_ref(ty: &Type) -> bool {
+    match ty {
+        Type::Reference(TypeReference { elem, .. }) => match elem.as_ref() {
+            Type::Path(TypePath { path, .. }) => path
+                .segments
+                .last()
+                .map(|segment| segment.ident == "Ruby")
+                .unwrap_or(false),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+fn is_slice_ref(ty: &Type) -> bool {
+    matches!(ty, Type::Reference(TypeReference { elem, .. }) if matches!(elem.as_ref(), Type::Slice(_)))
+}
@@ -0,0 +1,56 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Error, FnArg, ItemFn};
+
+use crate::methods;
+
+pub fn expand(
+    name: Option<String>,
+    module: Option<String>,
+    optional: bool,
+    input: ItemFn,
+) -> Result<TokenStream, Error> {
+    if let Some(receiver) = input.sig.receiver() {
+        return Err(Error::new_spanned(
+            receiver,
+            "#[magnus::register] is not supported on methods, see #[magnus::methods]",
+        ));
+    }
+    if !input.sig.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.sig.generics,
+            "#[magnus::register] is not supported on generic functions",
+        ));
+    }
+
+    let ident = &input.sig.ident;
+    let name = name.unwrap_or_else(|| ident.to_string());
+    let mut inputs = input.sig.inputs.iter().peekable();
+    if matches!(inputs.peek(), Some(FnArg::Typed(arg)) if methods::is_ruby_ref(&arg.ty)) {
+        inputs.next();
+    }
+    let arity = methods::arity(&inputs.collect::<Vec<_>>(), optional);
+
+    let (description, module) = match module {
+        Some(module) => (
+            format!("{}.{}", module, name),
+            quote! { ::std::option::Option::Some(#module) },
+        ),
+        None => (name.clone(), quote! { ::std::option::Option::None }),
+    };
+
+    Ok(quote! {
+        #input
+
+        magnus::registry::inventory::submit! {
+            magnus::registry::Registration::function(#description, |ruby| {
+                magnus::registry::define_function(
+                    ruby,
+                    #module,
+                    #name,
+                    magnus::function!(#ident, #arity),
+                )
+            })
+        }
+    })
+}
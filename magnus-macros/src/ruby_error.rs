@@ -0,0 +1,196 @@
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::{ext::IdentExt, spanned::Spanned, Data, DataEnum, DeriveInput, Error, Fields, LitStr};
+
+use crate::util;
+
+pub fn expand_derive_ruby_error(input: DeriveInput) -> Result<TokenStream, Error> {
+    let variants = match input.data {
+        Data::Enum(DataEnum { ref variants, .. }) => variants,
+        _ => {
+            return Err(Error::new(
+                input.span(),
+                "RubyError can only be derived for enums",
+            ))
+        }
+    };
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "RubyError can not be derived for types with generics",
+        ));
+    }
+
+    let attrs = match util::get_magnus_attrubute(&input.attrs)? {
+        Some(v) => v,
+        None => return Err(Error::new(input.span(), "missing #[magnus] attribute")),
+    };
+    let mut class = None;
+    let mut superclass = None;
+    attrs.parse_nested_meta(|meta| {
+        if meta.path.is_ident("class") {
+            class = Some(meta.value()?.parse::<LitStr>()?.value());
+            Ok(())
+        } else if meta.path.is_ident("superclass") {
+            superclass = Some(meta.value()?.parse::<LitStr>()?.value());
+            Ok(())
+        } else {
+            Err(meta.error("unsupported attribute"))
+        }
+    })?;
+    let class = match class {
+        Some(v) => v,
+        None => return Err(Error::new(attrs.span(), "missing attribute: `class = ...`")),
+    };
+    let superclass = superclass.unwrap_or_else(|| String::from("StandardError"));
+
+    let ident = &input.ident;
+    let mut defines = Vec::new();
+    let mut class_arms = Vec::new();
+    let mut ivar_arms = Vec::new();
+    for variant in variants {
+        let mut name = None;
+        if let Some(attrs) = util::get_magnus_attrubute(&variant.attrs)? {
+            attrs.parse_nested_meta(|meta| {
+                if meta.path.is_ident("class") {
+                    name = Some(meta.value()?.parse::<LitStr>()?.value());
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported attribute"))
+                }
+            })?;
+        }
+        let path = format!(
+            "{}::{}",
+            class,
+            name.unwrap_or_else(|| variant.ident.unraw().to_string())
+        );
+
+        let mut attr_names = Vec::new();
+        let mut ivars = Vec::new();
+        let mut patterns = Vec::new();
+        for (i, field) in variant.fields.iter().enumerate() {
+            let mut name = None;
+            let mut skip = false;
+            if let Some(attrs) = util::get_magnus_attrubute(&field.attrs)? {
+                attrs.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("attr") {
+                        name = Some(meta.value()?.parse::<LitStr>()?.value());
+                        Ok(())
+                    } else if meta.path.is_ident("skip") {
+                        skip = true;
+                        Ok(())
+                    } else {
+                        Err(meta.error("unsupported attribute"))
+                    }
+                })?;
+            }
+            let name = match (skip, name, &field.ident) {
+                (true, _, _) => None,
+                (false, Some(name), _) => Some(name),
+                (false, None, Some(ident)) => Some(ident.unraw().to_string()),
+                (false, None, None) => None,
+            };
+            let binding = Ident::new(&format!("field_{}", i), Span::call_site());
+            match (name, &field.ident) {
+                (Some(name), Some(ident)) => {
+                    patterns.push(quote! { #ident: #binding });
+                    ivars.push((format!("@{}", name), binding));
+                    attr_names.push(name);
+                }
+                (Some(name), None) => {
+                    patterns.push(quote! { #binding });
+                    ivars.push((format!("@{}", name), binding));
+                    attr_names.push(name);
+                }
+                (None, Some(_)) => (),
+                (None, None) => patterns.push(quote! { _ }),
+            }
+        }
+
+        let define = quote! {
+            magnus::error::define_error_class(ruby, #path, #class, &[#(#attr_names),*])
+        };
+        defines.push(quote! { #define?; });
+
+        let variant_ident = &variant.ident;
+        let (any, pattern) = match variant.fields {
+            Fields::Named(_) => (
+                quote! { #ident::#variant_ident { .. } },
+                quote! { #ident::#variant_ident { #(#patterns,)* .. } },
+            ),
+            Fields::Unnamed(_) => (
+                quote! { #ident::#variant_ident(..) },
+                quote! { #ident::#variant_ident(#(#patterns),*) },
+            ),
+            Fields::Unit => (
+                quote! { #ident::#variant_ident },
+                quote! { #ident::#variant_ident },
+            ),
+        };
+        class_arms.push(quote! {
+            #any => {
+                static CLASS: magnus::error::ErrorClassCell =
+                    magnus::error::ErrorClassCell::new();
+                CLASS.get_or_try_init(ruby, |ruby| #define)
+            }
+        });
+        let ivar_sets = ivars.iter().map(|(name, binding)| {
+            quote! { .and_then(|_| magnus::Object::ivar_set(ex, #name, #binding)) }
+        });
+        ivar_arms.push(quote! {
+            #pattern => Ok(())#(#ivar_sets)*
+        });
+    }
+
+    Ok(quote! {
+        impl magnus::error::RubyError for #ident {
+            fn define_exceptions(ruby: &magnus::Ruby) -> Result<(), magnus::Error> {
+                magnus::error::define_error_class(ruby, #class, #superclass, &[])?;
+                #(#defines)*
+                Ok(())
+            }
+
+            fn base_class(
+                ruby: &magnus::Ruby,
+            ) -> Result<magnus::ExceptionClass, magnus::Error> {
+                static CLASS: magnus::error::ErrorClassCell =
+                    magnus::error::ErrorClassCell::new();
+                CLASS.get_or_try_init(ruby, |ruby| {
+                    magnus::error::define_error_class(ruby, #class, #superclass, &[])
+                })
+            }
+
+            fn exception_class(
+                &self,
+                ruby: &magnus::Ruby,
+            ) -> Result<magnus::ExceptionClass, magnus::Error> {
+                Self::base_class(ruby)?;
+                match self {
+                    #(#class_arms)*
+                }
+            }
+        }
+
+        impl From<#ident> for magnus::Error {
+            fn from(err: #ident) -> Self {
+                let ruby = magnus::Ruby::get().unwrap();
+                let class = match magnus::error::RubyError::exception_class(&err, &ruby) {
+                    Ok(class) => class,
+                    Err(e) => return e,
+                };
+                let ex = match magnus::Class::new_instance(class, (err.to_string(),)) {
+                    Ok(ex) => ex,
+                    Err(e) => return e,
+                };
+                let res: Result<(), magnus::Error> = match err {
+                    #(#ivar_arms,)*
+                };
+                match res {
+                    Ok(()) => ex.into(),
+                    Err(e) => e,
+                }
+            }
+        }
+    })
+}
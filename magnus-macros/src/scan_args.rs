@@ -0,0 +1,205 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{spanned::Spanned, Data, DataStruct, DeriveInput, Error, Expr, Fields, Ident};
+
+use crate::util;
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Position {
+    Required,
+    Optional,
+    Splat,
+    Trailing,
+    Keywords,
+    Block,
+}
+
+impl Position {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Required => "required",
+            Self::Optional => "optional",
+            Self::Splat => "splat",
+            Self::Trailing => "trailing required",
+            Self::Keywords => "kw",
+            Self::Block => "block",
+        }
+    }
+}
+
+pub fn expand_derive_scan_args(input: DeriveInput) -> Result<TokenStream, Error> {
+    let fields = match input.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(ref fields),
+            ..
+        }) => &fields.named,
+        _ => {
+            return Err(Error::new(
+                input.span(),
+                "ScanArgs can only be derived for structs with named fields",
+            ))
+        }
+    };
+
+    let mut required = Vec::<&Ident>::new();
+    let mut optional = Vec::<(&Ident, Option<Expr>)>::new();
+    let mut splat = None;
+    let mut trailing = Vec::<&Ident>::new();
+    let mut keywords = None;
+    let mut block = None;
+    let mut last = Position::Required;
+    for field in fields {
+        let ident = field.ident.as_ref().unwrap();
+        let mut position = None;
+        let mut default = None;
+        if let Some(attrs) = util::get_magnus_attrubute(&field.attrs)? {
+            attrs.parse_nested_meta(|meta| {
+                let set = if meta.path.is_ident("required") {
+                    Position::Required
+                } else if meta.path.is_ident("optional") {
+                    Position::Optional
+                } else if meta.path.is_ident("default") {
+                    default = Some(meta.value()?.parse::<Expr>()?);
+                    return Ok(());
+                } else if meta.path.is_ident("splat") {
+                    Position::Splat
+                } else if meta.path.is_ident("kw") {
+                    Position::Keywords
+                } else if meta.path.is_ident("block") {
+                    Position::Block
+                } else {
+                    return Err(meta.error("unsupported attribute"));
+                };
+                if position.replace(set).is_some() {
+                    return Err(meta.error("conflicting attributes"));
+                }
+                Ok(())
+            })?;
+        }
+        let mut position = match (position, &default) {
+            (None, Some(_)) => Position::Optional,
+            (None, None) => Position::Required,
+            (Some(Position::Optional), _) => Position::Optional,
+            (Some(_), Some(default)) => {
+                return Err(Error::new(
+                    default.span(),
+                    "`default` can only be used with optional arguments",
+                ))
+            }
+            (Some(position), None) => position,
+        };
+        if position == Position::Required && last > Position::Required {
+            position = Position::Trailing;
+        }
+        let repeated = position == last
+            && matches!(
+                position,
+                Position::Splat | Position::Keywords | Position::Block
+            );
+        if position < last || repeated {
+            return Err(Error::new(
+                field.span(),
+                format!(
+                    "{} argument can not follow {} argument",
+                    position.name(),
+                    last.name()
+                ),
+            ));
+        }
+        last = position;
+        match position {
+            Position::Required => required.push(ident),
+            Position::Optional => optional.push((ident, default)),
+            Position::Splat => splat = Some(ident),
+            Position::Trailing => trailing.push(ident),
+            Position::Keywords => keywords = Some(ident),
+            Position::Block => block = Some(ident),
+        }
+    }
+    for (len, position) in [
+        (required.len(), Position::Required),
+        (optional.len(), Position::Optional),
+        (trailing.len(), Position::Trailing),
+    ] {
+        if len > 9 {
+            return Err(Error::new(
+                input.ident.span(),
+                format!(
+                    "at most 9 {} arguments are supported, got {}",
+                    position.name(),
+                    len
+                ),
+            ));
+        }
+    }
+
+    let required_len = required.len();
+    let optional_len = optional.len();
+    let trailing_len = trailing.len();
+    let has_splat = splat.is_some();
+    let has_keywords = keywords.is_some();
+    let has_block = block.is_some();
+
+    let required_fields = required.iter().enumerate().map(|(i, ident)| {
+        quote! {
+            #ident: magnus::TryConvert::try_convert(args.required[#i])?
+        }
+    });
+    let optional_fields = optional.iter().enumerate().map(|(i, (ident, default))| {
+        let default = match default {
+            Some(default) => quote! { #default },
+            None => quote! { Default::default() },
+        };
+        quote! {
+            #ident: match args.optional[#i] {
+                Some(v) => magnus::TryConvert::try_convert(v)?,
+                None => #default,
+            }
+        }
+    });
+    let splat_field = splat.map(|ident| {
+        quote! { #ident: magnus::TryConvert::try_convert(args.splat)?, }
+    });
+    let trailing_fields = trailing.iter().enumerate().map(|(i, ident)| {
+        quote! {
+            #ident: magnus::TryConvert::try_convert(args.trailing[#i])?
+        }
+    });
+    let keywords_field = keywords.map(|ident| {
+        quote! { #ident: magnus::scan_args::FromKwArgs::from_kwargs(args.keywords)?, }
+    });
+    let block_field = block.map(|ident| {
+        quote! { #ident: magnus::TryConvert::try_convert(args.block)?, }
+    });
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics magnus::scan_args::ScanArgs for #ident #ty_generics #where_clause {
+            fn from_args(
+                ruby: &magnus::Ruby,
+                args: &[magnus::Value],
+            ) -> Result<Self, magnus::Error> {
+                #[allow(unused_variables)]
+                let args = magnus::scan_args::scan_args_values(
+                    ruby,
+                    args,
+                    #required_len,
+                    #optional_len,
+                    #has_splat,
+                    #trailing_len,
+                    #has_keywords,
+                    #has_block,
+                )?;
+                Ok(Self {
+                    #(#required_fields,)*
+                    #(#optional_fields,)*
+                    #splat_field
+                    #(#trailing_fields,)*
+                    #keywords_field
+                    #block_field
+                })
+            }
+        }
+    })
+}
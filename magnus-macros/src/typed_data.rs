@@ -1,7 +1,8 @@
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 use syn::{
-    spanned::Spanned, Data, DataEnum, DataStruct, DeriveInput, Error, Fields, FieldsNamed, LitStr,
+    spanned::Spanned, token::Paren, Data, DataEnum, DataStruct, DeriveInput, Error, Fields,
+    FieldsNamed, LitStr, Member, Path,
 };
 
 use crate::util;
@@ -37,11 +38,17 @@ pub fn expand_derive_typed_data(input: DeriveInput) -> Result<TokenStream, Error
     let mut wb_protected = false;
     let mut frozen_shareable = false;
     let mut unsafe_generics = false;
+    let mut parent = None;
+    let mut register = false;
+    let mut superclass = None;
 
     attrs.parse_nested_meta(|meta| {
         if meta.path.is_ident("class") {
             class = Some(meta.value()?.parse::<LitStr>()?.value());
             Ok(())
+        } else if meta.path.is_ident("parent") {
+            parent = Some(meta.value()?.parse::<LitStr>()?.parse::<Path>()?);
+            Ok(())
         } else if meta.path.is_ident("name") {
             name = Some(meta.value()?.parse::<LitStr>()?.value());
             Ok(())
@@ -66,6 +73,12 @@ pub fn expand_derive_typed_data(input: DeriveInput) -> Result<TokenStream, Error
         } else if meta.path.is_ident("unsafe_generics") {
             unsafe_generics = true;
             Ok(())
+        } else if meta.path.is_ident("register") {
+            register = true;
+            Ok(())
+        } else if meta.path.is_ident("superclass") {
+            superclass = Some(meta.value()?.parse::<LitStr>()?.value());
+            Ok(())
         } else if meta.path.is_ident("free_immediatly") {
             Err(meta.error("unsupported attribute (use free_immediately)"))
         } else {
@@ -95,9 +108,88 @@ pub fn expand_derive_typed_data(input: DeriveInput) -> Result<TokenStream, Error
     };
     let name = name.unwrap_or_else(|| class.clone());
 
+    let registration = if register {
+        if !input.generics.params.is_empty() {
+            return Err(Error::new_spanned(
+                &input.generics,
+                "`register` is not supported for types with generics",
+            ));
+        }
+        let superclass = match superclass {
+            Some(ref v) => quote! { Some(#v) },
+            None => quote! { None },
+        };
+        quote! {
+            magnus::registry::inventory::submit! {
+                magnus::registry::Registration::class(#class, #superclass)
+            }
+        }
+    } else if superclass.is_some() {
+        return Err(Error::new(
+            attrs.span(),
+            "`superclass = ...` requires the `register` attribute",
+        ));
+    } else {
+        quote! {}
+    };
+
     let ident = &input.ident;
     let generics = &input.generics;
 
+    let parent_check = match parent {
+        Some(ref parent) => {
+            let first = match input.data {
+                Data::Struct(DataStruct { ref fields, .. }) => match fields.iter().next() {
+                    Some(field) => match field.ident {
+                        Some(ref ident) => Member::Named(ident.clone()),
+                        None => Member::Unnamed(0.into()),
+                    },
+                    None => {
+                        return Err(Error::new_spanned(
+                            parent,
+                            "`parent` requires the parent type as the first field",
+                        ))
+                    }
+                },
+                _ => {
+                    return Err(Error::new_spanned(
+                        parent,
+                        "`parent` is only supported for structs",
+                    ))
+                }
+            };
+            let mut repr_c = false;
+            for attr in input.attrs.iter().filter(|a| a.path().is_ident("repr")) {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("C") || meta.path.is_ident("transparent") {
+                        repr_c = true;
+                    }
+                    if meta.input.peek(Paren) {
+                        let _content;
+                        syn::parenthesized!(_content in meta.input);
+                    }
+                    Ok(())
+                })?;
+            }
+            if !repr_c {
+                return Err(Error::new_spanned(
+                    parent,
+                    "`parent` requires the type to be `#[repr(C)]` or `#[repr(transparent)]`",
+                ));
+            }
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+            quote! {
+                const _: () = {
+                    #[allow(dead_code)]
+                    fn parent_is_first_field #impl_generics (child: &#ident #ty_generics) #where_clause {
+                        let _: *const #parent = std::ptr::addr_of!(child.#first);
+                    }
+                };
+            }
+        }
+        None => quote! {},
+    };
+
     let mut arms = Vec::new();
     if let Data::Enum(DataEnum { ref variants, .. }) = input.data {
         for variant in variants.into_iter() {
@@ -213,11 +305,30 @@ pub fn expand_derive_typed_data(input: DeriveInput) -> Result<TokenStream, Error
     if frozen_shareable {
         builder.push(quote! { .frozen_shareable() });
     }
+    if let Some(ref parent) = parent {
+        builder.push(quote! { .parent(<#parent as magnus::TypedData>::data_type()) });
+    }
     builder.push(quote! { .build() });
     let builder = builder.into_iter().collect::<TokenStream>();
+    let data_type = if parent.is_some() {
+        quote! {
+            static DATA_TYPE: magnus::typed_data::LazyDataType =
+                magnus::typed_data::LazyDataType::new(|| #builder);
+            DATA_TYPE.get()
+        }
+    } else {
+        quote! {
+            static DATA_TYPE: magnus::DataType = #builder;
+            &DATA_TYPE
+        }
+    };
     let tokens = quote! {
         #accessor_impl
 
+        #parent_check
+
+        #registration
+
         unsafe impl #generics magnus::TypedData for #ident #generics {
             fn class(ruby: &magnus::Ruby) -> magnus::RClass {
                 use magnus::{class, Module, Class, RClass, value::{Lazy, ReprValue}};
@@ -230,8 +341,11 @@ pub fn expand_derive_typed_data(input: DeriveInput) -> Result<TokenStream, Error
             }
 
             fn data_type() -> &'static magnus::DataType {
-                static DATA_TYPE: magnus::DataType = #builder;
-                &DATA_TYPE
+                #data_type
+            }
+
+            fn class_path() -> Option<&'static str> {
+                Some(#class)
             }
 
             #class_for
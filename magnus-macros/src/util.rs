@@ -1,4 +1,4 @@
-use syn::{spanned::Spanned, Attribute, Error};
+use syn::{spanned::Spanned, Attribute, Error, PathArguments, Type, TypePath};
 
 pub fn get_magnus_attrubute(attrs: &[Attribute]) -> Result<Option<&Attribute>, Error> {
     let attrs = attrs
@@ -19,3 +19,25 @@ pub fn get_magnus_attrubute(attrs: &[Attribute]) -> Result<Option<&Attribute>, E
     }
     Ok(Some(attrs[0]))
 }
+
+pub fn is_option(ty: &Type) -> bool {
+    is_generic(ty, "Option")
+}
+
+pub fn is_kwargs(ty: &Type) -> bool {
+    is_generic(ty, "KwArgs")
+}
+
+fn is_generic(ty: &Type, name: &str) -> bool {
+    match ty {
+        Type::Path(TypePath { qself: None, path }) => path
+            .segments
+            .last()
+            .map(|segment| {
+                segment.ident == name
+                    && matches!(segment.arguments, PathArguments::AngleBracketed(_))
+            })
+            .unwrap_or(false),
+        _ => false,
+    }
+}
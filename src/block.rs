@@ -11,10 +11,11 @@ use std::{
 
 use rb_sys::{
     rb_block_given_p, rb_block_proc, rb_data_typed_object_wrap, rb_obj_is_proc, rb_proc_arity,
-    rb_proc_call, rb_proc_lambda_p, rb_proc_new, rb_yield, rb_yield_splat, rb_yield_values2, VALUE,
+    rb_proc_lambda_p, rb_proc_new, rb_yield, rb_yield_splat, VALUE,
 };
 
 use crate::{
+    compat::{rb_proc_call_kw, rb_yield_values_kw},
     data_type_builder,
     enumerator::Enumerator,
     error::{ensure, protect, Error},
@@ -324,10 +325,17 @@ impl Proc {
         A: RArrayArgList,
         T: TryConvert,
     {
+        let kw_splat = RArrayArgList::contains_kw_args(&args) as c_int;
         let args = args.into_array_arg_list_with(&Ruby::get_with(self));
         unsafe {
-            protect(|| Value::new(rb_proc_call(self.as_rb_value(), args.as_rb_value())))
-                .and_then(TryConvert::try_convert)
+            protect(|| {
+                Value::new(rb_proc_call_kw(
+                    self.as_rb_value(),
+                    args.as_rb_value(),
+                    kw_splat,
+                ))
+            })
+            .and_then(TryConvert::try_convert)
         }
     }
 
@@ -634,13 +642,15 @@ impl Ruby {
         T: ArgList,
         U: TryConvert,
     {
+        let kw_splat = ArgList::contains_kw_args(&vals) as c_int;
         let vals = vals.into_arg_list_with(self);
         let slice = vals.as_ref();
         unsafe {
             protect(|| {
-                Value::new(rb_yield_values2(
+                Value::new(rb_yield_values_kw(
                     slice.len() as c_int,
                     slice.as_ptr() as *const VALUE,
+                    kw_splat,
                 ))
             })
             .and_then(TryConvert::try_convert)
@@ -965,9 +975,14 @@ where
     ensure(
         || {
             for val in &mut *ptr {
+                let kw_splat = ArgList::contains_kw_args(&val) as c_int;
                 let vals = val.into_arg_list_with(&handle);
                 let slice = vals.as_ref();
-                rb_yield_values2(slice.len() as c_int, slice.as_ptr() as *const VALUE);
+                rb_yield_values_kw(
+                    slice.len() as c_int,
+                    slice.as_ptr() as *const VALUE,
+                    kw_splat,
+                );
             }
             handle.qnil()
         },
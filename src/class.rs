@@ -12,14 +12,15 @@ use rb_sys::{
     rb_cInteger, rb_cMatch, rb_cMethod, rb_cModule, rb_cNameErrorMesg, rb_cNilClass, rb_cNumeric,
     rb_cObject, rb_cProc, rb_cRandom, rb_cRange, rb_cRational, rb_cRegexp, rb_cStat, rb_cString,
     rb_cStruct, rb_cSymbol, rb_cThread, rb_cTime, rb_cTrueClass, rb_cUnboundMethod, rb_class2name,
-    rb_class_new, rb_class_new_instance, rb_class_superclass, rb_define_alloc_func,
-    rb_get_alloc_func, rb_obj_alloc, rb_undef_alloc_func, ruby_value_type, VALUE,
+    rb_class_new, rb_class_superclass, rb_define_alloc_func, rb_get_alloc_func, rb_obj_alloc,
+    rb_undef_alloc_func, ruby_value_type, VALUE,
 };
 
 use crate::{
+    compat::rb_class_new_instance_kw,
     error::{protect, Error},
     into_value::{ArgList, IntoValue},
-    module::Module,
+    module::{define_hook, Module},
     object::Object,
     try_convert::TryConvert,
     typed_data::TypedData,
@@ -425,6 +426,42 @@ pub trait Class: Module {
             }
         }
     }
+
+    /// Register `func` to be called when `self` is subclassed.
+    ///
+    /// `func` is called with the new subclass. The existing `inherited` hook
+    /// of `self` is called first, so multiple hooks can be registered, and
+    /// hooks defined in Ruby continue to work. As with Ruby's `inherited`
+    /// hook, `func` is called before the body of the subclass definition is
+    /// run, and is also called for subclasses of subclasses.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, rb_assert, value::Opaque, Error, RClass, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let base = ruby.define_class("Plugin", ruby.class_object())?;
+    ///     let registry = Opaque::from(ruby.ary_new());
+    ///     base.const_set("REGISTRY", registry)?;
+    ///     base.on_inherited(move |ruby: &Ruby, subclass: RClass| {
+    ///         ruby.get_inner(registry).push(subclass)
+    ///     })?;
+    ///
+    ///     rb_assert!(ruby, "class A < Plugin; end; class B < A; end; Plugin::REGISTRY == [A, B]");
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    fn on_inherited<F>(self, func: F) -> Result<(), Error>
+    where
+        F: 'static + Send + Fn(&Ruby, RClass) -> Result<(), Error>,
+    {
+        define_hook(self, "inherited", move |ruby, val| {
+            func(ruby, TryConvert::try_convert(val)?)
+        })
+    }
 }
 
 impl Class for RClass {
@@ -440,14 +477,16 @@ impl Class for RClass {
     where
         T: ArgList,
     {
+        let kw_splat = args.contains_kw_args() as c_int;
         let args = args.into_arg_list_with(&Ruby::get_with(self));
         let slice = args.as_ref();
         unsafe {
             protect(|| {
-                Value::new(rb_class_new_instance(
+                Value::new(rb_class_new_instance_kw(
                     slice.len() as c_int,
                     slice.as_ptr() as *const VALUE,
                     self.as_rb_value(),
+                    kw_splat,
                 ))
             })
         }
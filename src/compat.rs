@@ -0,0 +1,107 @@
+//! Fallbacks for Ruby API functions not available in all supported Ruby
+//! versions.
+//!
+//! Ruby 2.6 doesn't distinguish keyword arguments from a trailing positional
+//! `Hash`, so the `_kw` variants of functions simply drop the `kw_splat`
+//! argument.
+
+#[cfg(ruby_gte_2_7)]
+pub(crate) use rb_sys::{
+    rb_block_call_kw, rb_call_super_kw, rb_class_new_instance_kw, rb_funcall_with_block_kw,
+    rb_funcallv_kw, rb_funcallv_public_kw, rb_method_call_kw, rb_proc_call_kw, rb_yield_values_kw,
+};
+
+#[cfg(ruby_lt_2_7)]
+mod kw {
+    use std::os::raw::c_int;
+
+    use rb_sys::{
+        rb_block_call, rb_block_call_func_t, rb_call_super, rb_class_new_instance,
+        rb_funcall_with_block, rb_funcallv, rb_funcallv_public, rb_method_call, rb_proc_call,
+        rb_yield_values2, ID, VALUE,
+    };
+
+    pub(crate) unsafe fn rb_block_call_kw(
+        obj: VALUE,
+        mid: ID,
+        argc: c_int,
+        argv: *const VALUE,
+        bl_proc: rb_block_call_func_t,
+        data2: VALUE,
+        _kw_splat: c_int,
+    ) -> VALUE {
+        rb_block_call(obj, mid, argc, argv, bl_proc, data2)
+    }
+
+    pub(crate) unsafe fn rb_call_super_kw(
+        argc: c_int,
+        argv: *const VALUE,
+        _kw_splat: c_int,
+    ) -> VALUE {
+        rb_call_super(argc, argv)
+    }
+
+    pub(crate) unsafe fn rb_class_new_instance_kw(
+        argc: c_int,
+        argv: *const VALUE,
+        klass: VALUE,
+        _kw_splat: c_int,
+    ) -> VALUE {
+        rb_class_new_instance(argc, argv, klass)
+    }
+
+    pub(crate) unsafe fn rb_funcall_with_block_kw(
+        recv: VALUE,
+        mid: ID,
+        argc: c_int,
+        argv: *const VALUE,
+        procval: VALUE,
+        _kw_splat: c_int,
+    ) -> VALUE {
+        rb_funcall_with_block(recv, mid, argc, argv, procval)
+    }
+
+    pub(crate) unsafe fn rb_funcallv_kw(
+        recv: VALUE,
+        mid: ID,
+        argc: c_int,
+        argv: *const VALUE,
+        _kw_splat: c_int,
+    ) -> VALUE {
+        rb_funcallv(recv, mid, argc, argv)
+    }
+
+    pub(crate) unsafe fn rb_funcallv_public_kw(
+        recv: VALUE,
+        mid: ID,
+        argc: c_int,
+        argv: *const VALUE,
+        _kw_splat: c_int,
+    ) -> VALUE {
+        rb_funcallv_public(recv, mid, argc, argv)
+    }
+
+    pub(crate) unsafe fn rb_method_call_kw(
+        argc: c_int,
+        argv: *const VALUE,
+        method: VALUE,
+        _kw_splat: c_int,
+    ) -> VALUE {
+        rb_method_call(argc, argv, method)
+    }
+
+    pub(crate) unsafe fn rb_proc_call_kw(recv: VALUE, args: VALUE, _kw_splat: c_int) -> VALUE {
+        rb_proc_call(recv, args)
+    }
+
+    pub(crate) unsafe fn rb_yield_values_kw(
+        argc: c_int,
+        argv: *const VALUE,
+        _kw_splat: c_int,
+    ) -> VALUE {
+        rb_yield_values2(argc, argv)
+    }
+}
+
+#[cfg(ruby_lt_2_7)]
+pub(crate) use kw::*;
@@ -2,22 +2,52 @@
 //!
 //! See also [`Ruby`](Ruby#errors) for more error related methods.
 
-use std::{any::Any, borrow::Cow, ffi::CString, fmt, mem::transmute, os::raw::c_int};
+#[cfg(feature = "backtrace")]
+use std::backtrace::Backtrace;
+use std::{
+    any::Any,
+    borrow::Cow,
+    cell::{Cell, RefCell},
+    ffi::CString,
+    fmt,
+    mem::transmute,
+    os::raw::{c_char, c_int},
+    panic::{AssertUnwindSafe, Location, UnwindSafe},
+    ptr,
+    sync::{
+        atomic::{AtomicPtr, AtomicUsize, Ordering},
+        Once,
+    },
+};
 
+#[cfg(feature = "backtrace")]
+use rb_sys::rb_make_backtrace;
 use rb_sys::{
-    rb_bug, rb_ensure, rb_errinfo, rb_exc_raise, rb_iter_break_value, rb_jump_tag, rb_protect,
-    rb_set_errinfo, rb_warning, ruby_special_consts, VALUE,
+    rb_bug, rb_catch_obj, rb_ensure, rb_errinfo, rb_exc_raise, rb_iter_break_value, rb_jump_tag,
+    rb_protect, rb_rescue2, rb_set_errinfo, rb_syserr_new, rb_throw_obj, rb_warn, rb_warning,
+    ruby_special_consts, VALUE,
 };
 
+#[cfg(feature = "backtrace")]
+use crate::r_array::RArray;
+
 use crate::{
-    class::Class,
+    class::{Class, RClass},
     exception::Exception,
-    into_value::IntoValue,
-    module::Module,
-    value::{private::ReprValue as _, ReprValue, Value},
+    gc,
+    into_value::{IntoValue, KwSplat},
+    module::{Attr, Module, RModule},
+    object::Object,
+    r_hash::RHash,
+    try_convert::TryConvert,
+    typed_data::TypedData,
+    value::{private::ReprValue as _, Lazy, ReprValue, Value},
     ExceptionClass, Ruby,
 };
 
+// ivar without @ prefix is invisible from Ruby
+pub(crate) const EXCEPTION_DATA_IVAR: &str = "__rust_data";
+
 /// An error returned to indicate an attempt to interact with the Ruby API from
 /// a non-Ruby thread or without aquiring the GVL.
 #[derive(Debug)]
@@ -89,11 +119,499 @@ impl Ruby {
         .unwrap_err()
     }
 
+    /// Calls `func`, returning early with a value if a matching
+    /// [`throw`](Ruby::throw) is called.
+    ///
+    /// `func` is passed `tag`. The result is the value thrown, or the value
+    /// returned by `func` if nothing was thrown.
+    ///
+    /// A `throw` without a matching `catch` results in an
+    /// `UncaughtThrowError`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, Error, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let tag = ruby.to_symbol("found");
+    ///     let res = ruby.catch(tag, |tag| {
+    ///         for i in 0..10 {
+    ///             if i == 3 {
+    ///                 return Err(ruby.throw(tag, i));
+    ///             }
+    ///         }
+    ///         Ok(-1)
+    ///     })?;
+    ///     assert_eq!(i64::try_convert(res)?, 3);
+    ///
+    ///     let res = ruby.catch(tag, |_| Ok(1))?;
+    ///     assert_eq!(i64::try_convert(res)?, 1);
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    pub fn catch<T, F, R>(&self, tag: T, func: F) -> Result<Value, Error>
+    where
+        T: IntoValue,
+        F: FnOnce(Value) -> Result<R, Error>,
+        R: IntoValue,
+    {
+        unsafe extern "C" fn call<F, R>(
+            tag: VALUE,
+            arg: VALUE,
+            _argc: c_int,
+            _argv: *const VALUE,
+            _blockarg: VALUE,
+        ) -> VALUE
+        where
+            F: FnOnce(Value) -> Result<R, Error>,
+            R: IntoValue,
+        {
+            let closure = (*(arg as *mut Option<F>)).take().unwrap();
+            let ruby = Ruby::get_unchecked();
+            let res = match catch_unwind(AssertUnwindSafe(|| {
+                (closure)(Value::new(tag)).map(|v| ruby.into_value(v))
+            })) {
+                Ok(v) => v,
+                Err(e) => Err(Error::from_panic(e)),
+            };
+            match res {
+                Ok(v) => v.as_rb_value(),
+                Err(e) => raise(e),
+            }
+        }
+
+        let tag = self.into_value(tag);
+        let call_func =
+            call::<F, R> as unsafe extern "C" fn(VALUE, VALUE, c_int, *const VALUE, VALUE) -> VALUE;
+        #[cfg(ruby_lt_2_7)]
+        let call_func: unsafe extern "C" fn() -> VALUE = unsafe { transmute(call_func) };
+        let mut some_func = Some(func);
+        let closure = &mut some_func as *mut Option<F> as VALUE;
+        protect(|| unsafe { Value::new(rb_catch_obj(tag.as_rb_value(), Some(call_func), closure)) })
+    }
+
+    /// Create a new error that will return `val` from the
+    /// [`catch`](Ruby::catch) for `tag` when returned to Ruby.
+    ///
+    /// If there is no `catch` for `tag` the error will be an
+    /// `UncaughtThrowError`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{function, rb_assert, Error, Ruby, Value};
+    ///
+    /// fn stop(ruby: &Ruby, tag: Value) -> Result<(), Error> {
+    ///     Err(ruby.throw(tag, "stopped"))
+    /// }
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     ruby.define_global_function("stop", function!(stop, 1));
+    ///
+    ///     rb_assert!(ruby, r#"catch(:done) { stop(:done); "finished" } == "stopped""#);
+    ///     rb_assert!(ruby, "(stop(:done) rescue $!).is_a?(UncaughtThrowError)");
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    pub fn throw<T, U>(&self, tag: T, val: U) -> Error
+    where
+        T: IntoValue,
+        U: IntoValue,
+    {
+        let tag = self.into_value(tag);
+        let val = self.into_value(val);
+        protect(|| {
+            unsafe { rb_throw_obj(tag.as_rb_value(), val.as_rb_value()) };
+            // we never get here, but this is needed to satisfy the type system
+            #[allow(unreachable_code)]
+            self.qnil()
+        })
+        .unwrap_err()
+    }
+
+    /// Calls `func`, then calls `cleanup`, even if `func` returned an error,
+    /// or Ruby exited early with a `break`, `throw`, etc.
+    ///
+    /// Equivalent to Ruby's `begin; ...; ensure; ...; end`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    ///
+    /// use magnus::{Error, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let cleaned_up = Cell::new(false);
+    ///     let res: Result<(), Error> = ruby.ensure(
+    ///         || Err(Error::new(ruby.exception_runtime_error(), "oops")),
+    ///         || cleaned_up.set(true),
+    ///     );
+    ///     assert!(res.is_err());
+    ///     assert!(cleaned_up.get());
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    pub fn ensure<F, T, E>(&self, func: F, cleanup: E) -> Result<T, Error>
+    where
+        F: FnOnce() -> Result<T, Error>,
+        E: FnOnce(),
+    {
+        let mut result = None;
+        protect(|| {
+            ensure(
+                || {
+                    let res = match catch_unwind(AssertUnwindSafe(func)) {
+                        Ok(v) => v,
+                        Err(e) => Err(Error::from_panic(e)),
+                    };
+                    match res {
+                        Ok(v) => {
+                            result = Some(v);
+                            self.qnil().as_value()
+                        }
+                        Err(e) => raise(e),
+                    }
+                },
+                || {
+                    if let Err(e) = catch_unwind(AssertUnwindSafe(cleanup)) {
+                        raise(Error::from_panic(e))
+                    }
+                },
+            )
+        })?;
+        Ok(result.unwrap())
+    }
+
+    /// Calls `func`, calling `handler` with the exception if `func` returns
+    /// an error that is an instance of one of `classes`.
+    ///
+    /// If `classes` is empty `StandardError` is rescued, matching Ruby's
+    /// `rescue` with no exception classes. Errors that don't match are
+    /// returned unchanged.
+    ///
+    /// Equivalent to Ruby's `begin; ...; rescue *classes => e; ...; end`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{Error, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let mut attempts = 0;
+    ///     let res = loop {
+    ///         attempts += 1;
+    ///         let res = ruby.rescue(
+    ///             || ruby.eval::<i64>("Integer('ten')").map(Some),
+    ///             &[ruby.exception_arg_error()],
+    ///             |e| if attempts < 3 { Ok(None) } else { Err(e.into()) },
+    ///         );
+    ///         match res {
+    ///             Ok(None) => continue,
+    ///             res => break res,
+    ///         }
+    ///     };
+    ///     assert!(res.unwrap_err().is_kind_of(ruby.exception_arg_error()));
+    ///     assert_eq!(attempts, 3);
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    pub fn rescue<F, T, H>(
+        &self,
+        func: F,
+        classes: &[ExceptionClass],
+        handler: H,
+    ) -> Result<T, Error>
+    where
+        F: FnOnce() -> Result<T, Error>,
+        H: FnOnce(Exception) -> Result<T, Error>,
+    {
+        let default = [self.exception_standard_error()];
+        let classes = if classes.is_empty() {
+            &default[..]
+        } else {
+            classes
+        };
+        let result = Cell::new(None);
+        protect(|| {
+            rescue(
+                || {
+                    let res = match catch_unwind(AssertUnwindSafe(func)) {
+                        Ok(v) => v,
+                        Err(e) => Err(Error::from_panic(e)),
+                    };
+                    match res {
+                        Ok(v) => {
+                            result.set(Some(v));
+                            self.qnil().as_value()
+                        }
+                        Err(e) => raise(e),
+                    }
+                },
+                |ex| {
+                    if !classes.iter().any(|c| ex.is_kind_of(*c)) {
+                        drop(handler);
+                        raise(ex.into());
+                    }
+                    let res = match catch_unwind(AssertUnwindSafe(|| handler(ex))) {
+                        Ok(v) => v,
+                        Err(e) => Err(Error::from_panic(e)),
+                    };
+                    match res {
+                        Ok(v) => {
+                            result.set(Some(v));
+                            self.qnil().as_value()
+                        }
+                        Err(e) => raise(e),
+                    }
+                },
+            )
+        })?;
+        Ok(result.into_inner().unwrap())
+    }
+
     /// Outputs `s` to Ruby's stderr if Ruby is configured to output warnings.
     pub fn warning(&self, s: &str) {
         let s = CString::new(s).unwrap();
         unsafe { rb_warning(s.as_ptr()) };
     }
+
+    /// Outputs `msg` as a warning, unless warnings have been disabled, e.g.
+    /// with `ruby -W0`.
+    ///
+    /// Unlike [`Ruby::warning`] this does not require verbose mode (`ruby -w`)
+    /// to be shown. The warning is prefixed with the location of the calling
+    /// Ruby code, and is output with `Warning.warn`, so can be customised by
+    /// overriding that method.
+    ///
+    /// Returns an error if `msg` contains a nul byte, or if `Warning.warn`
+    /// raises.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{Error, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     ruby.warn("this is a warning")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    pub fn warn(&self, msg: &str) -> Result<(), Error> {
+        let msg =
+            CString::new(msg).map_err(|e| Error::new(self.exception_arg_error(), e.to_string()))?;
+        protect(|| {
+            // pass msg as an argument, rather than the format string, so any
+            // `%` in msg is output as is
+            unsafe { rb_warn("%s\0".as_ptr() as *const c_char, msg.as_ptr()) };
+            self.qnil()
+        })?;
+        Ok(())
+    }
+
+    /// Returns whether warnings in `category` are enabled.
+    ///
+    /// This is the same as checking `Warning[category]` from Ruby, and so can
+    /// be toggled with, for example, `ruby -W:deprecated` or
+    /// `Warning[:deprecated] = true`.
+    ///
+    /// Before Ruby 2.7.2 deprecated and experimental warnings are always
+    /// enabled. Performance warnings require Ruby 3.3, and are always
+    /// disabled on earlier versions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{error::Category, Error, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     // enabled by default
+    ///     assert!(ruby.warning_category_enabled(Category::Experimental));
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    pub fn warning_category_enabled(&self, category: Category) -> bool {
+        let warning = match self.class_object().const_get::<_, RModule>("Warning") {
+            Ok(v) => v,
+            Err(_) => return category != Category::Performance,
+        };
+        if !warning.respond_to("[]", false).unwrap_or(false) {
+            return category != Category::Performance;
+        }
+        // `Warning.[]` raises for categories unknown to the running version
+        // of Ruby
+        warning
+            .funcall("[]", (self.to_symbol(category.name()),))
+            .unwrap_or(false)
+    }
+
+    /// Outputs `msg` as a warning in `category`, if warnings in `category`
+    /// are enabled.
+    ///
+    /// See [`Ruby::warning_category_enabled`] and [`Ruby::warn`].
+    ///
+    /// With Ruby 3.0 and later the category is passed to `Warning.warn`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{error::Category, Error, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     ruby.warn_category(
+    ///         Category::Deprecated,
+    ///         "Example#old_method is deprecated, use Example#new_method",
+    ///     )?;
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    pub fn warn_category(&self, category: Category, msg: &str) -> Result<(), Error> {
+        if !self.warning_category_enabled(category) {
+            return Ok(());
+        }
+        let kwargs = self.hash_new();
+        kwargs.aset(self.to_symbol("uplevel"), 0)?;
+        // the category keyword was added in Ruby 3.0
+        if cfg!(ruby_gte_3_0) {
+            kwargs.aset(self.to_symbol("category"), self.to_symbol(category.name()))?;
+        }
+        let _: Value = self
+            .module_kernel()
+            .funcall("warn", (msg, KwSplat(kwargs)))?;
+        Ok(())
+    }
+
+    /// Outputs `msg` as a warning in `category`, as [`Ruby::warn_category`],
+    /// but only the first time this is called from each location in the
+    /// source.
+    ///
+    /// Calls made while warnings in `category` are disabled do not count
+    /// towards this.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{error::Category, Error, Ruby};
+    ///
+    /// fn old_method(ruby: &Ruby) -> Result<(), Error> {
+    ///     ruby.warn_once(
+    ///         Category::Deprecated,
+    ///         "Example#old_method is deprecated, use Example#new_method",
+    ///     )
+    /// }
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     for _ in 0..3 {
+    ///         // only warns the first time
+    ///         old_method(ruby)?;
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    #[track_caller]
+    pub fn warn_once(&self, category: Category, msg: &str) -> Result<(), Error> {
+        static WARNED: Lazy<RHash> = Lazy::new(|ruby| ruby.hash_new());
+
+        if !self.warning_category_enabled(category) {
+            return Ok(());
+        }
+        let location = Location::caller();
+        let key = format!(
+            "{}:{}:{}",
+            location.file(),
+            location.line(),
+            location.column()
+        );
+        let warned = self.get_inner(&WARNED);
+        if warned.get(key.as_str()).is_some() {
+            return Ok(());
+        }
+        warned.aset(key, true)?;
+        self.warn_category(category, msg)
+    }
+
+    /// Set how panics in Rust code called from Ruby are handled.
+    ///
+    /// The policy applies to the whole process, and is usually set once, at
+    /// init. See the `panic` attribute of the [`init`](macro@crate::init)
+    /// macro.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{error::PanicPolicy, function, rb_assert, Error, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     ruby.set_panic_policy(
+    ///         PanicPolicy::new().exception_class(|ruby| ruby.exception_runtime_error()),
+    ///     );
+    ///
+    ///     fn oops() -> i64 {
+    ///         panic!("oops")
+    ///     }
+    ///
+    ///     ruby.define_global_function("oops", function!(oops, 0));
+    ///     rb_assert!(ruby, "(oops rescue $!).is_a?(RuntimeError)");
+    ///     rb_assert!(ruby, r#"(oops rescue $!).message == "oops""#);
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    pub fn set_panic_policy(&self, policy: PanicPolicy) {
+        let needs_hook = policy.backtrace || policy.hook.is_some();
+        // the previous policy may still be in use by a panic on another
+        // thread, so it is leaked rather than freed. Policies are small and
+        // rarely set more than once.
+        PANIC_POLICY.store(Box::into_raw(Box::new(policy)), Ordering::Release);
+        if needs_hook {
+            PANIC_HOOK.call_once(|| {
+                let previous = std::panic::take_hook();
+                std::panic::set_hook(Box::new(move |info| {
+                    panic_hook(info.location());
+                    previous(info);
+                }));
+            });
+        }
+    }
+}
+
+/// Categories of warning, see [`Ruby::warn_category`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Category {
+    /// Warnings for features that will be removed or changed in the future.
+    Deprecated,
+    /// Warnings for experimental features.
+    Experimental,
+    /// Warnings for code that may perform poorly. Requires Ruby 3.3.
+    Performance,
+}
+
+impl Category {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Deprecated => "deprecated",
+            Self::Experimental => "experimental",
+            Self::Performance => "performance",
+        }
+    }
 }
 
 /// Shorthand for `std::result::Result<T, magnus::Error>`.
@@ -152,6 +670,132 @@ impl Error {
         Self(ErrorType::Error(class, msg.into()))
     }
 
+    /// Returns a new `Error` with `cause` set as the cause of `self`.
+    ///
+    /// This has the same result as Ruby's `raise error, cause: cause` once the
+    /// error is raised, and is useful when wrapping a Ruby error in a more
+    /// specific error, so that the original is still available from Ruby with
+    /// `Exception#cause`.
+    ///
+    /// The cause is set directly on the exception, it is not raised, so the
+    /// backtrace is left to be set when the error is raised and no `:raise`
+    /// event is triggered for `TracePoint`.
+    ///
+    /// If either `self` or `cause` is not an exception (such as an error
+    /// created with [`Ruby::iter_break_value`]) `self` is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{rb_assert, Error, Ruby, Value};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let err = ruby
+    ///         .eval::<Value>("Integer('x')")
+    ///         .map_err(|e| {
+    ///             Error::new(ruby.exception_runtime_error(), "invalid config").with_cause(e)
+    ///         })
+    ///         .unwrap_err();
+    ///
+    ///     let ex = err.value().unwrap();
+    ///     rb_assert!(ruby, r#"ex.message == "invalid config""#, ex);
+    ///     rb_assert!(ruby, "ex.cause.is_a?(ArgumentError)", ex);
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    pub fn with_cause(self, cause: Error) -> Self {
+        let (ex, cause) = match (self.0, cause.0) {
+            (ErrorType::Jump(tag), _) => return Self(ErrorType::Jump(tag)),
+            (e, ErrorType::Jump(_)) => return Self(e),
+            (e, c) => (Self(e).exception(), Self(c).exception()),
+        };
+        if ex.as_rb_value() == cause.as_rb_value() {
+            return ex.into();
+        }
+        // Ruby stores the cause in an ivar without an @ prefix, and leaves it
+        // untouched when raising an exception that already has a cause
+        match ex.ivar_set("cause", cause) {
+            Ok(()) => ex.into(),
+            Err(e) => e,
+        }
+    }
+
+    /// Create a new `Error` that will be raised as an instance of `class`
+    /// wrapping `data`.
+    ///
+    /// This allows Ruby code rescuing the exception to access structured data
+    /// through methods defined on `class`, rather than only the message.
+    ///
+    /// The exception is an ordinary instance of `class`, with `data` wrapped
+    /// as an instance of the class of `T` (see [`TypedData::class`]) and
+    /// stored in an instance variable hidden from Ruby. `class` can still be
+    /// raised and instantiated from Ruby as normal, and copies made with
+    /// `Exception#exception` (such as when re-raising with a new message)
+    /// keep the data.
+    ///
+    /// The data can be retrieved from a rescued error with [`Error::data`],
+    /// or from the exception with [`Exception::data`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{method, prelude::*, rb_assert, Error, Exception, Ruby};
+    ///
+    /// #[magnus::wrap(class = "ParseErrorData", free_immediately, size)]
+    /// struct ParseErrorData {
+    ///     line: usize,
+    ///     column: usize,
+    /// }
+    ///
+    /// fn line(rb_self: Exception) -> Option<usize> {
+    ///     rb_self.data::<ParseErrorData>().map(|d| d.line)
+    /// }
+    ///
+    /// fn column(rb_self: Exception) -> Option<usize> {
+    ///     rb_self.data::<ParseErrorData>().map(|d| d.column)
+    /// }
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     ruby.define_class("ParseErrorData", ruby.class_object())?;
+    ///     let class = ruby.define_error("ParseError", ruby.exception_standard_error())?;
+    ///     class.define_method("line", method!(line, 0))?;
+    ///     class.define_method("column", method!(column, 0))?;
+    ///
+    ///     let err = Error::new_with_data(
+    ///         class,
+    ///         "unexpected token",
+    ///         ParseErrorData { line: 3, column: 7 },
+    ///     );
+    ///
+    ///     let data = err.data::<ParseErrorData>().unwrap();
+    ///     assert_eq!((data.line, data.column), (3, 7));
+    ///
+    ///     let ex = err.value().unwrap();
+    ///     rb_assert!(ruby, r#"ex.message == "unexpected token""#, ex);
+    ///     rb_assert!(ruby, "ex.line == 3 && ex.column == 7", ex);
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    pub fn new_with_data<T, U>(class: ExceptionClass, msg: T, data: U) -> Self
+    where
+        T: Into<Cow<'static, str>>,
+        U: TypedData,
+    {
+        let handle = Ruby::get_with(class);
+        let ex = match class.new_instance((handle.str_new(msg.into().as_ref()),)) {
+            Ok(ex) => ex,
+            Err(e) => return e,
+        };
+        match ex.ivar_set(EXCEPTION_DATA_IVAR, handle.wrap(data)) {
+            Ok(()) => ex.into(),
+            Err(e) => e,
+        }
+    }
+
     pub(crate) fn from_tag(tag: Tag) -> Self {
         Self(ErrorType::Jump(tag))
     }
@@ -240,6 +884,52 @@ impl Error {
         }
     }
 
+    /// Returns a reference to the Rust data wrapped by the exception, if
+    /// `self` is an exception wrapping a `T`.
+    ///
+    /// See [`Error::new_with_data`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{function, prelude::*, Error, Ruby, Value};
+    ///
+    /// #[magnus::wrap(class = "LimitError")]
+    /// struct Limit(usize);
+    ///
+    /// fn check(ruby: &Ruby, n: usize) -> Result<usize, Error> {
+    ///     if n > 10 {
+    ///         let class = ruby.class_object().const_get("LimitError")?;
+    ///         return Err(Error::new_with_data(class, "too big", Limit(10)));
+    ///     }
+    ///     Ok(n)
+    /// }
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let class = ruby.define_error("LimitError", ruby.exception_standard_error())?;
+    ///     ruby.define_global_function("check", function!(check, 1));
+    ///
+    ///     let err = ruby.eval::<Value>("check(11)").unwrap_err();
+    ///     assert!(err.is_kind_of(class));
+    ///     assert_eq!(err.data::<Limit>().unwrap().0, 10);
+    ///
+    ///     let err = ruby.eval::<Value>("raise ArgumentError").unwrap_err();
+    ///     assert!(err.data::<Limit>().is_none());
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    pub fn data<T>(&self) -> Option<&T>
+    where
+        T: TypedData,
+    {
+        match self.0 {
+            ErrorType::Exception(ref e) => e.data(),
+            _ => None,
+        }
+    }
+
     /// Consumes `self`, returning an `Exception`.
     ///
     /// # Panics
@@ -281,20 +971,53 @@ impl Error {
 
     /// Create an `Error` from the error value of [`std::panic::catch_unwind`].
     ///
-    /// The Ruby Exception will be `fatal`, terminating the Ruby process, but
-    /// allowing cleanup code to run.
+    /// By default the Ruby Exception will be `fatal`, terminating the Ruby
+    /// process, but allowing cleanup code to run. This can be changed with
+    /// [`Ruby::set_panic_policy`].
     pub(crate) fn from_panic(e: Box<dyn Any + Send + 'static>) -> Self {
-        let msg = if let Some(&m) = e.downcast_ref::<&'static str>() {
+        let msg: Cow<'static, str> = if let Some(&m) = e.downcast_ref::<&'static str>() {
             m.into()
         } else if let Some(m) = e.downcast_ref::<String>() {
             m.clone().into()
         } else {
             "panic".into()
         };
-        Self(ErrorType::Error(
-            unsafe { Ruby::get_unchecked().exception_fatal() },
-            msg,
-        ))
+        let policy = panic_policy();
+        let captured = CAPTURED_PANIC.with(|c| c.borrow_mut().take());
+        if let Some(hook) = policy.hook {
+            hook(&PanicReport {
+                message: &msg,
+                location: captured
+                    .as_ref()
+                    .and_then(|c| c.location.as_ref())
+                    .map(|(file, line, column)| (file.as_str(), *line, *column)),
+                #[cfg(feature = "backtrace")]
+                backtrace: captured.as_ref().and_then(|c| c.backtrace.as_ref()),
+            });
+        }
+        if cfg!(debug_assertions) && policy.abort_in_debug {
+            std::process::abort();
+        }
+        let handle = unsafe { Ruby::get_unchecked() };
+        let class = match policy.exception_class {
+            Some(f) => f(&handle),
+            None => handle.exception_fatal(),
+        };
+        let err = Self(ErrorType::Error(class, msg));
+        #[cfg(feature = "backtrace")]
+        if let Some(backtrace) = captured.and_then(|c| c.backtrace) {
+            let ex = err.exception();
+            let mut lines = backtrace_lines(&backtrace);
+            let ruby_backtrace = unsafe { RArray::from_rb_value_unchecked(rb_make_backtrace()) };
+            return match ruby_backtrace.to_vec::<String>().and_then(|ruby_lines| {
+                lines.extend(ruby_lines);
+                ex.set_backtrace(lines)
+            }) {
+                Ok(()) => ex.into(),
+                Err(e) => e,
+            };
+        }
+        err
     }
 }
 
@@ -314,6 +1037,106 @@ impl From<Exception> for Error {
     }
 }
 
+impl std::error::Error for Error {}
+
+/// Conversion from [`std::io::Error`].
+///
+/// Errors with an OS error code are converted to the matching `Errno`
+/// subclass of `SystemCallError` (such as `Errno::ENOENT`). Other errors are
+/// converted to `IOError`.
+///
+/// # Panics
+///
+/// Panics if called from a non-Ruby thread.
+///
+/// # Examples
+///
+/// ```
+/// use magnus::{function, rb_assert, Error, Ruby};
+///
+/// fn read(path: String) -> Result<String, Error> {
+///     Ok(std::fs::read_to_string(path)?)
+/// }
+///
+/// fn example(ruby: &Ruby) -> Result<(), Error> {
+///     ruby.define_global_function("read", function!(read, 1));
+///     rb_assert!(
+///         ruby,
+///         r#"(read("/does/not/exist") rescue $!).is_a?(Errno::ENOENT)"#
+///     );
+///
+///     Ok(())
+/// }
+/// # Ruby::init(example).unwrap()
+/// ```
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        let handle = get_ruby!();
+        #[cfg(unix)]
+        if let Some(code) = e.raw_os_error() {
+            return match protect(|| unsafe {
+                Exception::from_rb_value_unchecked(rb_syserr_new(code, std::ptr::null()))
+            }) {
+                Ok(ex) => ex.into(),
+                Err(err) => err,
+            };
+        }
+        Self::new(handle.exception_io_error(), e.to_string())
+    }
+}
+
+/// Conversion from [`std::str::Utf8Error`] to `EncodingError`.
+///
+/// # Panics
+///
+/// Panics if called from a non-Ruby thread.
+impl From<std::str::Utf8Error> for Error {
+    fn from(e: std::str::Utf8Error) -> Self {
+        Self::new(get_ruby!().exception_encoding_error(), e.to_string())
+    }
+}
+
+/// Conversion from [`std::num::ParseIntError`] to `ArgumentError`.
+///
+/// # Panics
+///
+/// Panics if called from a non-Ruby thread.
+///
+/// # Examples
+///
+/// ```
+/// use magnus::{function, rb_assert, Error, Ruby};
+///
+/// fn parse(s: String) -> Result<i64, Error> {
+///     Ok(s.parse()?)
+/// }
+///
+/// fn example(ruby: &Ruby) -> Result<(), Error> {
+///     ruby.define_global_function("parse", function!(parse, 1));
+///     rb_assert!(ruby, r#"parse("42") == 42"#);
+///     rb_assert!(ruby, r#"(parse("x") rescue $!).is_a?(ArgumentError)"#);
+///
+///     Ok(())
+/// }
+/// # Ruby::init(example).unwrap()
+/// ```
+impl From<std::num::ParseIntError> for Error {
+    fn from(e: std::num::ParseIntError) -> Self {
+        Self::new(get_ruby!().exception_arg_error(), e.to_string())
+    }
+}
+
+/// Conversion from [`std::num::TryFromIntError`] to `RangeError`.
+///
+/// # Panics
+///
+/// Panics if called from a non-Ruby thread.
+impl From<std::num::TryFromIntError> for Error {
+    fn from(e: std::num::TryFromIntError) -> Self {
+        Self::new(get_ruby!().exception_range_error(), e.to_string())
+    }
+}
+
 /// A wrapper to make a [`Error`] [`Send`] + [`Sync`].
 ///
 /// [`Error`] is not [`Send`] or [`Sync`] as it provides a way to call some of
@@ -331,7 +1154,12 @@ impl From<Exception> for Error {
 /// Note that `OpaqueError` contains a Ruby value, so must be kept on the stack
 /// of a Ruby thread to prevent it from being Garbage Collected (or otherwise
 /// protected from premature GC).
-pub struct OpaqueError(ErrorType);
+///
+/// `OpaqueError` implements [`std::error::Error`], so can be used with error
+/// handling libraries that require errors to be [`Send`] + [`Sync`]. The
+/// message is captured when the `OpaqueError` is created, so can be displayed
+/// without calling Ruby.
+pub struct OpaqueError(ErrorType, String);
 
 unsafe impl Send for OpaqueError {}
 unsafe impl Sync for OpaqueError {}
@@ -362,7 +1190,126 @@ impl OpaqueError {
 
 impl From<Error> for OpaqueError {
     fn from(err: Error) -> Self {
-        Self(err.0)
+        let msg = err.to_string();
+        Self(err.0, msg)
+    }
+}
+
+impl fmt::Display for OpaqueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.1)
+    }
+}
+
+impl fmt::Debug for OpaqueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("OpaqueError").field(&self.1).finish()
+    }
+}
+
+impl std::error::Error for OpaqueError {}
+
+/// Trait for Rust error types that map to a hierarchy of Ruby exception
+/// classes.
+///
+/// This trait is usually derived with the
+/// [`RubyError`](macro@crate::RubyError) derive macro, which also implements
+/// `From<T> for Error`.
+///
+/// # Panics
+///
+/// The derived `From<T> for Error` panics if called from a non-Ruby thread.
+pub trait RubyError {
+    /// Define the exception classes for `Self`.
+    ///
+    /// The classes will be defined on first use if this isn't called, but
+    /// calling this at init makes them available to Ruby code before any
+    /// error has been raised.
+    fn define_exceptions(ruby: &Ruby) -> Result<()>;
+
+    /// Returns the base exception class for `Self`.
+    ///
+    /// Defines the class if it isn't already defined. Returns a `TypeError`
+    /// if a class of the same name already exists with a different
+    /// superclass.
+    fn base_class(ruby: &Ruby) -> Result<ExceptionClass>;
+
+    /// Returns the exception class for `self`.
+    ///
+    /// Defines the class if it isn't already defined. Returns a `TypeError`
+    /// if a class of the same name already exists with a different
+    /// superclass.
+    fn exception_class(&self, ruby: &Ruby) -> Result<ExceptionClass>;
+}
+
+/// Defines the exception class at `path`, e.g. `"Foo::Bar::BazError"`, with
+/// readers for the instance variables named by `attrs`.
+///
+/// Used by the [`RubyError`](macro@crate::RubyError) derive macro.
+#[doc(hidden)]
+pub fn define_error_class(
+    ruby: &Ruby,
+    path: &str,
+    superclass: &str,
+    attrs: &[&str],
+) -> Result<ExceptionClass> {
+    let superclass: ExceptionClass = ruby.class_object().funcall("const_get", (superclass,))?;
+    let (namespace, name) = match path.rsplit_once("::") {
+        Some((namespace, name)) => (ruby.define_namespace(namespace)?, name),
+        None => (ruby.class_object().as_value(), path),
+    };
+    // only look in `namespace` itself, not its ancestors
+    let defined: bool = namespace.funcall("const_defined?", (name, false))?;
+    let class = match RClass::from_value(namespace) {
+        Some(namespace) => namespace.define_error(name, superclass)?,
+        None => RModule::try_convert(namespace)?.define_error(name, superclass)?,
+    };
+    // an existing class already has its readers
+    if !defined {
+        for attr in attrs {
+            class.define_attr(*attr, Attr::Read)?;
+        }
+    }
+    Ok(class)
+}
+
+/// A `static` cache for an exception class, which is defined on first use.
+///
+/// Unlike [`Lazy`], defining the class may fail. Errors are returned rather
+/// than cached, so a failed definition is retried on the next use.
+///
+/// Used by the [`RubyError`](macro@crate::RubyError) derive macro.
+#[doc(hidden)]
+pub struct ErrorClassCell(AtomicUsize);
+
+impl ErrorClassCell {
+    /// Create a new, empty, `ErrorClassCell`.
+    pub const fn new() -> Self {
+        Self(AtomicUsize::new(0))
+    }
+
+    /// Returns the cached class, or defines it with `define` and caches the
+    /// result if this is the first successful use.
+    pub fn get_or_try_init(
+        &self,
+        ruby: &Ruby,
+        define: fn(&Ruby) -> Result<ExceptionClass>,
+    ) -> Result<ExceptionClass> {
+        let cached = self.0.load(Ordering::Acquire);
+        if cached != 0 {
+            return Ok(unsafe { ExceptionClass::from_rb_value_unchecked(cached as VALUE) });
+        }
+        let class = define(ruby)?;
+        gc::register_mark_object(class);
+        self.0
+            .store(class.as_rb_value() as usize, Ordering::Release);
+        Ok(class)
+    }
+}
+
+impl Default for ErrorClassCell {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -513,6 +1460,58 @@ where
     unsafe { T::from_value_unchecked(Value::new(result)) }
 }
 
+/// Calls `func`, calling `rescue` with the exception if `func` raises any
+/// exception.
+fn rescue<F1, F2, T>(func: F1, rescue: F2) -> T
+where
+    F1: FnOnce() -> T,
+    F2: FnOnce(Exception) -> T,
+    T: ReprValue,
+{
+    unsafe extern "C" fn call_func<F1, T>(arg: VALUE) -> VALUE
+    where
+        F1: FnOnce() -> T,
+        T: ReprValue,
+    {
+        let closure = (*(arg as *mut Option<F1>)).take().unwrap();
+        (closure)().as_rb_value()
+    }
+
+    unsafe extern "C" fn call_rescue<F2, T>(arg: VALUE, ex: VALUE) -> VALUE
+    where
+        F2: FnOnce(Exception) -> T,
+        T: ReprValue,
+    {
+        let closure = (*(arg as *mut Option<F2>)).take().unwrap();
+        (closure)(Exception::from_rb_value_unchecked(ex)).as_rb_value()
+    }
+
+    let result = unsafe {
+        let call_func_ptr = call_func::<F1, T> as unsafe extern "C" fn(VALUE) -> VALUE;
+        #[cfg(ruby_lt_2_7)]
+        let call_func_ptr: unsafe extern "C" fn() -> VALUE = std::mem::transmute(call_func_ptr);
+        let mut some_func = Some(func);
+        let func_closure = &mut some_func as *mut Option<F1> as VALUE;
+        let call_rescue_ptr = call_rescue::<F2, T> as unsafe extern "C" fn(VALUE, VALUE) -> VALUE;
+        #[cfg(ruby_lt_2_7)]
+        let call_rescue_ptr: unsafe extern "C" fn() -> VALUE = std::mem::transmute(call_rescue_ptr);
+        let mut some_rescue = Some(rescue);
+        let rescue_closure = &mut some_rescue as *mut Option<F2> as VALUE;
+        // exceptions not matching the caller's classes are re-raised by
+        // `rescue`, as the variadic list of classes can't be built at runtime
+        rb_rescue2(
+            Some(call_func_ptr),
+            func_closure,
+            Some(call_rescue_ptr),
+            rescue_closure,
+            Ruby::get_unchecked().exception_exception().as_rb_value(),
+            0 as VALUE,
+        )
+    };
+
+    unsafe { T::from_value_unchecked(Value::new(result)) }
+}
+
 pub(crate) fn raise(e: Error) -> ! {
     match e.0 {
         ErrorType::Jump(tag) => tag.resume(),
@@ -558,3 +1557,216 @@ pub fn bug(s: &str) -> ! {
 pub fn warning(s: &str) {
     get_ruby!().warning(s)
 }
+
+/// Configures how panics in Rust code called from Ruby are handled.
+///
+/// Panics are caught at the boundary between Rust and Ruby and converted to
+/// a Ruby exception. By default this is a `fatal` exception, terminating the
+/// Ruby process, but allowing cleanup code to run.
+///
+/// Set with [`Ruby::set_panic_policy`], or the `panic` attribute of the
+/// [`init`](macro@crate::init) macro.
+///
+/// # Examples
+///
+/// ```
+/// use magnus::error::{PanicPolicy, PanicReport};
+///
+/// fn report(panic: &PanicReport) {
+///     // send to crash reporter
+///     # let _ = panic.message();
+/// }
+///
+/// const POLICY: PanicPolicy = PanicPolicy::new()
+///     .exception_class(|ruby| ruby.exception_runtime_error())
+///     .abort_in_debug(true)
+///     .hook(report);
+/// ```
+#[derive(Clone, Copy)]
+pub struct PanicPolicy {
+    exception_class: Option<fn(&Ruby) -> ExceptionClass>,
+    backtrace: bool,
+    abort_in_debug: bool,
+    hook: Option<fn(&PanicReport)>,
+}
+
+impl PanicPolicy {
+    /// Create a new `PanicPolicy` with the default behaviour.
+    pub const fn new() -> Self {
+        Self {
+            exception_class: None,
+            backtrace: false,
+            abort_in_debug: false,
+            hook: None,
+        }
+    }
+
+    /// Set the class of the exception raised for a panic.
+    ///
+    /// `f` is called each time a panic is converted to an exception. It must
+    /// not panic.
+    pub const fn exception_class(mut self, f: fn(&Ruby) -> ExceptionClass) -> Self {
+        self.exception_class = Some(f);
+        self
+    }
+
+    /// Set whether to capture a [`std::backtrace::Backtrace`] when a panic
+    /// occurs in Rust code called from Ruby.
+    ///
+    /// When set, the Rust frames are prepended to the Ruby backtrace of the
+    /// raised exception, and the backtrace is available from
+    /// [`PanicReport::backtrace`].
+    ///
+    /// Requires the `backtrace` feature, and Rust 1.65.
+    #[cfg(feature = "backtrace")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "backtrace")))]
+    pub const fn backtrace(mut self, capture: bool) -> Self {
+        self.backtrace = capture;
+        self
+    }
+
+    /// Set whether to abort the process on a panic, rather than raising an
+    /// exception, when built with debug assertions.
+    pub const fn abort_in_debug(mut self, abort: bool) -> Self {
+        self.abort_in_debug = abort;
+        self
+    }
+
+    /// Set a function to be called when a panic occurs, e.g. to report the
+    /// panic to a crash reporter.
+    ///
+    /// This is called on the Ruby thread when a panic in Rust code called
+    /// from Ruby is converted to an exception, before the exception is raised.
+    /// Other panics, such as those on non-Ruby threads, or caught with
+    /// [`std::panic::catch_unwind`] in your own code, are not reported.
+    /// `hook` must not panic.
+    pub const fn hook(mut self, hook: fn(&PanicReport)) -> Self {
+        self.hook = Some(hook);
+        self
+    }
+}
+
+impl Default for PanicPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Details of a panic, passed to the function set with [`PanicPolicy::hook`].
+pub struct PanicReport<'a> {
+    message: &'a str,
+    location: Option<(&'a str, u32, u32)>,
+    #[cfg(feature = "backtrace")]
+    backtrace: Option<&'a Backtrace>,
+}
+
+impl<'a> PanicReport<'a> {
+    /// Returns the panic message.
+    ///
+    /// Panics with a payload that is not a string report the message
+    /// `"panic"`.
+    pub fn message(&self) -> &str {
+        self.message
+    }
+
+    /// Returns the file, line, and column in the source the panic originated
+    /// from, if available.
+    pub fn location(&self) -> Option<(&str, u32, u32)> {
+        self.location
+    }
+
+    /// Returns the backtrace captured for the panic, if
+    /// [`PanicPolicy::backtrace`] is set.
+    #[cfg(feature = "backtrace")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "backtrace")))]
+    pub fn backtrace(&self) -> Option<&Backtrace> {
+        self.backtrace
+    }
+}
+
+static DEFAULT_PANIC_POLICY: PanicPolicy = PanicPolicy::new();
+static PANIC_POLICY: AtomicPtr<PanicPolicy> = AtomicPtr::new(ptr::null_mut());
+static PANIC_HOOK: Once = Once::new();
+
+/// Details of a panic recorded by the panic hook, for `Error::from_panic`.
+struct CapturedPanic {
+    location: Option<(String, u32, u32)>,
+    #[cfg(feature = "backtrace")]
+    backtrace: Option<Backtrace>,
+}
+
+thread_local! {
+    static CATCH_DEPTH: Cell<usize> = Cell::new(0);
+    static CAPTURED_PANIC: RefCell<Option<CapturedPanic>> = RefCell::new(None);
+}
+
+fn panic_policy() -> &'static PanicPolicy {
+    let policy = PANIC_POLICY.load(Ordering::Acquire);
+    if policy.is_null() {
+        &DEFAULT_PANIC_POLICY
+    } else {
+        // policies are never freed, see Ruby::set_panic_policy
+        unsafe { &*policy }
+    }
+}
+
+/// Wraps [`std::panic::catch_unwind`], so that panics in `func` are captured
+/// by the panic hook for [`Error::from_panic`].
+///
+/// Panics outside of this, such as on non-Ruby threads, are left alone.
+pub(crate) fn catch_unwind<F, T>(func: F) -> std::thread::Result<T>
+where
+    F: FnOnce() -> T + UnwindSafe,
+{
+    CATCH_DEPTH.with(|d| d.set(d.get() + 1));
+    let res = std::panic::catch_unwind(func);
+    CATCH_DEPTH.with(|d| d.set(d.get() - 1));
+    res
+}
+
+fn panic_hook(location: Option<&Location>) {
+    if CATCH_DEPTH.with(|d| d.get()) == 0 {
+        return;
+    }
+    let captured = CapturedPanic {
+        location: location.map(|l| (l.file().to_owned(), l.line(), l.column())),
+        #[cfg(feature = "backtrace")]
+        backtrace: if panic_policy().backtrace {
+            Some(Backtrace::force_capture())
+        } else {
+            None
+        },
+    };
+    CAPTURED_PANIC.with(|c| *c.borrow_mut() = Some(captured));
+}
+
+/// Formats the frames of `backtrace` like Ruby backtrace lines, skipping
+/// frames from the standard library and magnus.
+#[cfg(feature = "backtrace")]
+fn backtrace_lines(backtrace: &Backtrace) -> Vec<String> {
+    fn is_internal(symbol: &str) -> bool {
+        let symbol = symbol.trim_start_matches('<');
+        ["std::", "core::", "alloc::", "__rustc::", "magnus::"]
+            .iter()
+            .any(|prefix| symbol.starts_with(prefix))
+    }
+
+    let formatted = backtrace.to_string();
+    let mut lines = Vec::new();
+    let mut symbol = None;
+    for line in formatted.lines().map(str::trim) {
+        if let Some(location) = line.strip_prefix("at ") {
+            if let Some(symbol) = symbol.take() {
+                if is_internal(symbol) || location.starts_with("/rustc/") {
+                    continue;
+                }
+                // drop the column, Ruby backtraces only have the line
+                let location = location.rsplit_once(':').map_or(location, |(l, _)| l);
+                lines.push(format!("{}:in `{}'", location, symbol));
+            }
+        } else if let Some((_, s)) = line.split_once(": ") {
+            symbol = Some(s);
+        }
+    }
+    lines
+}
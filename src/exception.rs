@@ -20,12 +20,13 @@ use rb_sys::{
 
 use crate::{
     class::{Class, RClass},
-    error::Error,
-    into_value::{ArgList, IntoValue},
+    error::{Error, EXCEPTION_DATA_IVAR},
+    into_value::{ArgList, IntoValue, KwSplat},
     module::Module,
     object::Object,
     r_array::RArray,
     try_convert::TryConvert,
+    typed_data::TypedData,
     value::{
         private::{self, ReprValue as _},
         NonZeroValue, ReprValue, Value,
@@ -88,6 +89,163 @@ impl Exception {
         unsafe { ExceptionClass::from_rb_value_unchecked(self.class().as_rb_value()) }
     }
 
+    /// Returns the exception's message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{Error, Exception, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let e: Exception = ruby.eval(r#"StandardError.new("example")"#)?;
+    ///     assert_eq!(e.message()?, "example");
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    pub fn message(self) -> Result<String, Error> {
+        self.funcall("message", ())
+    }
+
+    /// Returns the exception's message, along with the class name and
+    /// backtrace, formatted as Ruby would print an uncaught exception.
+    ///
+    /// `highlight` controls whether the message is highlighted with ANSI
+    /// escape sequences, and `order` controls whether the backtrace is
+    /// printed with the innermost frame first or last.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{exception::BacktraceOrder, Error, Exception, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let e: Exception = ruby.eval(r#"StandardError.new("example")"#)?;
+    ///     e.set_backtrace(vec!["lib/example.rb:1:in `example'".to_owned()])?;
+    ///     let msg = e.full_message(false, BacktraceOrder::Top)?;
+    ///     assert!(msg.starts_with("lib/example.rb:1:in `example': example (StandardError)"));
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    pub fn full_message(self, highlight: bool, order: BacktraceOrder) -> Result<String, Error> {
+        let handle = Ruby::get_with(self);
+        let kwargs = handle.hash_new();
+        kwargs.aset(handle.to_symbol("highlight"), highlight)?;
+        kwargs.aset(
+            handle.to_symbol("order"),
+            handle.to_symbol(match order {
+                BacktraceOrder::Top => "top",
+                BacktraceOrder::Bottom => "bottom",
+            }),
+        )?;
+        self.funcall("full_message", (KwSplat(kwargs),))
+    }
+
+    /// Returns the exception that was being handled when `self` was raised,
+    /// if any.
+    ///
+    /// See also [`Error::with_cause`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, Error, Exception, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let e: Exception = ruby.eval(
+    ///         r#"
+    ///           begin
+    ///             begin
+    ///               raise ArgumentError
+    ///             rescue
+    ///               raise "example"
+    ///             end
+    ///           rescue => e
+    ///             e
+    ///           end
+    ///         "#,
+    ///     )?;
+    ///     let cause = e.cause()?.unwrap();
+    ///     assert!(cause.is_kind_of(ruby.exception_arg_error()));
+    ///     assert!(cause.cause()?.is_none());
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    pub fn cause(self) -> Result<Option<Exception>, Error> {
+        self.funcall("cause", ())
+    }
+
+    /// Returns a reference to the Rust data wrapped by `self`, if `self` was
+    /// created with [`Error::new_with_data`] wrapping a `T`, or is a copy of
+    /// such an exception.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, Error, Exception, Ruby};
+    ///
+    /// #[magnus::wrap(class = "Limit")]
+    /// struct Limit(usize);
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     ruby.define_class("Limit", ruby.class_object())?;
+    ///     let err = Error::new_with_data(ruby.exception_arg_error(), "too big", Limit(10));
+    ///     let ex = Exception::from_value(err.value().unwrap()).unwrap();
+    ///     assert_eq!(ex.data::<Limit>().unwrap().0, 10);
+    ///
+    ///     let ex: Exception = ruby.eval("ArgumentError.new")?;
+    ///     assert!(ex.data::<Limit>().is_none());
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    pub fn data<T>(&self) -> Option<&T>
+    where
+        T: TypedData,
+    {
+        let data: Value = self.ivar_get(EXCEPTION_DATA_IVAR).ok()?;
+        <&T>::try_convert(data).ok()
+    }
+
+    /// Sets the backtrace of `self`.
+    ///
+    /// Each line should be in the same format as Ruby's own backtrace lines,
+    /// such as ``"lib/example.rb:1:in `example'"``.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{rb_assert, Error, Exception, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let e: Exception = ruby.eval(r#"StandardError.new("example")"#)?;
+    ///     e.set_backtrace(vec![format!("{}:{}:in `example'", file!(), line!())])?;
+    ///     rb_assert!(ruby, "e.backtrace.first.end_with?(\"in `example'\")", e);
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    pub fn set_backtrace<I, T>(self, backtrace: I) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<str>,
+    {
+        let handle = Ruby::get_with(self);
+        let ary = handle.ary_new();
+        for line in backtrace {
+            ary.push(handle.str_new(line.as_ref()))?;
+        }
+        self.funcall::<_, _, Value>("set_backtrace", (ary,))?;
+        Ok(())
+    }
+
     #[doc(hidden)]
     #[deprecated(
         since = "0.6.0",
@@ -98,6 +256,15 @@ impl Exception {
     }
 }
 
+/// The order of the backtrace in [`Exception::full_message`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BacktraceOrder {
+    /// The innermost frame is printed first, along with the error message.
+    Top,
+    /// The innermost frame is printed last, along with the error message.
+    Bottom,
+}
+
 impl fmt::Display for Exception {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", unsafe { self.to_s_infallible() })
@@ -133,6 +300,8 @@ impl IntoValue for Exception {
     }
 }
 
+impl Object for Exception {}
+
 unsafe impl private::ReprValue for Exception {}
 
 impl ReprValue for Exception {}
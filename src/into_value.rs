@@ -2,6 +2,7 @@ use seq_macro::seq;
 
 use crate::{
     r_array::RArray,
+    r_hash::IntoRHash,
     value::{ReprValue, Value},
     Ruby,
 };
@@ -63,8 +64,36 @@ pub trait IntoValue: Sized {
 /// This trait must not be implemented for types that contain `Value`.
 pub unsafe trait IntoValueFromNative: IntoValue {}
 
+/// Wrapper for a hash-like type, marking it as keyword arguments when used as
+/// the last element of an [`ArgList`] tuple.
+///
+/// Ruby 3 distinguishes between a method being passed a `Hash` as a final
+/// positional argument and being passed keyword arguments. Without this
+/// wrapper a hash is passed positionally.
+///
+/// # Examples
+///
+/// ```
+/// use magnus::{prelude::*, Error, KwSplat, Ruby, Value};
+///
+/// fn example(ruby: &Ruby) -> Result<(), Error> {
+///     let obj: Value = ruby.eval("o = Object.new; def o.test(a, b:); [a, b]; end; o")?;
+///     let kwargs = ruby.hash_new();
+///     kwargs.aset(ruby.to_symbol("b"), 2)?;
+///     let res: (i64, i64) = obj.funcall("test", (1, KwSplat(kwargs)))?;
+///     assert_eq!(res, (1, 2));
+///
+///     Ok(())
+/// }
+/// # Ruby::init(example).unwrap()
+/// ```
+pub struct KwSplat<T>(pub T);
+
 /// Trait for types that can be used as an arguments list when calling Ruby
 /// methods.
+///
+/// This is implemented for tuples of values implementing [`IntoValue`], and
+/// for tuples where the last element is [`KwSplat`] to pass keyword arguments.
 pub trait ArgList {
     /// The specific Ruby value type.
     type Value: ReprValue;
@@ -74,6 +103,14 @@ pub trait ArgList {
 
     /// Convert `self` into a type that can be used as a Ruby argument list.
     fn into_arg_list_with(self, handle: &Ruby) -> Self::Output;
+
+    /// Whether the last argument of the list should be passed as keyword
+    /// arguments.
+    ///
+    /// Defaults to `false`.
+    fn contains_kw_args(&self) -> bool {
+        false
+    }
 }
 
 /// # Safety
@@ -112,10 +149,38 @@ macro_rules! impl_arg_list {
     }
 }
 
-seq!(N in 0..=12 {
+seq!(N in 0..=32 {
     impl_arg_list!(N);
 });
 
+macro_rules! impl_arg_list_kw {
+    ($n:literal) => {
+        seq!(N in 0..$n {
+            impl<#(T~N,)* TKw> ArgList for (#(T~N,)* KwSplat<TKw>,)
+            where
+                #(T~N: IntoValue,)*
+                TKw: IntoRHash,
+            {
+                type Value = Value;
+                type Output = [Self::Value; $n + 1];
+
+                fn into_arg_list_with(self, handle: &Ruby) -> Self::Output {
+                    let (#(t~N,)* kw,) = self;
+                    [#(handle.into_value(t~N),)* kw.0.into_r_hash_with(handle).as_value()]
+                }
+
+                fn contains_kw_args(&self) -> bool {
+                    true
+                }
+            }
+        });
+    }
+}
+
+seq!(N in 0..32 {
+    impl_arg_list_kw!(N);
+});
+
 impl<T, const N: usize> ArgList for [T; N]
 where
     T: ReprValue,
@@ -134,6 +199,14 @@ pub trait RArrayArgList {
     /// Convert `self` into a type that can be used as a Ruby Proc argument
     /// list.
     fn into_array_arg_list_with(self, handle: &Ruby) -> RArray;
+
+    /// Whether the last argument of the list should be passed as keyword
+    /// arguments.
+    ///
+    /// Defaults to `false`.
+    fn contains_kw_args(&self) -> bool {
+        false
+    }
 }
 
 impl RArrayArgList for RArray {
@@ -149,4 +222,8 @@ where
     fn into_array_arg_list_with(self, handle: &Ruby) -> RArray {
         handle.ary_new_from_values(self.into_arg_list_with(handle).as_ref())
     }
+
+    fn contains_kw_args(&self) -> bool {
+        ArgList::contains_kw_args(self)
+    }
 }
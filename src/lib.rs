@@ -293,9 +293,9 @@
 // * `rb_assoc_new`:
 //! * `rb_attr`: [`Module::define_attr`].
 // * `rb_attr_get`:
-// * `rb_autoload`:
+//! * `rb_autoload`: [`Module::autoload`].
 // * `rb_autoload_load`:
-// * `rb_autoload_p`:
+//! * `rb_autoload_p`: [`Module::autoload_path`].
 //!
 //! ## `rb_b`
 //!
@@ -337,7 +337,7 @@
 // * `rb_big_xor`:
 //! * `rb_binding_new`: [`Binding::new`]. Unimplemented >= Ruby 3.2.
 //! * `rb_block_call`: [`Value::block_call`].
-// * `rb_block_call_kw`:
+//! * `rb_block_call_kw`: See [`KwSplat`] and [`Value::block_call`].
 //! * `rb_block_given_p`: [`block::block_given`].
 // * `rb_block_lambda`:
 //! * `rb_block_proc`: [`block::block_proc`].
@@ -348,11 +348,11 @@
 //! ## `rb_c`
 //!
 //! * `rb_call_super`: [`call_super`].
-// * `rb_call_super_kw`:
-// * `rb_catch`:
-// * `rb_catch_obj`:
+//! * `rb_call_super_kw`: See [`KwSplat`] and [`Ruby::call_super`].
+//! * `rb_catch`: Similar to [`Ruby::catch`].
+//! * `rb_catch_obj`: [`Ruby::catch`].
 // * `rb_category_compile_warn`:
-// * `rb_category_warn`:
+//! * `rb_category_warn`: Similar to [`Ruby::warn_category`].
 // * `rb_category_warning`:
 // * `rb_char_to_option_kcode`:
 //! * `rb_check_arity`: [`scan_args::check_arity`].
@@ -385,7 +385,7 @@
 //! * `rb_class_name`: Simmilar to [`Value::classname`].
 //! * `rb_class_new`: [`RClass::new`].
 //! * `rb_class_new_instance`: [`RClass::new_instance`].
-// * `rb_class_new_instance_kw`:
+//! * `rb_class_new_instance_kw`: See [`KwSplat`] and [`RClass::new_instance`].
 // * `rb_class_new_instance_pass_kw`:
 // * `rb_class_of`:
 // * `rb_class_path`:
@@ -435,14 +435,14 @@
 //! * `rb_complex_real`: [`RComplex::real`].
 // * `rb_complex_sub`:
 // * `rb_complex_uminus`:
-// * `rb_const_defined`:
+//! * `rb_const_defined`: [`Module::const_defined`].
 // * `rb_const_defined_at`:
 // * `rb_const_defined_from`:
 //! * `rb_const_get`: [`Module::const_get`].
 // * `rb_const_get_at`:
 // * `rb_const_get_from`:
 // * `rb_const_list`:
-// * `rb_const_remove`:
+//! * `rb_const_remove`: [`Module::remove_const`].
 //! * `rb_const_set`: [`Module::const_set`].
 // * `rb_convert_type`:
 // * `rb_copy_generic_ivar`:
@@ -450,10 +450,10 @@
 // * `rb_cstr_to_dbl`:
 // * `rb_cstr_to_inum`:
 //! * `rb_current_receiver`: [`current_receiver`].
-// * `rb_cvar_defined`:
+//! * `rb_cvar_defined`: [`Module::cvar_defined`].
 // * `rb_cvar_find`:
-// * `rb_cvar_get`:
-// * `rb_cvar_set`:
+//! * `rb_cvar_get`: [`Module::cvar_get`].
+//! * `rb_cvar_set`: [`Module::cvar_set`].
 // * `rb_cv_get`:
 // * `rb_cv_set`:
 //!
@@ -492,7 +492,8 @@
 // * `rb_define_finalizer`:
 // * `rb_define_global_const`:
 //! * `rb_define_global_function`: [`define_global_function`].
-// * `rb_define_hooked_variable`:
+//! * `rb_define_hooked_variable`: See [`Ruby::define_virtual_variable`] and
+//!   [`Ruby::define_readonly_virtual_variable`].
 //! * `rb_define_method`: See [`Module::define_method`].
 //! * `rb_define_method_id`: [`Module::define_method`].
 //! * `rb_define_module`: [`define_module`].
@@ -502,7 +503,7 @@
 //! * `rb_define_module_under`: See [`Module::define_module`].
 //! * `rb_define_private_method`: [`Module::define_private_method`].
 //! * `rb_define_protected_method`: [`Module::define_protected_method`].
-// * `rb_define_readonly_variable`:
+//! * `rb_define_readonly_variable`: [`Ruby::define_readonly_variable`].
 //! * `rb_define_singleton_method`: [`Object::define_singleton_method`].
 //! * `rb_define_variable`: [`define_variable`].
 // * `rb_define_virtual_variable`:
@@ -642,7 +643,7 @@
 // * `rb_enc_vsprintf`:
 //!
 //! ## `rb_en`-`rb_ez`
-// * `rb_ensure`:
+//! * `rb_ensure`: [`Ruby::ensure`].
 //! * `rb_enumeratorize`: See [`Value::enumeratorize`].
 //! * `rb_enumeratorize_with_size`: Simmilar to [`Value::enumeratorize`].
 // * `rb_enumeratorize_with_size_kw`:
@@ -792,13 +793,13 @@
 // * `rb_frozen_error_raise`:
 //! * `rb_funcall`: See [`Value::funcall`].
 //! * `rb_funcallv`: [`Value::funcall`].
-// * `rb_funcallv_kw`:
+//! * `rb_funcallv_kw`: See [`KwSplat`] and [`Value::funcall`].
 //! * `rb_funcallv_public`: [`Value::funcall_public`].
-// * `rb_funcallv_public_kw`:
+//! * `rb_funcallv_public_kw`: See [`KwSplat`] and [`Value::funcall_public`].
 // * `rb_funcall_passing_block`:
 // * `rb_funcall_passing_block_kw`:
 //! * `rb_funcall_with_block`: [`Value::funcall_with_block`].
-// * `rb_funcall_with_block_kw`:
+//! * `rb_funcall_with_block_kw`: See [`KwSplat`] and [`Value::funcall_with_block`].
 // * `rb_f_abort`:
 // * `rb_f_exec`:
 // * `rb_f_exit`:
@@ -988,8 +989,8 @@
 //! * `rb_iter_break`: See [`Error::iter_break`].
 //! * `rb_iter_break_value`: [`Error::iter_break`].
 // * `rb_ivar_count`:
-// * `rb_ivar_defined`:
-// * `rb_ivar_foreach`:
+//! * `rb_ivar_defined`: [`Object::ivar_defined`].
+//! * `rb_ivar_foreach`: [`Object::ivar_foreach`].
 //! * `rb_ivar_get`: [`Object::ivar_get`].
 //! * `rb_ivar_set`: [`Object::ivar_set`].
 // * `rb_iv_get`:
@@ -998,7 +999,7 @@
 //! ## `rb_j`-`rb_k`
 //!
 //! * `rb_jump_tag`: Return [`Error`].
-// * `rb_keyword_given_p`:
+//! * `rb_keyword_given_p`: See [`FromKwArgs`] and [`method!`].
 //!
 //! ## `rb_l`
 // * `rb_lastline_get`:
@@ -1060,8 +1061,8 @@
 // * `rb_mem_clear`:
 // * `rb_method_basic_definition_p`:
 // * `rb_method_boundp`:
-// * `rb_method_call`:
-// * `rb_method_call_kw`:
+//! * `rb_method_call`: [`RMethod::call`].
+//! * `rb_method_call_kw`: See [`KwSplat`] and [`RMethod::call`].
 // * `rb_method_call_with_block`:
 // * `rb_method_call_with_block_kw`:
 //! * `rb_module_new`: [`RModule::new`].
@@ -1178,17 +1179,17 @@
 // * `rb_obj_init_copy`:
 // * `rb_obj_instance_eval`:
 // * `rb_obj_instance_exec`:
-// * `rb_obj_instance_variables`:
+//! * `rb_obj_instance_variables`: [`Object::instance_variables`].
 // * `rb_obj_is_fiber`:
 // * `rb_obj_is_instance_of`:
 //! * `rb_obj_is_kind_of`: [`Value::is_kind_of`].
 // * `rb_obj_is_method`:
 //! * `rb_obj_is_proc`: [`Proc::from_value`](block::Proc::from_value).
-// * `rb_obj_method`:
+//! * `rb_obj_method`: [`ReprValue::method`].
 // * `rb_obj_method_arity`:
 // * `RB_OBJ_PROMOTED`:
 // * `RB_OBJ_PROMOTED_RAW`:
-// * `rb_obj_remove_instance_variable`:
+//! * `rb_obj_remove_instance_variable`: [`Object::remove_ivar`].
 //! * `rb_obj_respond_to`: [`Value::respond_to`].
 // * `rb_obj_reveal`:
 // * `rb_obj_setup`:
@@ -1213,7 +1214,7 @@
 // * `rb_prepend_module`: [`Module::prepend_module`].
 //! * `rb_proc_arity`: [`Proc::arity`](block::Proc::arity).
 //! * `rb_proc_call`: [`Proc::call`](block::Proc::call).
-// * `rb_proc_call_kw`:
+//! * `rb_proc_call_kw`: See [`KwSplat`] and [`Proc::call`](block::Proc::call).
 // * `rb_proc_call_with_block`:
 // * `rb_proc_call_with_block_kw`:
 // * `rb_proc_exec`:
@@ -1313,8 +1314,8 @@
 // * `rb_remove_method_id`:
 //! * `rb_require`: [`require`].
 //! * `rb_require_string`: [`require`].
-// * `rb_rescue`:
-// * `rb_rescue2`:
+//! * `rb_rescue`: Similar to [`Ruby::rescue`].
+//! * `rb_rescue2`: [`Ruby::rescue`].
 // * `RB_RESERVED_FD_P`:
 // * `rb_reserved_fd_p`:
 // * `rb_reset_random_seed`:
@@ -1536,8 +1537,8 @@
 // * `rb_thread_wait_for`:
 // * `rb_thread_wakeup`:
 // * `rb_thread_wakeup_alive`:
-// * `rb_throw`:
-// * `rb_throw_obj`:
+//! * `rb_throw`: Similar to [`Ruby::throw`].
+//! * `rb_throw_obj`: [`Ruby::throw`].
 // * `rb_timespec_now`:
 // * `rb_time_interval`:
 // * `rb_time_nano_new`:
@@ -1575,7 +1576,7 @@
 // * `rb_tracepoint_new`:
 // * `rb_trap_exit`:
 // * `rb_type`:
-// * `rb_typeddata_inherited_p`:
+//! * `rb_typeddata_inherited_p`: [`DataType::is_inherited`].
 // * `rb_typeddata_is_kind_of`:
 // * `RB_TYPE_P`:
 // * `rb_type_p`:
@@ -1621,7 +1622,7 @@
 // * `rb_w32_fd_copy`:
 // * `rb_w32_fd_dup`:
 // * `rb_waitpid`:
-// * `rb_warn`:
+//! * `rb_warn`: [`Ruby::warn`].
 //! * `rb_warning`: [`error::warning`].
 // * `rb_write_error`:
 // * `rb_write_error2`:
@@ -1633,7 +1634,7 @@
 //!   See [`block::yield_values`] / return [`block::YieldValues`].
 //! * `rb_yield_values2`:
 //!   [`block::yield_values`] / return [`block::YieldValues`].
-// * `rb_yield_values_kw`:
+//! * `rb_yield_values_kw`: See [`KwSplat`] and [`Ruby::yield_values`].
 // * `RB_ZALLOC`:
 // * `RB_ZALLOC_N`:
 //!
@@ -1794,6 +1795,7 @@ mod api;
 mod binding;
 pub mod block;
 pub mod class;
+mod compat;
 #[cfg(feature = "embed")]
 #[cfg_attr(docsrs, doc(cfg(feature = "embed")))]
 pub mod embed;
@@ -1824,6 +1826,7 @@ mod r_file;
 mod r_float;
 pub mod r_hash;
 mod r_match;
+mod r_method;
 mod r_object;
 mod r_rational;
 pub mod r_regexp;
@@ -1834,24 +1837,30 @@ mod range;
 #[cfg(feature = "rb-sys")]
 #[cfg_attr(docsrs, doc(cfg(feature = "rb-sys")))]
 pub mod rb_sys;
+#[cfg(feature = "registry")]
+#[cfg_attr(docsrs, doc(cfg(feature = "registry")))]
+pub mod registry;
 pub mod scan_args;
 pub mod symbol;
 pub mod try_convert;
 pub mod typed_data;
 pub mod value;
 
-use std::{ffi::CString, mem::transmute, os::raw::c_int};
+use std::{ffi::CString, mem::transmute, os::raw::c_int, panic::AssertUnwindSafe};
 
 #[cfg(ruby_lt_2_7)]
 use ::rb_sys::rb_require;
 #[cfg(ruby_gte_2_7)]
 use ::rb_sys::rb_require_string;
 use ::rb_sys::{
-    rb_backref_get, rb_call_super, rb_current_receiver, rb_define_class, rb_define_global_const,
-    rb_define_global_function, rb_define_module, rb_define_variable, rb_errinfo,
-    rb_eval_string_protect, rb_set_errinfo, VALUE,
+    rb_backref_get, rb_current_receiver, rb_define_class, rb_define_global_const,
+    rb_define_global_function, rb_define_hooked_variable, rb_define_module,
+    rb_define_readonly_variable, rb_define_variable, rb_errinfo, rb_eval_string_protect,
+    rb_set_errinfo, ID, VALUE,
+};
+pub use magnus_macros::{
+    init, methods, register, wrap, DataTypeFunctions, FromKwArgs, RubyError, ScanArgs, TypedData,
 };
-pub use magnus_macros::{init, wrap, DataTypeFunctions, TypedData};
 
 #[cfg(ruby_use_flonum)]
 pub use crate::value::Flonum;
@@ -1863,7 +1872,7 @@ pub use crate::{
     exception::{Exception, ExceptionClass},
     float::Float,
     integer::Integer,
-    into_value::{ArgList, IntoValue, IntoValueFromNative, RArrayArgList},
+    into_value::{ArgList, IntoValue, IntoValueFromNative, KwSplat, RArrayArgList},
     module::{Attr, Module, RModule},
     numeric::Numeric,
     object::Object,
@@ -1874,6 +1883,7 @@ pub use crate::{
     r_float::RFloat,
     r_hash::RHash,
     r_match::RMatch,
+    r_method::{RMethod, RUnboundMethod},
     r_object::RObject,
     r_rational::RRational,
     r_regexp::RRegexp,
@@ -1881,6 +1891,7 @@ pub use crate::{
     r_struct::RStruct,
     r_typed_data::RTypedData,
     range::Range,
+    scan_args::{FromKwArgs, ScanArgs},
     symbol::Symbol,
     try_convert::TryConvert,
     typed_data::{DataType, DataTypeFunctions, TypedData},
@@ -1892,7 +1903,8 @@ pub use crate::{
     value::{QFALSE, QNIL, QTRUE},
 };
 use crate::{
-    error::protect,
+    compat::rb_call_super_kw,
+    error::{protect, raise},
     method::Method,
     r_string::IntoRString,
     value::{private::ReprValue as _, ReprValue},
@@ -2074,6 +2086,26 @@ impl Ruby {
             .map(|c| unsafe { ExceptionClass::from_value_unchecked(c.as_value()) })
     }
 
+    /// Returns the module or class at `path`, e.g. `"Foo::Bar"`, defining any
+    /// modules in the path that don't yet exist.
+    pub(crate) fn define_namespace(&self, path: &str) -> Result<Value, Error> {
+        let mut namespace = self.class_object().as_value();
+        for name in path.split("::") {
+            let defined: bool = namespace.funcall("const_defined?", (name, false))?;
+            namespace = if defined {
+                namespace.funcall("const_get", (name, false))?
+            } else {
+                match RClass::from_value(namespace) {
+                    Some(namespace) => namespace.define_module(name)?.as_value(),
+                    None => RModule::try_convert(namespace)?
+                        .define_module(name)?
+                        .as_value(),
+                }
+            };
+        }
+        Ok(namespace)
+    }
+
     /// Define a global variable.
     ///
     /// # Examples
@@ -2109,6 +2141,243 @@ impl Ruby {
         Ok(ptr)
     }
 
+    /// Define a read-only global variable.
+    ///
+    /// Attempting to assign to the variable from Ruby will raise a
+    /// `NameError`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{rb_assert, Error, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     ruby.define_readonly_variable("example", 42)?;
+    ///     rb_assert!(ruby, "$example == 42");
+    ///
+    ///     let res = ruby.eval::<bool>("$example = 1");
+    ///     assert!(res.unwrap_err().is_kind_of(ruby.exception_name_error()));
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    pub fn define_readonly_variable<T>(&self, name: &str, value: T) -> Result<(), Error>
+    where
+        T: IntoValue,
+    {
+        let value = self.into_value(value);
+        debug_assert_value!(value);
+        let name = CString::new(name).unwrap();
+        let ptr = Box::into_raw(Box::new(value));
+        unsafe {
+            rb_define_readonly_variable(name.as_ptr(), ptr as *const VALUE);
+        }
+        Ok(())
+    }
+
+    /// Define a global variable with a getter and setter implemented in Rust.
+    ///
+    /// The variable has no storage of its own. `getter` is called every time
+    /// the variable is read, and `setter` every time it is assigned. The
+    /// assigned value is converted to `U` before being passed to `setter`. If
+    /// the conversion fails, or `setter` returns an error, that error is
+    /// raised from the assignment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    ///
+    /// use magnus::{rb_assert, Error, Ruby};
+    ///
+    /// static DEBUG: AtomicBool = AtomicBool::new(false);
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     ruby.define_virtual_variable(
+    ///         "example_debug",
+    ///         |_ruby| Ok(DEBUG.load(Ordering::Relaxed)),
+    ///         |_ruby, val: bool| {
+    ///             DEBUG.store(val, Ordering::Relaxed);
+    ///             Ok(())
+    ///         },
+    ///     )?;
+    ///
+    ///     rb_assert!(ruby, "$example_debug == false");
+    ///     let _: bool = ruby.eval("$example_debug = true")?;
+    ///     assert!(DEBUG.load(Ordering::Relaxed));
+    ///     rb_assert!(ruby, "$example_debug == true");
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    ///
+    /// Validating assignment:
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicI64, Ordering};
+    ///
+    /// use magnus::{rb_assert, Error, Ruby};
+    ///
+    /// static LEVEL: AtomicI64 = AtomicI64::new(1);
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     ruby.define_virtual_variable(
+    ///         "example_level",
+    ///         |_ruby| Ok(LEVEL.load(Ordering::Relaxed)),
+    ///         |ruby, val: i64| {
+    ///             if !(0..=3).contains(&val) {
+    ///                 return Err(Error::new(
+    ///                     ruby.exception_arg_error(),
+    ///                     "level must be between 0 and 3",
+    ///                 ));
+    ///             }
+    ///             LEVEL.store(val, Ordering::Relaxed);
+    ///             Ok(())
+    ///         },
+    ///     )?;
+    ///
+    ///     rb_assert!(ruby, "$example_level == 1");
+    ///     rb_assert!(ruby, "($example_level = 9 rescue $!).is_a?(ArgumentError)");
+    ///     rb_assert!(ruby, r#"($example_level = "2" rescue $!).is_a?(TypeError)"#);
+    ///     rb_assert!(ruby, "$example_level == 1");
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    pub fn define_virtual_variable<G, T, S, U>(
+        &self,
+        name: &str,
+        getter: G,
+        setter: S,
+    ) -> Result<(), Error>
+    where
+        G: 'static + Send + Fn(&Ruby) -> Result<T, Error>,
+        T: IntoValue,
+        S: 'static + Send + Fn(&Ruby, U) -> Result<(), Error>,
+        U: TryConvert,
+    {
+        self.define_hooked_variable(name, getter, Some(setter))
+    }
+
+    /// Define a read-only global variable with a getter implemented in Rust.
+    ///
+    /// `getter` is called every time the variable is read. Attempting to
+    /// assign to the variable from Ruby will raise a `NameError`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{rb_assert, Error, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     ruby.define_readonly_virtual_variable("example_pid", |_ruby| {
+    ///         Ok(std::process::id())
+    ///     })?;
+    ///
+    ///     rb_assert!(ruby, "$example_pid == Process.pid");
+    ///     rb_assert!(ruby, "($example_pid = 1 rescue $!).is_a?(NameError)");
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    pub fn define_readonly_virtual_variable<G, T>(&self, name: &str, getter: G) -> Result<(), Error>
+    where
+        G: 'static + Send + Fn(&Ruby) -> Result<T, Error>,
+        T: IntoValue,
+    {
+        self.define_hooked_variable::<_, _, fn(&Ruby, Value) -> Result<(), Error>, _>(
+            name, getter, None,
+        )
+    }
+
+    fn define_hooked_variable<G, T, S, U>(
+        &self,
+        name: &str,
+        getter: G,
+        setter: Option<S>,
+    ) -> Result<(), Error>
+    where
+        G: 'static + Send + Fn(&Ruby) -> Result<T, Error>,
+        T: IntoValue,
+        S: 'static + Send + Fn(&Ruby, U) -> Result<(), Error>,
+        U: TryConvert,
+    {
+        unsafe extern "C" fn get<G, T, S>(_id: ID, data: *mut VALUE) -> VALUE
+        where
+            G: Fn(&Ruby) -> Result<T, Error>,
+            T: IntoValue,
+        {
+            let var = &*(data as *const HookedVariable<G, S>);
+            let ruby = Ruby::get_unchecked();
+            let res = match error::catch_unwind(AssertUnwindSafe(|| {
+                (var.getter)(&ruby).map(|v| ruby.into_value(v))
+            })) {
+                Ok(v) => v,
+                Err(e) => Err(Error::from_panic(e)),
+            };
+            match res {
+                Ok(v) => v.as_rb_value(),
+                Err(e) => raise(e),
+            }
+        }
+
+        unsafe extern "C" fn set<G, S, U>(val: VALUE, _id: ID, data: *mut VALUE)
+        where
+            S: Fn(&Ruby, U) -> Result<(), Error>,
+            U: TryConvert,
+        {
+            let var = &*(data as *const HookedVariable<G, S>);
+            let ruby = Ruby::get_unchecked();
+            let res = match var.setter {
+                Some(ref setter) => match error::catch_unwind(AssertUnwindSafe(|| {
+                    U::try_convert(Value::new(val)).and_then(|v| setter(&ruby, v))
+                })) {
+                    Ok(v) => v,
+                    Err(e) => Err(Error::from_panic(e)),
+                },
+                None => Err(Error::new(
+                    ruby.exception_name_error(),
+                    format!("{} is a read-only variable", var.name),
+                )),
+            };
+            if let Err(e) = res {
+                raise(e)
+            }
+        }
+
+        let get_func = get::<G, T, S> as unsafe extern "C" fn(ID, *mut VALUE) -> VALUE;
+        #[cfg(ruby_lt_2_7)]
+        let get_func: unsafe extern "C" fn() -> VALUE = unsafe { transmute(get_func) };
+        let set_func = set::<G, S, U> as unsafe extern "C" fn(VALUE, ID, *mut VALUE);
+        #[cfg(ruby_lt_2_7)]
+        let set_func: unsafe extern "C" fn() = unsafe { transmute(set_func) };
+
+        let var = Box::into_raw(Box::new(HookedVariable {
+            value: self.qnil().as_value(),
+            name: if name.starts_with('$') {
+                name.to_owned()
+            } else {
+                format!("${}", name)
+            },
+            getter,
+            setter,
+        }));
+        let name = CString::new(name).unwrap();
+        unsafe {
+            rb_define_hooked_variable(
+                name.as_ptr(),
+                var as *mut VALUE,
+                Some(get_func),
+                Some(set_func),
+            );
+        }
+        Ok(())
+    }
+
     /// Define a global constant.
     ///
     /// # Examples
@@ -2270,12 +2539,14 @@ impl Ruby {
         T: TryConvert,
     {
         unsafe {
+            let kw_splat = ArgList::contains_kw_args(&args) as c_int;
             let args = args.into_arg_list_with(self);
             let slice = args.as_ref();
             protect(|| {
-                Value::new(rb_call_super(
+                Value::new(rb_call_super_kw(
                     slice.len() as c_int,
                     slice.as_ptr() as *const VALUE,
+                    kw_splat,
                 ))
             })
             .and_then(TryConvert::try_convert)
@@ -2375,6 +2646,17 @@ impl Ruby {
     }
 }
 
+// Storage for a global variable defined with `rb_define_hooked_variable`.
+// Ruby's default marker for hooked variables marks the `VALUE` the variable's
+// data points to, so `value` must be the first field.
+#[repr(C)]
+struct HookedVariable<G, S> {
+    value: Value,
+    name: String,
+    getter: G,
+    setter: Option<S>,
+}
+
 /// Define a class in the root scope.
 ///
 /// # Panics
@@ -4,7 +4,10 @@
 #![allow(clippy::many_single_char_names)]
 #![allow(clippy::missing_safety_doc)]
 
-use std::{ffi::c_void, os::raw::c_int, panic::AssertUnwindSafe, slice};
+use std::{
+    any::type_name, ffi::c_void, marker::PhantomData, os::raw::c_int, panic::AssertUnwindSafe,
+    slice,
+};
 
 use seq_macro::seq;
 
@@ -13,9 +16,10 @@ use crate::{
         do_yield_iter, do_yield_splat_iter, do_yield_values_iter, Proc, Yield, YieldSplat,
         YieldValues,
     },
-    error::{raise, Error},
+    error::{catch_unwind, raise, Error},
     into_value::{ArgList, IntoValue},
     r_array::RArray,
+    scan_args::{split_kwargs, FromKwArgs, KwArgs, ScanArgs},
     try_convert::TryConvert,
     value::{ReprValue, Value},
     Ruby,
@@ -66,7 +70,7 @@ mod private {
         }
     }
 
-    seq!(N in 0..=16 {
+    seq!(N in 0..=15 {
         impl_method!(N);
     });
 
@@ -227,9 +231,9 @@ mod private {
 /// |     1 | `unsafe extern "C" fn(Value, Value) -> Value;`               |
 /// |     2 | `unsafe extern "C" fn(Value, Value, Value) -> Value;`        |
 /// |   ... | ...                                                          |
-/// |    16 | ...                                                          |
+/// |    15 | ...                                                          |
 ///
-/// note: for arity 0..=16 the number of arguments is 1 greater than the arity,
+/// note: for arity 0..=15 the number of arguments is 1 greater than the arity,
 /// due to the initial `self` argument.
 pub trait Method: private::Method {}
 
@@ -310,7 +314,7 @@ where
 {
     #[inline]
     unsafe fn call_handle_error(self) {
-        let res = match std::panic::catch_unwind(AssertUnwindSafe(|| (self)().into_init_return())) {
+        let res = match catch_unwind(AssertUnwindSafe(|| (self)().into_init_return())) {
             Ok(v) => v,
             Err(e) => Err(Error::from_panic(e)),
         };
@@ -340,7 +344,7 @@ where
 {
     #[inline]
     unsafe fn call_handle_error(self) {
-        let res = match std::panic::catch_unwind(AssertUnwindSafe(|| {
+        let res = match catch_unwind(AssertUnwindSafe(|| {
             (self)(&Ruby::get_unchecked()).into_init_return()
         })) {
             Ok(v) => v,
@@ -383,7 +387,7 @@ where
 
     #[inline]
     unsafe fn call_handle_error(self, argc: c_int, argv: *const Value, blockarg: Value) -> Value {
-        let res = match std::panic::catch_unwind(AssertUnwindSafe(|| {
+        let res = match catch_unwind(AssertUnwindSafe(|| {
             self.call_convert_value(argc, argv, blockarg)
         })) {
             Ok(v) => v,
@@ -426,9 +430,7 @@ where
 
     #[inline]
     unsafe fn call_handle_error(self, rb_self: Value, args: RArray) -> Value {
-        let res = match std::panic::catch_unwind(AssertUnwindSafe(|| {
-            self.call_convert_value(rb_self, args)
-        })) {
+        let res = match catch_unwind(AssertUnwindSafe(|| self.call_convert_value(rb_self, args))) {
             Ok(v) => v,
             Err(e) => Err(Error::from_panic(e)),
         };
@@ -473,9 +475,7 @@ where
 
     #[inline]
     unsafe fn call_handle_error(self, rb_self: Value, args: RArray) -> Value {
-        let res = match std::panic::catch_unwind(AssertUnwindSafe(|| {
-            self.call_convert_value(rb_self, args)
-        })) {
+        let res = match catch_unwind(AssertUnwindSafe(|| self.call_convert_value(rb_self, args))) {
             Ok(v) => v,
             Err(e) => Err(Error::from_panic(e)),
         };
@@ -519,7 +519,7 @@ where
 
     #[inline]
     unsafe fn call_handle_error(self, argc: c_int, argv: *const Value, rb_self: Value) -> Value {
-        let res = match std::panic::catch_unwind(AssertUnwindSafe(|| {
+        let res = match catch_unwind(AssertUnwindSafe(|| {
             self.call_convert_value(argc, argv, rb_self)
         })) {
             Ok(v) => v,
@@ -570,7 +570,7 @@ where
 
     #[inline]
     unsafe fn call_handle_error(self, argc: c_int, argv: *const Value, rb_self: Value) -> Value {
-        let res = match std::panic::catch_unwind(AssertUnwindSafe(|| {
+        let res = match catch_unwind(AssertUnwindSafe(|| {
             self.call_convert_value(argc, argv, rb_self)
         })) {
             Ok(v) => v,
@@ -591,6 +591,120 @@ where
 {
 }
 
+/// Helper trait for wrapping a function as a Ruby method taking self and a
+/// type implementing [`ScanArgs`], with type conversions and error handling.
+///
+/// See the [`method`](crate::method!) macro.
+#[doc(hidden)]
+pub trait MethodScanArgs<RbSelf, Args, Res>
+where
+    Self: Sized + Fn(RbSelf, Args) -> Res,
+    RbSelf: TryConvert,
+    Args: ScanArgs,
+    Res: ReturnValue,
+{
+    #[inline]
+    unsafe fn call_convert_value(
+        self,
+        argc: c_int,
+        argv: *const Value,
+        rb_self: Value,
+    ) -> Result<Value, Error> {
+        let handle = Ruby::get_with(rb_self);
+        let args = slice::from_raw_parts(argv, argc as usize);
+        (self)(
+            TryConvert::try_convert(rb_self)?,
+            Args::from_args(&handle, args)?,
+        )
+        .into_return_value()
+    }
+
+    #[inline]
+    unsafe fn call_handle_error(self, argc: c_int, argv: *const Value, rb_self: Value) -> Value {
+        let res = match catch_unwind(AssertUnwindSafe(|| {
+            self.call_convert_value(argc, argv, rb_self)
+        })) {
+            Ok(v) => v,
+            Err(e) => Err(Error::from_panic(e)),
+        };
+        match res {
+            Ok(v) => v,
+            Err(e) => raise(e),
+        }
+    }
+}
+
+impl<Func, RbSelf, Args, Res> MethodScanArgs<RbSelf, Args, Res> for Func
+where
+    Func: Fn(RbSelf, Args) -> Res,
+    RbSelf: TryConvert,
+    Args: ScanArgs,
+    Res: ReturnValue,
+{
+}
+
+/// Helper trait for wrapping a function as a Ruby method taking
+/// [`&Ruby`](Ruby), self, and a type implementing [`ScanArgs`], with type
+/// conversions and error handling.
+///
+/// See the [`method`](crate::method!) macro.
+#[doc(hidden)]
+pub trait RubyMethodScanArgs<RbSelf, Args, Res>
+where
+    Self: Sized + Fn(&Ruby, RbSelf, Args) -> Res,
+    RbSelf: TryConvert,
+    Args: ScanArgs,
+    Res: ReturnValue,
+{
+    #[inline]
+    unsafe fn call_convert_value(
+        self,
+        argc: c_int,
+        argv: *const Value,
+        rb_self: Value,
+    ) -> Result<Value, Error> {
+        let handle = Ruby::get_with(rb_self);
+        let args = slice::from_raw_parts(argv, argc as usize);
+        (self)(
+            &handle,
+            TryConvert::try_convert(rb_self)?,
+            Args::from_args(&handle, args)?,
+        )
+        .into_return_value()
+    }
+
+    #[inline]
+    unsafe fn call_handle_error(self, argc: c_int, argv: *const Value, rb_self: Value) -> Value {
+        let res = match catch_unwind(AssertUnwindSafe(|| {
+            self.call_convert_value(argc, argv, rb_self)
+        })) {
+            Ok(v) => v,
+            Err(e) => Err(Error::from_panic(e)),
+        };
+        match res {
+            Ok(v) => v,
+            Err(e) => raise(e),
+        }
+    }
+}
+
+impl<Func, RbSelf, Args, Res> RubyMethodScanArgs<RbSelf, Args, Res> for Func
+where
+    Func: Fn(&Ruby, RbSelf, Args) -> Res,
+    RbSelf: TryConvert,
+    Args: ScanArgs,
+    Res: ReturnValue,
+{
+}
+
+// Returns the argument at `i`, or `nil` if fewer arguments were given.
+#[inline]
+fn arg_or_nil(handle: &Ruby, args: &[Value], i: usize) -> Value {
+    args.get(i)
+        .copied()
+        .unwrap_or_else(|| handle.qnil().as_value())
+}
+
 macro_rules! method_n {
     ($name:ident, $ruby_name:ident, $n:literal) => {
         seq!(N in 0..$n {
@@ -617,7 +731,7 @@ macro_rules! method_n {
                 #[inline]
                 unsafe fn call_handle_error(self, rb_self: Value, #(arg~N: Value,)*) -> Value {
                     let res =
-                        match std::panic::catch_unwind(AssertUnwindSafe(|| {
+                        match catch_unwind(AssertUnwindSafe(|| {
                             self.call_convert_value(rb_self, #(arg~N,)*)
                         })) {
                             Ok(v) => v,
@@ -628,6 +742,15 @@ macro_rules! method_n {
                         Err(e) => raise(e),
                     }
                 }
+
+                #[inline]
+                fn select_wrapper(
+                    self,
+                    fixed: unsafe extern "C" fn(Value, #(Value,)*) -> Value,
+                    _argv: unsafe extern "C" fn(c_int, *const Value, Value) -> Value,
+                ) -> unsafe extern "C" fn(Value, #(Value,)*) -> Value {
+                    fixed
+                }
             }
 
             impl<Func, RbSelf, #(T~N,)* Res> $name<RbSelf, #(T~N,)* Res> for Func
@@ -663,7 +786,7 @@ macro_rules! method_n {
                 #[inline]
                 unsafe fn call_handle_error(self, rb_self: Value, #(arg~N: Value,)*) -> Value {
                     let res =
-                        match std::panic::catch_unwind(AssertUnwindSafe(|| {
+                        match catch_unwind(AssertUnwindSafe(|| {
                             self.call_convert_value(rb_self, #(arg~N,)*)
                         })) {
                             Ok(v) => v,
@@ -674,6 +797,15 @@ macro_rules! method_n {
                         Err(e) => raise(e),
                     }
                 }
+
+                #[inline]
+                fn select_wrapper(
+                    self,
+                    fixed: unsafe extern "C" fn(Value, #(Value,)*) -> Value,
+                    _argv: unsafe extern "C" fn(c_int, *const Value, Value) -> Value,
+                ) -> unsafe extern "C" fn(Value, #(Value,)*) -> Value {
+                    fixed
+                }
             }
 
             impl<Func, RbSelf, #(T~N,)* Res> $ruby_name<RbSelf, #(T~N,)* Res> for Func
@@ -687,862 +819,3241 @@ macro_rules! method_n {
     }
 }
 
-seq!(N in 0..=16 {
+seq!(N in 0..=15 {
     method_n!(Method~N, RubyMethod~N, N);
 });
 
-/// Wrap a Rust function item with Ruby type conversion and error handling.
-///
-/// This macro wraps the given function and returns a function pointer
-/// implementing the [`Method`] trait, suitable for passing to functions that
-/// define Ruby methods such as
-/// [`define_method`](crate::module::Module::define_method).
-///
-/// Ruby code implicitly always has a `self` parameter available. In the
-/// extention API this is passed explicitly. As a result there is always an
-/// extra `self` argument before the arguments explitly passed in Ruby, and the
-/// number of Rust argument will be one more than the Ruby arity.
-///
-/// The values `-2` and `-1` for `arity` have special meaning. Both indicate
-/// functions with any number of arguments, with `-2` the arguments are passed
-/// as a [`RArray`], with `-1` they are passed as a slice of [`Value`]s.
-/// Arity of `-1` can be used with [`scan_args`](crate::scan_args::scan_args)
-/// and [`get_kwargs`](crate::scan_args::get_kwargs) for more complex method
-/// signatures.
-///
-/// | Arity | Signature                                                 |
-/// |-------|-----------------------------------------------------------|
-/// |    -2 | `fn(rb_self: T, arguments: RArray) -> Result<R, Error>`   |
-/// |    -1 | `fn(rb_self: T, arguments: &[Value]) -> Result<R, Error>` |
-/// |     0 | `fn(rb_self: T) -> Result<R, Error>`                      |
-/// |     1 | `fn(rb_self: T, arg1: U) -> Result<R, Error>`             |
-/// |     2 | `fn(rb_self: T, arg1: U, arg2: V) -> Result<R, Error>`    |
-/// |   ... | ...                                                       |
-/// |    16 | ...                                                       |
-///
-/// Where `T`, `U`, `V` and so on are any types that implement `TryConvert`,
-/// and `R` implements [`IntoValue`]. It is also possible to return just `R`
-/// rather than a `Result` for functions that will never error, and omit the
-/// return value (i.e. return `()`) for a function that returns `nil` to Ruby.
-/// See [`ReturnValue`] for more details on what can be returned.
-///
-/// See the [`function`](crate::function!) macro for cases where there is no
-/// need to handle the `self` argument.
-///
-/// # Examples
-///
-/// ```
-/// use magnus::{class, define_class, method, prelude::*, Error};
-///
-/// fn rb_is_blank(rb_self: String) -> bool {
-///     rb_self.contains(|c: char| !c.is_whitespace())
-/// }
-///
-/// #[magnus::init]
-/// fn init() -> Result<(), Error> {
-///     let class = define_class("String", class::object())?;
-///     class.define_method("blank?", method!(rb_is_blank, 0))?;
-///     Ok(())
-/// }
-/// # let _cleanup = unsafe { magnus::embed::init() };
-/// # init().unwrap();
-/// ```
-#[macro_export]
-macro_rules! method {
-    ($name:expr, -2) => {{
-        unsafe extern "C" fn anon(rb_self: $crate::Value, args: $crate::RArray) -> $crate::Value {
-            use $crate::method::{MethodRbAry, RubyMethodRbAry};
-            $name.call_handle_error(rb_self, args)
-        }
-        anon as unsafe extern "C" fn($crate::Value, $crate::RArray) -> $crate::Value
-    }};
-    ($name:expr, -1) => {{
-        unsafe extern "C" fn anon(
-            argc: std::os::raw::c_int,
-            argv: *const $crate::Value,
-            rb_self: $crate::Value,
-        ) -> $crate::Value {
-            use $crate::method::{MethodCAry, RubyMethodCAry};
-            $name.call_handle_error(argc, argv, rb_self)
-        }
-        anon as unsafe extern "C" fn(
-            std::os::raw::c_int,
-            *const $crate::Value,
-            $crate::Value,
-        ) -> $crate::Value
-    }};
-    ($name:expr, 0) => {{
-        unsafe extern "C" fn anon(rb_self: $crate::Value) -> $crate::Value {
-            use $crate::method::{Method0, RubyMethod0};
-            $name.call_handle_error(rb_self)
-        }
-        anon as unsafe extern "C" fn($crate::Value) -> $crate::Value
-    }};
-    ($name:expr, 1) => {{
-        unsafe extern "C" fn anon(rb_self: $crate::Value, a: $crate::Value) -> $crate::Value {
-            use $crate::method::{Method1, RubyMethod1};
-            $name.call_handle_error(rb_self, a)
-        }
-        anon as unsafe extern "C" fn($crate::Value, $crate::Value) -> $crate::Value
-    }};
-    ($name:expr, 2) => {{
-        unsafe extern "C" fn anon(
-            rb_self: $crate::Value,
-            a: $crate::Value,
-            b: $crate::Value,
-        ) -> $crate::Value {
-            use $crate::method::{Method2, RubyMethod2};
-            $name.call_handle_error(rb_self, a, b)
-        }
-        anon as unsafe extern "C" fn($crate::Value, $crate::Value, $crate::Value) -> $crate::Value
-    }};
-    ($name:expr, 3) => {{
-        unsafe extern "C" fn anon(
-            rb_self: $crate::Value,
-            a: $crate::Value,
-            b: $crate::Value,
-            c: $crate::Value,
-        ) -> $crate::Value {
-            use $crate::method::{Method3, RubyMethod3};
-            $name.call_handle_error(rb_self, a, b, c)
-        }
-        anon as unsafe extern "C" fn(
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-        ) -> $crate::Value
-    }};
-    ($name:expr, 4) => {{
-        unsafe extern "C" fn anon(
-            rb_self: $crate::Value,
-            a: $crate::Value,
-            b: $crate::Value,
-            c: $crate::Value,
-            d: $crate::Value,
-        ) -> $crate::Value {
-            use $crate::method::{Method4, RubyMethod4};
-            $name.call_handle_error(rb_self, a, b, c, d)
-        }
-        anon as unsafe extern "C" fn(
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-        ) -> $crate::Value
-    }};
-    ($name:expr, 5) => {{
-        unsafe extern "C" fn anon(
-            rb_self: $crate::Value,
-            a: $crate::Value,
-            b: $crate::Value,
-            c: $crate::Value,
-            d: $crate::Value,
-            e: $crate::Value,
-        ) -> $crate::Value {
-            use $crate::method::{Method5, RubyMethod5};
-            $name.call_handle_error(rb_self, a, b, c, d, e)
-        }
-        anon as unsafe extern "C" fn(
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-        ) -> $crate::Value
-    }};
-    ($name:expr, 6) => {{
-        unsafe extern "C" fn anon(
-            rb_self: $crate::Value,
-            a: $crate::Value,
-            b: $crate::Value,
+macro_rules! method_kw_n {
+    ($name:ident, $ruby_name:ident, $n:literal) => {
+        seq!(N in 0..$n {
+            /// Helper trait for wrapping a function as a Ruby method taking
+            /// self, up to N arguments, and [`KwArgs`], with type conversions
+            /// and error handling.
+            ///
+            /// See the [`method`](crate::method!) macro.
+            #[doc(hidden)]
+            pub trait $name<RbSelf, #(T~N,)* Kw, Res>
+            where
+                Self: Sized + Fn(RbSelf, #(T~N,)* KwArgs<Kw>) -> Res,
+                RbSelf: TryConvert,
+                #(T~N: TryConvert,)*
+                Kw: FromKwArgs,
+                Res: ReturnValue,
+            {
+                #[inline]
+                unsafe fn call_convert_value(
+                    self,
+                    min: usize,
+                    argc: c_int,
+                    argv: *const Value,
+                    rb_self: Value,
+                ) -> Result<Value, Error> {
+                    let handle = Ruby::get_with(rb_self);
+                    let args = slice::from_raw_parts(argv, argc as usize);
+                    let (args, kw) = split_kwargs(&handle, args);
+                    handle.check_arity(args.len(), min..=$n)?;
+                    (self)(
+                        TryConvert::try_convert(rb_self)?,
+                        #(TryConvert::try_convert(arg_or_nil(&handle, args, N))?,)*
+                        FromKwArgs::from_kwargs(kw)?,
+                    ).into_return_value()
+                }
+
+                #[inline]
+                unsafe fn call_handle_error_argv(
+                    self,
+                    min: usize,
+                    argc: c_int,
+                    argv: *const Value,
+                    rb_self: Value,
+                ) -> Value {
+                    let res =
+                        match catch_unwind(AssertUnwindSafe(|| {
+                            self.call_convert_value(min, argc, argv, rb_self)
+                        })) {
+                            Ok(v) => v,
+                            Err(e) => Err(Error::from_panic(e)),
+                        };
+                    match res {
+                        Ok(v) => v,
+                        Err(e) => raise(e),
+                    }
+                }
+
+                #[inline]
+                unsafe fn call_handle_error(self, rb_self: Value, #(arg~N: Value,)*) -> Value {
+                    let args: [Value; $n] = [#(arg~N,)*];
+                    self.call_handle_error_argv($n, $n, args.as_ptr(), rb_self)
+                }
+
+                #[inline]
+                fn select_wrapper(
+                    self,
+                    _fixed: unsafe extern "C" fn(Value, #(Value,)*) -> Value,
+                    argv: unsafe extern "C" fn(c_int, *const Value, Value) -> Value,
+                ) -> unsafe extern "C" fn(c_int, *const Value, Value) -> Value {
+                    argv
+                }
+            }
+
+            impl<Func, RbSelf, #(T~N,)* Kw, Res> $name<RbSelf, #(T~N,)* Kw, Res> for Func
+            where
+                Func: Fn(RbSelf, #(T~N,)* KwArgs<Kw>) -> Res,
+                RbSelf: TryConvert,
+                #(T~N: TryConvert,)*
+                Kw: FromKwArgs,
+                Res: ReturnValue,
+            {}
+
+            /// Helper trait for wrapping a function as a Ruby method taking
+            /// [`&Ruby`](Ruby), self, up to N arguments, and [`KwArgs`], with
+            /// type conversions and error handling.
+            ///
+            /// See the [`method`](crate::method!) macro.
+            #[doc(hidden)]
+            pub trait $ruby_name<RbSelf, #(T~N,)* Kw, Res>
+            where
+                Self: Sized + Fn(&Ruby, RbSelf, #(T~N,)* KwArgs<Kw>) -> Res,
+                RbSelf: TryConvert,
+                #(T~N: TryConvert,)*
+                Kw: FromKwArgs,
+                Res: ReturnValue,
+            {
+                #[inline]
+                unsafe fn call_convert_value(
+                    self,
+                    min: usize,
+                    argc: c_int,
+                    argv: *const Value,
+                    rb_self: Value,
+                ) -> Result<Value, Error> {
+                    let handle = Ruby::get_with(rb_self);
+                    let args = slice::from_raw_parts(argv, argc as usize);
+                    let (args, kw) = split_kwargs(&handle, args);
+                    handle.check_arity(args.len(), min..=$n)?;
+                    (self)(
+                        &handle,
+                        TryConvert::try_convert(rb_self)?,
+                        #(TryConvert::try_convert(arg_or_nil(&handle, args, N))?,)*
+                        FromKwArgs::from_kwargs(kw)?,
+                    ).into_return_value()
+                }
+
+                #[inline]
+                unsafe fn call_handle_error_argv(
+                    self,
+                    min: usize,
+                    argc: c_int,
+                    argv: *const Value,
+                    rb_self: Value,
+                ) -> Value {
+                    let res =
+                        match catch_unwind(AssertUnwindSafe(|| {
+                            self.call_convert_value(min, argc, argv, rb_self)
+                        })) {
+                            Ok(v) => v,
+                            Err(e) => Err(Error::from_panic(e)),
+                        };
+                    match res {
+                        Ok(v) => v,
+                        Err(e) => raise(e),
+                    }
+                }
+
+                #[inline]
+                unsafe fn call_handle_error(self, rb_self: Value, #(arg~N: Value,)*) -> Value {
+                    let args: [Value; $n] = [#(arg~N,)*];
+                    self.call_handle_error_argv($n, $n, args.as_ptr(), rb_self)
+                }
+
+                #[inline]
+                fn select_wrapper(
+                    self,
+                    _fixed: unsafe extern "C" fn(Value, #(Value,)*) -> Value,
+                    argv: unsafe extern "C" fn(c_int, *const Value, Value) -> Value,
+                ) -> unsafe extern "C" fn(c_int, *const Value, Value) -> Value {
+                    argv
+                }
+            }
+
+            impl<Func, RbSelf, #(T~N,)* Kw, Res> $ruby_name<RbSelf, #(T~N,)* Kw, Res> for Func
+            where
+                Func: Fn(&Ruby, RbSelf, #(T~N,)* KwArgs<Kw>) -> Res,
+                RbSelf: TryConvert,
+                #(T~N: TryConvert,)*
+                Kw: FromKwArgs,
+                Res: ReturnValue,
+            {}
+        });
+    }
+}
+
+seq!(N in 0..=32 {
+    method_kw_n!(MethodKw~N, RubyMethodKw~N, N);
+});
+
+macro_rules! method_opt_n {
+    ($name:ident, $ruby_name:ident, $n:literal) => {
+        seq!(N in 0..$n {
+            /// Helper trait for wrapping a function as a Ruby method taking
+            /// self and up to N arguments, passing `nil` for missing arguments,
+            /// with type conversions and error handling.
+            ///
+            /// See the [`method`](crate::method!) macro.
+            #[doc(hidden)]
+            pub trait $name<RbSelf, #(T~N,)* Res>
+            where
+                Self: Sized + Fn(RbSelf, #(T~N,)*) -> Res,
+                RbSelf: TryConvert,
+                #(T~N: TryConvert,)*
+                Res: ReturnValue,
+            {
+                #[inline]
+                unsafe fn call_convert_value(
+                    self,
+                    min: usize,
+                    argc: c_int,
+                    argv: *const Value,
+                    rb_self: Value,
+                ) -> Result<Value, Error> {
+                    let handle = Ruby::get_with(rb_self);
+                    let args = slice::from_raw_parts(argv, argc as usize);
+                    handle.check_arity(args.len(), min..=$n)?;
+                    (self)(
+                        TryConvert::try_convert(rb_self)?,
+                        #(TryConvert::try_convert(arg_or_nil(&handle, args, N))?,)*
+                    ).into_return_value()
+                }
+
+                #[inline]
+                unsafe fn call_handle_error_argv(
+                    self,
+                    min: usize,
+                    argc: c_int,
+                    argv: *const Value,
+                    rb_self: Value,
+                ) -> Value {
+                    let res =
+                        match catch_unwind(AssertUnwindSafe(|| {
+                            self.call_convert_value(min, argc, argv, rb_self)
+                        })) {
+                            Ok(v) => v,
+                            Err(e) => Err(Error::from_panic(e)),
+                        };
+                    match res {
+                        Ok(v) => v,
+                        Err(e) => raise(e),
+                    }
+                }
+            }
+
+            impl<Func, RbSelf, #(T~N,)* Res> $name<RbSelf, #(T~N,)* Res> for Func
+            where
+                Func: Fn(RbSelf, #(T~N,)*) -> Res,
+                RbSelf: TryConvert,
+                #(T~N: TryConvert,)*
+                Res: ReturnValue,
+            {}
+
+            /// Helper trait for wrapping a function as a Ruby method taking
+            /// [`&Ruby`](Ruby), self, and up to N arguments, passing `nil` for
+            /// missing arguments, with type conversions and error handling.
+            ///
+            /// See the [`method`](crate::method!) macro.
+            #[doc(hidden)]
+            pub trait $ruby_name<RbSelf, #(T~N,)* Res>
+            where
+                Self: Sized + Fn(&Ruby, RbSelf, #(T~N,)*) -> Res,
+                RbSelf: TryConvert,
+                #(T~N: TryConvert,)*
+                Res: ReturnValue,
+            {
+                #[inline]
+                unsafe fn call_convert_value(
+                    self,
+                    min: usize,
+                    argc: c_int,
+                    argv: *const Value,
+                    rb_self: Value,
+                ) -> Result<Value, Error> {
+                    let handle = Ruby::get_with(rb_self);
+                    let args = slice::from_raw_parts(argv, argc as usize);
+                    handle.check_arity(args.len(), min..=$n)?;
+                    (self)(
+                        &handle,
+                        TryConvert::try_convert(rb_self)?,
+                        #(TryConvert::try_convert(arg_or_nil(&handle, args, N))?,)*
+                    ).into_return_value()
+                }
+
+                #[inline]
+                unsafe fn call_handle_error_argv(
+                    self,
+                    min: usize,
+                    argc: c_int,
+                    argv: *const Value,
+                    rb_self: Value,
+                ) -> Value {
+                    let res =
+                        match catch_unwind(AssertUnwindSafe(|| {
+                            self.call_convert_value(min, argc, argv, rb_self)
+                        })) {
+                            Ok(v) => v,
+                            Err(e) => Err(Error::from_panic(e)),
+                        };
+                    match res {
+                        Ok(v) => v,
+                        Err(e) => raise(e),
+                    }
+                }
+            }
+
+            impl<Func, RbSelf, #(T~N,)* Res> $ruby_name<RbSelf, #(T~N,)* Res> for Func
+            where
+                Func: Fn(&Ruby, RbSelf, #(T~N,)*) -> Res,
+                RbSelf: TryConvert,
+                #(T~N: TryConvert,)*
+                Res: ReturnValue,
+            {}
+        });
+    }
+}
+
+seq!(N in 0..=32 {
+    method_opt_n!(MethodOpt~N, RubyMethodOpt~N, N);
+});
+
+/// Wrap a Rust function item with Ruby type conversion and error handling.
+///
+/// This macro wraps the given function and returns a function pointer
+/// implementing the [`Method`] trait, suitable for passing to functions that
+/// define Ruby methods such as
+/// [`define_method`](crate::module::Module::define_method).
+///
+/// Ruby code implicitly always has a `self` parameter available. In the
+/// extention API this is passed explicitly. As a result there is always an
+/// extra `self` argument before the arguments explitly passed in Ruby, and the
+/// number of Rust argument will be one more than the Ruby arity.
+///
+/// The values `-2` and `-1` for `arity` have special meaning. Both indicate
+/// functions with any number of arguments, with `-2` the arguments are passed
+/// as a [`RArray`], with `-1` they are passed as a slice of [`Value`]s.
+/// Arity of `-1` can be used with [`scan_args`](crate::scan_args::scan_args)
+/// and [`get_kwargs`](crate::scan_args::get_kwargs) for more complex method
+/// signatures.
+///
+/// | Arity | Signature                                                 |
+/// |-------|-----------------------------------------------------------|
+/// |    -2 | `fn(rb_self: T, arguments: RArray) -> Result<R, Error>`   |
+/// |    -1 | `fn(rb_self: T, arguments: &[Value]) -> Result<R, Error>` |
+/// |     0 | `fn(rb_self: T) -> Result<R, Error>`                      |
+/// |     1 | `fn(rb_self: T, arg1: U) -> Result<R, Error>`             |
+/// |     2 | `fn(rb_self: T, arg1: U, arg2: V) -> Result<R, Error>`    |
+/// |   ... | ...                                                       |
+/// |    32 | ...                                                       |
+///
+/// Where `T`, `U`, `V` and so on are any types that implement `TryConvert`,
+/// and `R` implements [`IntoValue`]. It is also possible to return just `R`
+/// rather than a `Result` for functions that will never error, and omit the
+/// return value (i.e. return `()`) for a function that returns `nil` to Ruby.
+/// See [`ReturnValue`] for more details on what can be returned.
+///
+/// Ruby only supports methods with a fixed arity of up to `15`, functions
+/// with `16` to `32` arguments are wrapped to take a slice of arguments,
+/// so will report an arity of `-1` in Ruby, but raise an `ArgumentError` when
+/// called with the wrong number of arguments.
+///
+/// See the [`function`](crate::function!) macro for cases where there is no
+/// need to handle the `self` argument.
+///
+/// # Keyword Arguments
+///
+/// If the final argument of the function is [`KwArgs<T>`](KwArgs), where `T`
+/// is any type implementing [`FromKwArgs`], such as a struct deriving
+/// [`FromKwArgs`](macro@crate::FromKwArgs), it receives the method's keyword
+/// arguments. The arity is the number of positional arguments, not including
+/// `self` or the keyword arguments, e.g. `method!(Foo::bar, 1)` for
+/// `fn bar(rb_self: Foo, a: i64, kwargs: KwArgs<MyOpts>)`. The method will
+/// have an arity of `-1` in Ruby, and raise an `ArgumentError` for the wrong
+/// number of positional arguments, or missing or unknown keywords.
+///
+/// # Optional Arguments
+///
+/// The arity may be given as an inclusive range, e.g.
+/// `method!(Foo::search, 1..=2)`, for a function taking between `1` and `2`
+/// arguments (not including `self`). Arguments not given by the caller are
+/// passed to the function as `nil`, so should be a type such as `Option<T>`.
+/// The method will have an arity of `-1` in Ruby, and raise an
+/// `ArgumentError` when called with too few or too many arguments. The
+/// maximum may be between `1` and `32`.
+///
+/// # Argument Structs
+///
+/// With an arity of `-1` the function may take a single argument (after
+/// `self`) of any type implementing [`ScanArgs`], such as a struct deriving
+/// [`ScanArgs`](macro@crate::ScanArgs), in place of a slice of arguments.
+///
+/// # Examples
+///
+/// ```
+/// use magnus::{class, define_class, method, prelude::*, Error};
+///
+/// fn rb_is_blank(rb_self: String) -> bool {
+///     rb_self.contains(|c: char| !c.is_whitespace())
+/// }
+///
+/// #[magnus::init]
+/// fn init() -> Result<(), Error> {
+///     let class = define_class("String", class::object())?;
+///     class.define_method("blank?", method!(rb_is_blank, 0))?;
+///     Ok(())
+/// }
+/// # let _cleanup = unsafe { magnus::embed::init() };
+/// # init().unwrap();
+/// ```
+#[macro_export]
+macro_rules! method {
+    ($name:expr, -2) => {{
+        unsafe extern "C" fn anon(rb_self: $crate::Value, args: $crate::RArray) -> $crate::Value {
+            use $crate::method::{MethodRbAry, RubyMethodRbAry};
+            $name.call_handle_error(rb_self, args)
+        }
+        anon as unsafe extern "C" fn($crate::Value, $crate::RArray) -> $crate::Value
+    }};
+    ($name:expr, -1) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodCAry, MethodScanArgs, RubyMethodCAry, RubyMethodScanArgs};
+            $name.call_handle_error(argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, 0) => {{
+        use $crate::method::{
+            Method0, MethodKw0, MethodOpt0, RubyMethod0, RubyMethodKw0, RubyMethodOpt0,
+        };
+        unsafe extern "C" fn anon(rb_self: $crate::Value) -> $crate::Value {
+            $name.call_handle_error(rb_self)
+        }
+        unsafe extern "C" fn anon_argv(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error_argv(0, argc, argv, rb_self)
+        }
+        $name.select_wrapper(anon, anon_argv)
+    }};
+    ($name:expr, 1) => {{
+        use $crate::method::{
+            Method1, MethodKw1, MethodOpt1, RubyMethod1, RubyMethodKw1, RubyMethodOpt1,
+        };
+        unsafe extern "C" fn anon(rb_self: $crate::Value, a: $crate::Value) -> $crate::Value {
+            $name.call_handle_error(rb_self, a)
+        }
+        unsafe extern "C" fn anon_argv(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error_argv(1, argc, argv, rb_self)
+        }
+        $name.select_wrapper(anon, anon_argv)
+    }};
+    ($name:expr, 2) => {{
+        use $crate::method::{
+            Method2, MethodKw2, MethodOpt2, RubyMethod2, RubyMethodKw2, RubyMethodOpt2,
+        };
+        unsafe extern "C" fn anon(
+            rb_self: $crate::Value,
+            a: $crate::Value,
+            b: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error(rb_self, a, b)
+        }
+        unsafe extern "C" fn anon_argv(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error_argv(2, argc, argv, rb_self)
+        }
+        $name.select_wrapper(anon, anon_argv)
+    }};
+    ($name:expr, 3) => {{
+        use $crate::method::{
+            Method3, MethodKw3, MethodOpt3, RubyMethod3, RubyMethodKw3, RubyMethodOpt3,
+        };
+        unsafe extern "C" fn anon(
+            rb_self: $crate::Value,
+            a: $crate::Value,
+            b: $crate::Value,
+            c: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error(rb_self, a, b, c)
+        }
+        unsafe extern "C" fn anon_argv(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error_argv(3, argc, argv, rb_self)
+        }
+        $name.select_wrapper(anon, anon_argv)
+    }};
+    ($name:expr, 4) => {{
+        use $crate::method::{
+            Method4, MethodKw4, MethodOpt4, RubyMethod4, RubyMethodKw4, RubyMethodOpt4,
+        };
+        unsafe extern "C" fn anon(
+            rb_self: $crate::Value,
+            a: $crate::Value,
+            b: $crate::Value,
+            c: $crate::Value,
+            d: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error(rb_self, a, b, c, d)
+        }
+        unsafe extern "C" fn anon_argv(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error_argv(4, argc, argv, rb_self)
+        }
+        $name.select_wrapper(anon, anon_argv)
+    }};
+    ($name:expr, 5) => {{
+        use $crate::method::{
+            Method5, MethodKw5, MethodOpt5, RubyMethod5, RubyMethodKw5, RubyMethodOpt5,
+        };
+        unsafe extern "C" fn anon(
+            rb_self: $crate::Value,
+            a: $crate::Value,
+            b: $crate::Value,
+            c: $crate::Value,
+            d: $crate::Value,
+            e: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error(rb_self, a, b, c, d, e)
+        }
+        unsafe extern "C" fn anon_argv(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error_argv(5, argc, argv, rb_self)
+        }
+        $name.select_wrapper(anon, anon_argv)
+    }};
+    ($name:expr, 6) => {{
+        use $crate::method::{
+            Method6, MethodKw6, MethodOpt6, RubyMethod6, RubyMethodKw6, RubyMethodOpt6,
+        };
+        unsafe extern "C" fn anon(
+            rb_self: $crate::Value,
+            a: $crate::Value,
+            b: $crate::Value,
+            c: $crate::Value,
+            d: $crate::Value,
+            e: $crate::Value,
+            f: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error(rb_self, a, b, c, d, e, f)
+        }
+        unsafe extern "C" fn anon_argv(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error_argv(6, argc, argv, rb_self)
+        }
+        $name.select_wrapper(anon, anon_argv)
+    }};
+    ($name:expr, 7) => {{
+        use $crate::method::{
+            Method7, MethodKw7, MethodOpt7, RubyMethod7, RubyMethodKw7, RubyMethodOpt7,
+        };
+        unsafe extern "C" fn anon(
+            rb_self: $crate::Value,
+            a: $crate::Value,
+            b: $crate::Value,
+            c: $crate::Value,
+            d: $crate::Value,
+            e: $crate::Value,
+            f: $crate::Value,
+            g: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error(rb_self, a, b, c, d, e, f, g)
+        }
+        unsafe extern "C" fn anon_argv(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error_argv(7, argc, argv, rb_self)
+        }
+        $name.select_wrapper(anon, anon_argv)
+    }};
+    ($name:expr, 8) => {{
+        use $crate::method::{
+            Method8, MethodKw8, MethodOpt8, RubyMethod8, RubyMethodKw8, RubyMethodOpt8,
+        };
+        unsafe extern "C" fn anon(
+            rb_self: $crate::Value,
+            a: $crate::Value,
+            b: $crate::Value,
+            c: $crate::Value,
+            d: $crate::Value,
+            e: $crate::Value,
+            f: $crate::Value,
+            g: $crate::Value,
+            h: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error(rb_self, a, b, c, d, e, f, g, h)
+        }
+        unsafe extern "C" fn anon_argv(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error_argv(8, argc, argv, rb_self)
+        }
+        $name.select_wrapper(anon, anon_argv)
+    }};
+    ($name:expr, 9) => {{
+        use $crate::method::{
+            Method9, MethodKw9, MethodOpt9, RubyMethod9, RubyMethodKw9, RubyMethodOpt9,
+        };
+        unsafe extern "C" fn anon(
+            rb_self: $crate::Value,
+            a: $crate::Value,
+            b: $crate::Value,
+            c: $crate::Value,
+            d: $crate::Value,
+            e: $crate::Value,
+            f: $crate::Value,
+            g: $crate::Value,
+            h: $crate::Value,
+            i: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error(rb_self, a, b, c, d, e, f, g, h, i)
+        }
+        unsafe extern "C" fn anon_argv(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error_argv(9, argc, argv, rb_self)
+        }
+        $name.select_wrapper(anon, anon_argv)
+    }};
+    ($name:expr, 10) => {{
+        use $crate::method::{
+            Method10, MethodKw10, MethodOpt10, RubyMethod10, RubyMethodKw10, RubyMethodOpt10,
+        };
+        unsafe extern "C" fn anon(
+            rb_self: $crate::Value,
+            a: $crate::Value,
+            b: $crate::Value,
+            c: $crate::Value,
+            d: $crate::Value,
+            e: $crate::Value,
+            f: $crate::Value,
+            g: $crate::Value,
+            h: $crate::Value,
+            i: $crate::Value,
+            j: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error(rb_self, a, b, c, d, e, f, g, h, i, j)
+        }
+        unsafe extern "C" fn anon_argv(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error_argv(10, argc, argv, rb_self)
+        }
+        $name.select_wrapper(anon, anon_argv)
+    }};
+    ($name:expr, 11) => {{
+        use $crate::method::{
+            Method11, MethodKw11, MethodOpt11, RubyMethod11, RubyMethodKw11, RubyMethodOpt11,
+        };
+        unsafe extern "C" fn anon(
+            rb_self: $crate::Value,
+            a: $crate::Value,
+            b: $crate::Value,
+            c: $crate::Value,
+            d: $crate::Value,
+            e: $crate::Value,
+            f: $crate::Value,
+            g: $crate::Value,
+            h: $crate::Value,
+            i: $crate::Value,
+            j: $crate::Value,
+            k: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error(rb_self, a, b, c, d, e, f, g, h, i, j, k)
+        }
+        unsafe extern "C" fn anon_argv(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error_argv(11, argc, argv, rb_self)
+        }
+        $name.select_wrapper(anon, anon_argv)
+    }};
+    ($name:expr, 12) => {{
+        use $crate::method::{
+            Method12, MethodKw12, MethodOpt12, RubyMethod12, RubyMethodKw12, RubyMethodOpt12,
+        };
+        unsafe extern "C" fn anon(
+            rb_self: $crate::Value,
+            a: $crate::Value,
+            b: $crate::Value,
+            c: $crate::Value,
+            d: $crate::Value,
+            e: $crate::Value,
+            f: $crate::Value,
+            g: $crate::Value,
+            h: $crate::Value,
+            i: $crate::Value,
+            j: $crate::Value,
+            k: $crate::Value,
+            l: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error(rb_self, a, b, c, d, e, f, g, h, i, j, k, l)
+        }
+        unsafe extern "C" fn anon_argv(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error_argv(12, argc, argv, rb_self)
+        }
+        $name.select_wrapper(anon, anon_argv)
+    }};
+    ($name:expr, 13) => {{
+        use $crate::method::{
+            Method13, MethodKw13, MethodOpt13, RubyMethod13, RubyMethodKw13, RubyMethodOpt13,
+        };
+        unsafe extern "C" fn anon(
+            rb_self: $crate::Value,
+            a: $crate::Value,
+            b: $crate::Value,
+            c: $crate::Value,
+            d: $crate::Value,
+            e: $crate::Value,
+            f: $crate::Value,
+            g: $crate::Value,
+            h: $crate::Value,
+            i: $crate::Value,
+            j: $crate::Value,
+            k: $crate::Value,
+            l: $crate::Value,
+            m: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error(rb_self, a, b, c, d, e, f, g, h, i, j, k, l, m)
+        }
+        unsafe extern "C" fn anon_argv(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error_argv(13, argc, argv, rb_self)
+        }
+        $name.select_wrapper(anon, anon_argv)
+    }};
+    ($name:expr, 14) => {{
+        use $crate::method::{
+            Method14, MethodKw14, MethodOpt14, RubyMethod14, RubyMethodKw14, RubyMethodOpt14,
+        };
+        unsafe extern "C" fn anon(
+            rb_self: $crate::Value,
+            a: $crate::Value,
+            b: $crate::Value,
+            c: $crate::Value,
+            d: $crate::Value,
+            e: $crate::Value,
+            f: $crate::Value,
+            g: $crate::Value,
+            h: $crate::Value,
+            i: $crate::Value,
+            j: $crate::Value,
+            k: $crate::Value,
+            l: $crate::Value,
+            m: $crate::Value,
+            n: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error(rb_self, a, b, c, d, e, f, g, h, i, j, k, l, m, n)
+        }
+        unsafe extern "C" fn anon_argv(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error_argv(14, argc, argv, rb_self)
+        }
+        $name.select_wrapper(anon, anon_argv)
+    }};
+    ($name:expr, 15) => {{
+        use $crate::method::{
+            Method15, MethodKw15, MethodOpt15, RubyMethod15, RubyMethodKw15, RubyMethodOpt15,
+        };
+        unsafe extern "C" fn anon(
+            rb_self: $crate::Value,
+            a: $crate::Value,
+            b: $crate::Value,
+            c: $crate::Value,
+            d: $crate::Value,
+            e: $crate::Value,
+            f: $crate::Value,
+            g: $crate::Value,
+            h: $crate::Value,
+            i: $crate::Value,
+            j: $crate::Value,
+            k: $crate::Value,
+            l: $crate::Value,
+            m: $crate::Value,
+            n: $crate::Value,
+            o: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error(rb_self, a, b, c, d, e, f, g, h, i, j, k, l, m, n, o)
+        }
+        unsafe extern "C" fn anon_argv(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error_argv(15, argc, argv, rb_self)
+        }
+        $name.select_wrapper(anon, anon_argv)
+    }};
+    ($name:expr, 16) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw16, MethodOpt16, RubyMethodKw16, RubyMethodOpt16};
+            $name.call_handle_error_argv(16, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, 17) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw17, MethodOpt17, RubyMethodKw17, RubyMethodOpt17};
+            $name.call_handle_error_argv(17, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, 18) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw18, MethodOpt18, RubyMethodKw18, RubyMethodOpt18};
+            $name.call_handle_error_argv(18, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, 19) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw19, MethodOpt19, RubyMethodKw19, RubyMethodOpt19};
+            $name.call_handle_error_argv(19, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, 20) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw20, MethodOpt20, RubyMethodKw20, RubyMethodOpt20};
+            $name.call_handle_error_argv(20, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, 21) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw21, MethodOpt21, RubyMethodKw21, RubyMethodOpt21};
+            $name.call_handle_error_argv(21, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, 22) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw22, MethodOpt22, RubyMethodKw22, RubyMethodOpt22};
+            $name.call_handle_error_argv(22, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, 23) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw23, MethodOpt23, RubyMethodKw23, RubyMethodOpt23};
+            $name.call_handle_error_argv(23, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, 24) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw24, MethodOpt24, RubyMethodKw24, RubyMethodOpt24};
+            $name.call_handle_error_argv(24, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, 25) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw25, MethodOpt25, RubyMethodKw25, RubyMethodOpt25};
+            $name.call_handle_error_argv(25, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, 26) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw26, MethodOpt26, RubyMethodKw26, RubyMethodOpt26};
+            $name.call_handle_error_argv(26, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, 27) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw27, MethodOpt27, RubyMethodKw27, RubyMethodOpt27};
+            $name.call_handle_error_argv(27, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, 28) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw28, MethodOpt28, RubyMethodKw28, RubyMethodOpt28};
+            $name.call_handle_error_argv(28, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, 29) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw29, MethodOpt29, RubyMethodKw29, RubyMethodOpt29};
+            $name.call_handle_error_argv(29, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, 30) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw30, MethodOpt30, RubyMethodKw30, RubyMethodOpt30};
+            $name.call_handle_error_argv(30, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, 31) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw31, MethodOpt31, RubyMethodKw31, RubyMethodOpt31};
+            $name.call_handle_error_argv(31, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, 32) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw32, MethodOpt32, RubyMethodKw32, RubyMethodOpt32};
+            $name.call_handle_error_argv(32, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=1) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw1, MethodOpt1, RubyMethodKw1, RubyMethodOpt1};
+            const _: () = assert!($min <= 1, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=2) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw2, MethodOpt2, RubyMethodKw2, RubyMethodOpt2};
+            const _: () = assert!($min <= 2, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=3) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw3, MethodOpt3, RubyMethodKw3, RubyMethodOpt3};
+            const _: () = assert!($min <= 3, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=4) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw4, MethodOpt4, RubyMethodKw4, RubyMethodOpt4};
+            const _: () = assert!($min <= 4, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=5) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw5, MethodOpt5, RubyMethodKw5, RubyMethodOpt5};
+            const _: () = assert!($min <= 5, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=6) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw6, MethodOpt6, RubyMethodKw6, RubyMethodOpt6};
+            const _: () = assert!($min <= 6, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=7) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw7, MethodOpt7, RubyMethodKw7, RubyMethodOpt7};
+            const _: () = assert!($min <= 7, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=8) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw8, MethodOpt8, RubyMethodKw8, RubyMethodOpt8};
+            const _: () = assert!($min <= 8, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=9) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw9, MethodOpt9, RubyMethodKw9, RubyMethodOpt9};
+            const _: () = assert!($min <= 9, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=10) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw10, MethodOpt10, RubyMethodKw10, RubyMethodOpt10};
+            const _: () = assert!($min <= 10, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=11) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw11, MethodOpt11, RubyMethodKw11, RubyMethodOpt11};
+            const _: () = assert!($min <= 11, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=12) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw12, MethodOpt12, RubyMethodKw12, RubyMethodOpt12};
+            const _: () = assert!($min <= 12, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=13) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw13, MethodOpt13, RubyMethodKw13, RubyMethodOpt13};
+            const _: () = assert!($min <= 13, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=14) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw14, MethodOpt14, RubyMethodKw14, RubyMethodOpt14};
+            const _: () = assert!($min <= 14, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=15) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw15, MethodOpt15, RubyMethodKw15, RubyMethodOpt15};
+            const _: () = assert!($min <= 15, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=16) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw16, MethodOpt16, RubyMethodKw16, RubyMethodOpt16};
+            const _: () = assert!($min <= 16, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=17) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw17, MethodOpt17, RubyMethodKw17, RubyMethodOpt17};
+            const _: () = assert!($min <= 17, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=18) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw18, MethodOpt18, RubyMethodKw18, RubyMethodOpt18};
+            const _: () = assert!($min <= 18, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=19) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw19, MethodOpt19, RubyMethodKw19, RubyMethodOpt19};
+            const _: () = assert!($min <= 19, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=20) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw20, MethodOpt20, RubyMethodKw20, RubyMethodOpt20};
+            const _: () = assert!($min <= 20, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=21) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw21, MethodOpt21, RubyMethodKw21, RubyMethodOpt21};
+            const _: () = assert!($min <= 21, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=22) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw22, MethodOpt22, RubyMethodKw22, RubyMethodOpt22};
+            const _: () = assert!($min <= 22, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=23) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw23, MethodOpt23, RubyMethodKw23, RubyMethodOpt23};
+            const _: () = assert!($min <= 23, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=24) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw24, MethodOpt24, RubyMethodKw24, RubyMethodOpt24};
+            const _: () = assert!($min <= 24, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=25) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw25, MethodOpt25, RubyMethodKw25, RubyMethodOpt25};
+            const _: () = assert!($min <= 25, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=26) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw26, MethodOpt26, RubyMethodKw26, RubyMethodOpt26};
+            const _: () = assert!($min <= 26, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=27) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw27, MethodOpt27, RubyMethodKw27, RubyMethodOpt27};
+            const _: () = assert!($min <= 27, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=28) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw28, MethodOpt28, RubyMethodKw28, RubyMethodOpt28};
+            const _: () = assert!($min <= 28, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=29) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw29, MethodOpt29, RubyMethodKw29, RubyMethodOpt29};
+            const _: () = assert!($min <= 29, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=30) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw30, MethodOpt30, RubyMethodKw30, RubyMethodOpt30};
+            const _: () = assert!($min <= 30, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=31) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw31, MethodOpt31, RubyMethodKw31, RubyMethodOpt31};
+            const _: () = assert!($min <= 31, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=32) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{MethodKw32, MethodOpt32, RubyMethodKw32, RubyMethodOpt32};
+            const _: () = assert!($min <= 32, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv, rb_self)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $arity:expr) => {
+        compile_error!(concat!(
+            "arity must be an integer literal between -2..=32, ",
+            "or a range with a maximum between 1..=32",
+        ))
+    };
+}
+
+/// Helper trait for wrapping a function as a Ruby method ignoring self and
+/// taking a Ruby array of arguments, with type conversions and error handling.
+///
+/// See the [`function`](crate::function!) macro.
+#[doc(hidden)]
+pub trait FunctionRbAry<Args, Res>
+where
+    Self: Sized + Fn(Args) -> Res,
+    Args: TryConvert,
+    Res: ReturnValue,
+{
+    #[inline]
+    fn call_convert_value(self, args: RArray) -> Result<Value, Error> {
+        (self)(TryConvert::try_convert(args.as_value())?).into_return_value()
+    }
+
+    #[inline]
+    unsafe fn call_handle_error(self, args: RArray) -> Value {
+        let res = match catch_unwind(AssertUnwindSafe(|| self.call_convert_value(args))) {
+            Ok(v) => v,
+            Err(e) => Err(Error::from_panic(e)),
+        };
+        match res {
+            Ok(v) => v,
+            Err(e) => raise(e),
+        }
+    }
+}
+
+impl<Func, Args, Res> FunctionRbAry<Args, Res> for Func
+where
+    Func: Fn(Args) -> Res,
+    Args: TryConvert,
+    Res: ReturnValue,
+{
+}
+
+/// Helper trait for wrapping a function as a Ruby method taking
+/// [`&Ruby`](Ruby), ignoring self, and taking a Ruby array of arguments, with
+/// type conversions and error handling.
+///
+/// See the [`function`](crate::function!) macro.
+#[doc(hidden)]
+pub trait RubyFunctionRbAry<Args, Res>
+where
+    Self: Sized + Fn(&Ruby, Args) -> Res,
+    Args: TryConvert,
+    Res: ReturnValue,
+{
+    #[inline]
+    fn call_convert_value(self, args: RArray) -> Result<Value, Error> {
+        (self)(
+            &Ruby::get_with(args),
+            TryConvert::try_convert(args.as_value())?,
+        )
+        .into_return_value()
+    }
+
+    #[inline]
+    unsafe fn call_handle_error(self, args: RArray) -> Value {
+        let res = match catch_unwind(AssertUnwindSafe(|| self.call_convert_value(args))) {
+            Ok(v) => v,
+            Err(e) => Err(Error::from_panic(e)),
+        };
+        match res {
+            Ok(v) => v,
+            Err(e) => raise(e),
+        }
+    }
+}
+
+impl<Func, Args, Res> RubyFunctionRbAry<Args, Res> for Func
+where
+    Func: Fn(&Ruby, Args) -> Res,
+    Args: TryConvert,
+    Res: ReturnValue,
+{
+}
+
+/// Helper trait for wrapping a function as a Ruby method ignoring self and
+/// taking a slice of arguments, with type conversions and error handling.
+///
+/// See the [`function`](crate::function!) macro.
+#[doc(hidden)]
+pub trait FunctionCAry<Res>
+where
+    Self: Sized + Fn(&[Value]) -> Res,
+    Res: ReturnValue,
+{
+    #[inline]
+    unsafe fn call_convert_value(self, argc: c_int, argv: *const Value) -> Result<Value, Error> {
+        let args = slice::from_raw_parts(argv, argc as usize);
+        (self)(args).into_return_value()
+    }
+
+    #[inline]
+    unsafe fn call_handle_error(self, argc: c_int, argv: *const Value) -> Value {
+        let res = match catch_unwind(AssertUnwindSafe(|| self.call_convert_value(argc, argv))) {
+            Ok(v) => v,
+            Err(e) => Err(Error::from_panic(e)),
+        };
+        match res {
+            Ok(v) => v,
+            Err(e) => raise(e),
+        }
+    }
+}
+
+impl<Func, Res> FunctionCAry<Res> for Func
+where
+    Func: Fn(&[Value]) -> Res,
+    Res: ReturnValue,
+{
+}
+
+/// Helper trait for wrapping a function as a Ruby method taking
+/// [`&Ruby`](Ruby), ignoring self, and taking a slice of arguments, with type
+/// conversions and error handling.
+///
+/// See the [`function`](crate::function!) macro.
+#[doc(hidden)]
+pub trait RubyFunctionCAry<Res>
+where
+    Self: Sized + Fn(&Ruby, &[Value]) -> Res,
+    Res: ReturnValue,
+{
+    #[inline]
+    unsafe fn call_convert_value(self, argc: c_int, argv: *const Value) -> Result<Value, Error> {
+        let args = slice::from_raw_parts(argv, argc as usize);
+        (self)(&Ruby::get_unchecked(), args).into_return_value()
+    }
+
+    #[inline]
+    unsafe fn call_handle_error(self, argc: c_int, argv: *const Value) -> Value {
+        let res = match catch_unwind(AssertUnwindSafe(|| self.call_convert_value(argc, argv))) {
+            Ok(v) => v,
+            Err(e) => Err(Error::from_panic(e)),
+        };
+        match res {
+            Ok(v) => v,
+            Err(e) => raise(e),
+        }
+    }
+}
+
+impl<Func, Res> RubyFunctionCAry<Res> for Func
+where
+    Func: Fn(&Ruby, &[Value]) -> Res,
+    Res: ReturnValue,
+{
+}
+
+/// Helper trait for wrapping a function as a Ruby method ignoring self and
+/// taking a type implementing [`ScanArgs`], with type conversions and error
+/// handling.
+///
+/// See the [`function`](crate::function!) macro.
+#[doc(hidden)]
+pub trait FunctionScanArgs<Args, Res>
+where
+    Self: Sized + Fn(Args) -> Res,
+    Args: ScanArgs,
+    Res: ReturnValue,
+{
+    #[inline]
+    unsafe fn call_convert_value(self, argc: c_int, argv: *const Value) -> Result<Value, Error> {
+        let args = slice::from_raw_parts(argv, argc as usize);
+        (self)(Args::from_args(&Ruby::get_unchecked(), args)?).into_return_value()
+    }
+
+    #[inline]
+    unsafe fn call_handle_error(self, argc: c_int, argv: *const Value) -> Value {
+        let res = match catch_unwind(AssertUnwindSafe(|| self.call_convert_value(argc, argv))) {
+            Ok(v) => v,
+            Err(e) => Err(Error::from_panic(e)),
+        };
+        match res {
+            Ok(v) => v,
+            Err(e) => raise(e),
+        }
+    }
+}
+
+impl<Func, Args, Res> FunctionScanArgs<Args, Res> for Func
+where
+    Func: Fn(Args) -> Res,
+    Args: ScanArgs,
+    Res: ReturnValue,
+{
+}
+
+/// Helper trait for wrapping a function as a Ruby method taking
+/// [`&Ruby`](Ruby), ignoring self, and taking a type implementing
+/// [`ScanArgs`], with type conversions and error handling.
+///
+/// See the [`function`](crate::function!) macro.
+#[doc(hidden)]
+pub trait RubyFunctionScanArgs<Args, Res>
+where
+    Self: Sized + Fn(&Ruby, Args) -> Res,
+    Args: ScanArgs,
+    Res: ReturnValue,
+{
+    #[inline]
+    unsafe fn call_convert_value(self, argc: c_int, argv: *const Value) -> Result<Value, Error> {
+        let handle = Ruby::get_unchecked();
+        let args = slice::from_raw_parts(argv, argc as usize);
+        (self)(&handle, Args::from_args(&handle, args)?).into_return_value()
+    }
+
+    #[inline]
+    unsafe fn call_handle_error(self, argc: c_int, argv: *const Value) -> Value {
+        let res = match catch_unwind(AssertUnwindSafe(|| self.call_convert_value(argc, argv))) {
+            Ok(v) => v,
+            Err(e) => Err(Error::from_panic(e)),
+        };
+        match res {
+            Ok(v) => v,
+            Err(e) => raise(e),
+        }
+    }
+}
+
+impl<Func, Args, Res> RubyFunctionScanArgs<Args, Res> for Func
+where
+    Func: Fn(&Ruby, Args) -> Res,
+    Args: ScanArgs,
+    Res: ReturnValue,
+{
+}
+
+macro_rules! function_n {
+    ($name:ident, $ruby_name:ident, $n:literal) => {
+        seq!(N in 0..$n {
+            /// Helper trait for wrapping a function as a Ruby method ignoring
+            /// self and taking N arguments, with type conversions and error
+            /// handling.
+            ///
+            /// See the [`function`](crate::function!) macro.
+            #[doc(hidden)]
+            pub trait $name<#(T~N,)* Res>
+            where
+                Self: Sized + Fn(#(T~N,)*) -> Res,
+                #(T~N: TryConvert,)*
+                Res: ReturnValue,
+            {
+                #[inline]
+                fn call_convert_value(self, #(arg~N: Value,)*) -> Result<Value, Error> {
+                    (self)(
+                        #(TryConvert::try_convert(arg~N)?,)*
+                    ).into_return_value()
+                }
+
+                #[inline]
+                unsafe fn call_handle_error(self, #(arg~N: Value,)*) -> Value {
+                    let res =
+                        match catch_unwind(AssertUnwindSafe(|| {
+                            self.call_convert_value(#(arg~N,)*)
+                        })) {
+                            Ok(v) => v,
+                            Err(e) => Err(Error::from_panic(e)),
+                        };
+                    match res {
+                        Ok(v) => v,
+                        Err(e) => raise(e),
+                    }
+                }
+
+                #[inline]
+                fn select_wrapper(
+                    self,
+                    fixed: unsafe extern "C" fn(Value, #(Value,)*) -> Value,
+                    _argv: unsafe extern "C" fn(c_int, *const Value, Value) -> Value,
+                ) -> unsafe extern "C" fn(Value, #(Value,)*) -> Value {
+                    fixed
+                }
+            }
+
+            impl<Func, #(T~N,)* Res> $name<#(T~N,)* Res> for Func
+            where
+                Func: Fn(#(T~N,)*) -> Res,
+                #(T~N: TryConvert,)*
+                Res: ReturnValue,
+            {}
+
+            /// Helper trait for wrapping a function as a Ruby method taking
+            /// [`&Ruby`](Ruby), ignoring self, and taking N arguments, with
+            /// type conversions and error handling.
+            ///
+            /// See the [`function`](crate::function!) macro.
+            #[doc(hidden)]
+            pub trait $ruby_name<#(T~N,)* Res>
+            where
+                Self: Sized + Fn(&Ruby, #(T~N,)*) -> Res,
+                #(T~N: TryConvert,)*
+                Res: ReturnValue,
+            {
+                #[inline]
+                unsafe fn call_convert_value(self, #(arg~N: Value,)*) -> Result<Value, Error> {
+                    (self)(
+                        &Ruby::get_unchecked(),
+                        #(TryConvert::try_convert(arg~N)?,)*
+                    ).into_return_value()
+                }
+
+                #[inline]
+                unsafe fn call_handle_error(self, #(arg~N: Value,)*) -> Value {
+                    let res =
+                        match catch_unwind(AssertUnwindSafe(|| {
+                            self.call_convert_value(#(arg~N,)*)
+                        })) {
+                            Ok(v) => v,
+                            Err(e) => Err(Error::from_panic(e)),
+                        };
+                    match res {
+                        Ok(v) => v,
+                        Err(e) => raise(e),
+                    }
+                }
+
+                #[inline]
+                fn select_wrapper(
+                    self,
+                    fixed: unsafe extern "C" fn(Value, #(Value,)*) -> Value,
+                    _argv: unsafe extern "C" fn(c_int, *const Value, Value) -> Value,
+                ) -> unsafe extern "C" fn(Value, #(Value,)*) -> Value {
+                    fixed
+                }
+            }
+
+            impl<Func, #(T~N,)* Res> $ruby_name<#(T~N,)* Res> for Func
+            where
+                Func: Fn(&Ruby, #(T~N,)*) -> Res,
+                #(T~N: TryConvert,)*
+                Res: ReturnValue,
+            {}
+        });
+    }
+}
+
+seq!(N in 0..=15 {
+    function_n!(Function~N, RubyFunction~N, N);
+});
+
+macro_rules! function_kw_n {
+    ($name:ident, $ruby_name:ident, $n:literal) => {
+        seq!(N in 0..$n {
+            /// Helper trait for wrapping a function as a Ruby method ignoring
+            /// self and taking up to N arguments and [`KwArgs`], with type
+            /// conversions and error handling.
+            ///
+            /// See the [`function`](crate::function!) macro.
+            #[doc(hidden)]
+            pub trait $name<#(T~N,)* Kw, Res>
+            where
+                Self: Sized + Fn(#(T~N,)* KwArgs<Kw>) -> Res,
+                #(T~N: TryConvert,)*
+                Kw: FromKwArgs,
+                Res: ReturnValue,
+            {
+                #[inline]
+                unsafe fn call_convert_value(
+                    self,
+                    min: usize,
+                    argc: c_int,
+                    argv: *const Value,
+                ) -> Result<Value, Error> {
+                    let handle = Ruby::get_unchecked();
+                    let args = slice::from_raw_parts(argv, argc as usize);
+                    let (args, kw) = split_kwargs(&handle, args);
+                    handle.check_arity(args.len(), min..=$n)?;
+                    (self)(
+                        #(TryConvert::try_convert(arg_or_nil(&handle, args, N))?,)*
+                        FromKwArgs::from_kwargs(kw)?,
+                    ).into_return_value()
+                }
+
+                #[inline]
+                unsafe fn call_handle_error_argv(
+                    self,
+                    min: usize,
+                    argc: c_int,
+                    argv: *const Value,
+                ) -> Value {
+                    let res =
+                        match catch_unwind(AssertUnwindSafe(|| {
+                            self.call_convert_value(min, argc, argv)
+                        })) {
+                            Ok(v) => v,
+                            Err(e) => Err(Error::from_panic(e)),
+                        };
+                    match res {
+                        Ok(v) => v,
+                        Err(e) => raise(e),
+                    }
+                }
+
+                #[inline]
+                unsafe fn call_handle_error(self, #(arg~N: Value,)*) -> Value {
+                    let args: [Value; $n] = [#(arg~N,)*];
+                    self.call_handle_error_argv($n, $n, args.as_ptr())
+                }
+
+                #[inline]
+                fn select_wrapper(
+                    self,
+                    _fixed: unsafe extern "C" fn(Value, #(Value,)*) -> Value,
+                    argv: unsafe extern "C" fn(c_int, *const Value, Value) -> Value,
+                ) -> unsafe extern "C" fn(c_int, *const Value, Value) -> Value {
+                    argv
+                }
+            }
+
+            impl<Func, #(T~N,)* Kw, Res> $name<#(T~N,)* Kw, Res> for Func
+            where
+                Func: Fn(#(T~N,)* KwArgs<Kw>) -> Res,
+                #(T~N: TryConvert,)*
+                Kw: FromKwArgs,
+                Res: ReturnValue,
+            {}
+
+            /// Helper trait for wrapping a function as a Ruby method taking
+            /// [`&Ruby`](Ruby), ignoring self, and taking up to N arguments and
+            /// [`KwArgs`], with type conversions and error handling.
+            ///
+            /// See the [`function`](crate::function!) macro.
+            #[doc(hidden)]
+            pub trait $ruby_name<#(T~N,)* Kw, Res>
+            where
+                Self: Sized + Fn(&Ruby, #(T~N,)* KwArgs<Kw>) -> Res,
+                #(T~N: TryConvert,)*
+                Kw: FromKwArgs,
+                Res: ReturnValue,
+            {
+                #[inline]
+                unsafe fn call_convert_value(
+                    self,
+                    min: usize,
+                    argc: c_int,
+                    argv: *const Value,
+                ) -> Result<Value, Error> {
+                    let handle = Ruby::get_unchecked();
+                    let args = slice::from_raw_parts(argv, argc as usize);
+                    let (args, kw) = split_kwargs(&handle, args);
+                    handle.check_arity(args.len(), min..=$n)?;
+                    (self)(
+                        &handle,
+                        #(TryConvert::try_convert(arg_or_nil(&handle, args, N))?,)*
+                        FromKwArgs::from_kwargs(kw)?,
+                    ).into_return_value()
+                }
+
+                #[inline]
+                unsafe fn call_handle_error_argv(
+                    self,
+                    min: usize,
+                    argc: c_int,
+                    argv: *const Value,
+                ) -> Value {
+                    let res =
+                        match catch_unwind(AssertUnwindSafe(|| {
+                            self.call_convert_value(min, argc, argv)
+                        })) {
+                            Ok(v) => v,
+                            Err(e) => Err(Error::from_panic(e)),
+                        };
+                    match res {
+                        Ok(v) => v,
+                        Err(e) => raise(e),
+                    }
+                }
+
+                #[inline]
+                unsafe fn call_handle_error(self, #(arg~N: Value,)*) -> Value {
+                    let args: [Value; $n] = [#(arg~N,)*];
+                    self.call_handle_error_argv($n, $n, args.as_ptr())
+                }
+
+                #[inline]
+                fn select_wrapper(
+                    self,
+                    _fixed: unsafe extern "C" fn(Value, #(Value,)*) -> Value,
+                    argv: unsafe extern "C" fn(c_int, *const Value, Value) -> Value,
+                ) -> unsafe extern "C" fn(c_int, *const Value, Value) -> Value {
+                    argv
+                }
+            }
+
+            impl<Func, #(T~N,)* Kw, Res> $ruby_name<#(T~N,)* Kw, Res> for Func
+            where
+                Func: Fn(&Ruby, #(T~N,)* KwArgs<Kw>) -> Res,
+                #(T~N: TryConvert,)*
+                Kw: FromKwArgs,
+                Res: ReturnValue,
+            {}
+        });
+    }
+}
+
+seq!(N in 0..=32 {
+    function_kw_n!(FunctionKw~N, RubyFunctionKw~N, N);
+});
+
+macro_rules! function_opt_n {
+    ($name:ident, $ruby_name:ident, $n:literal) => {
+        seq!(N in 0..$n {
+            /// Helper trait for wrapping a function as a Ruby method ignoring
+            /// self and taking up to N arguments, passing `nil` for missing
+            /// arguments, with type conversions and error handling.
+            ///
+            /// See the [`function`](crate::function!) macro.
+            #[doc(hidden)]
+            pub trait $name<#(T~N,)* Res>
+            where
+                Self: Sized + Fn(#(T~N,)*) -> Res,
+                #(T~N: TryConvert,)*
+                Res: ReturnValue,
+            {
+                #[inline]
+                unsafe fn call_convert_value(
+                    self,
+                    min: usize,
+                    argc: c_int,
+                    argv: *const Value,
+                ) -> Result<Value, Error> {
+                    let handle = Ruby::get_unchecked();
+                    let args = slice::from_raw_parts(argv, argc as usize);
+                    handle.check_arity(args.len(), min..=$n)?;
+                    (self)(
+                        #(TryConvert::try_convert(arg_or_nil(&handle, args, N))?,)*
+                    ).into_return_value()
+                }
+
+                #[inline]
+                unsafe fn call_handle_error_argv(
+                    self,
+                    min: usize,
+                    argc: c_int,
+                    argv: *const Value,
+                ) -> Value {
+                    let res =
+                        match catch_unwind(AssertUnwindSafe(|| {
+                            self.call_convert_value(min, argc, argv)
+                        })) {
+                            Ok(v) => v,
+                            Err(e) => Err(Error::from_panic(e)),
+                        };
+                    match res {
+                        Ok(v) => v,
+                        Err(e) => raise(e),
+                    }
+                }
+            }
+
+            impl<Func, #(T~N,)* Res> $name<#(T~N,)* Res> for Func
+            where
+                Func: Fn(#(T~N,)*) -> Res,
+                #(T~N: TryConvert,)*
+                Res: ReturnValue,
+            {}
+
+            /// Helper trait for wrapping a function as a Ruby method taking
+            /// [`&Ruby`](Ruby), ignoring self, and taking up to N arguments,
+            /// passing `nil` for missing arguments, with type conversions and
+            /// error handling.
+            ///
+            /// See the [`function`](crate::function!) macro.
+            #[doc(hidden)]
+            pub trait $ruby_name<#(T~N,)* Res>
+            where
+                Self: Sized + Fn(&Ruby, #(T~N,)*) -> Res,
+                #(T~N: TryConvert,)*
+                Res: ReturnValue,
+            {
+                #[inline]
+                unsafe fn call_convert_value(
+                    self,
+                    min: usize,
+                    argc: c_int,
+                    argv: *const Value,
+                ) -> Result<Value, Error> {
+                    let handle = Ruby::get_unchecked();
+                    let args = slice::from_raw_parts(argv, argc as usize);
+                    handle.check_arity(args.len(), min..=$n)?;
+                    (self)(
+                        &handle,
+                        #(TryConvert::try_convert(arg_or_nil(&handle, args, N))?,)*
+                    ).into_return_value()
+                }
+
+                #[inline]
+                unsafe fn call_handle_error_argv(
+                    self,
+                    min: usize,
+                    argc: c_int,
+                    argv: *const Value,
+                ) -> Value {
+                    let res =
+                        match catch_unwind(AssertUnwindSafe(|| {
+                            self.call_convert_value(min, argc, argv)
+                        })) {
+                            Ok(v) => v,
+                            Err(e) => Err(Error::from_panic(e)),
+                        };
+                    match res {
+                        Ok(v) => v,
+                        Err(e) => raise(e),
+                    }
+                }
+            }
+
+            impl<Func, #(T~N,)* Res> $ruby_name<#(T~N,)* Res> for Func
+            where
+                Func: Fn(&Ruby, #(T~N,)*) -> Res,
+                #(T~N: TryConvert,)*
+                Res: ReturnValue,
+            {}
+        });
+    }
+}
+
+seq!(N in 0..=32 {
+    function_opt_n!(FunctionOpt~N, RubyFunctionOpt~N, N);
+});
+
+/// Wrap a Rust function item with Ruby type conversion and error handling,
+/// ignoring Ruby's `self` argument.
+///
+/// This macro wraps the given function and returns a function pointer
+/// implementing the [`Method`] trait, suitable for passing to functions that
+/// define Ruby methods such as
+/// [`define_method`](crate::module::Module::define_method).
+///
+/// Ruby code implicitly always has a `self` parameter available. In the
+/// extention API this is passed explicitly. The wrapper this macro generates
+/// ignores that argument, and does not pass it to the wrapped function.
+///
+/// The values `-2` and `-1` for `arity` have special meaning. Both indicate
+/// functions with any number of arguments, with `-2` the arguments are passed
+/// as a [`RArray`], with `-1` they are passed as a slice of [`Value`]s.
+/// Arity of `-1` can be used with [`scan_args`](crate::scan_args::scan_args)
+/// and [`get_kwargs`](crate::scan_args::get_kwargs) for more complex method
+/// signatures.
+///
+/// | Arity | Signature                                     |
+/// |-------|-----------------------------------------------|
+/// |    -2 | `fn(arguments: RArray) -> Result<R, Error>`   |
+/// |    -1 | `fn(arguments: &[Value]) -> Result<R, Error>` |
+/// |     0 | `fn()-> Result<R, Error>`                     |
+/// |     1 | `fn(arg1: T) -> Result<R, Error>`             |
+/// |     2 | `fn(arg1: T, arg2: U) -> Result<R, Error>`    |
+/// |   ... | ...                                           |
+/// |    32 | ...                                           |
+///
+/// Where `T`, `U`, and so on are any types that implement `TryConvert`,
+/// and `R` implements [`IntoValue`]. It is also possible to return just `R`
+/// rather than a `Result` for functions that will never error, and omit the
+/// return value (i.e. return `()`) for a function that returns `nil` to Ruby.
+/// See [`ReturnValue`] for more details on what can be returned.
+///
+/// Ruby only supports methods with a fixed arity of up to `15`, functions
+/// with `16` to `32` arguments are wrapped to take a slice of arguments,
+/// so will report an arity of `-1` in Ruby, but raise an `ArgumentError` when
+/// called with the wrong number of arguments.
+///
+/// See the [`method`](crate::method!) macro for cases where the `self`
+/// argument is required.
+///
+/// # Keyword Arguments
+///
+/// If the final argument of the function is [`KwArgs<T>`](KwArgs), where `T`
+/// is any type implementing [`FromKwArgs`], such as a struct deriving
+/// [`FromKwArgs`](macro@crate::FromKwArgs), it receives the method's keyword
+/// arguments. The arity is the number of positional arguments, not including
+/// the keyword arguments, e.g. `function!(connect, 1)` for
+/// `fn connect(host: String, kwargs: KwArgs<MyOpts>)`. The method will have
+/// an arity of `-1` in Ruby, and raise an `ArgumentError` for the wrong number
+/// of positional arguments, or missing or unknown keywords.
+///
+/// # Optional Arguments
+///
+/// The arity may be given as an inclusive range, e.g.
+/// `function!(search, 1..=2)`, for a function taking between `1` and `2`
+/// arguments. Arguments not given by the caller are passed to the function as
+/// `nil`, so should be a type such as `Option<T>`. The method will have an
+/// arity of `-1` in Ruby, and raise an `ArgumentError` when called with too
+/// few or too many arguments. The maximum may be between `1` and `32`.
+///
+/// # Argument Structs
+///
+/// With an arity of `-1` the function may take a single argument of any type
+/// implementing [`ScanArgs`], such as a struct deriving
+/// [`ScanArgs`](macro@crate::ScanArgs), in place of a slice of arguments.
+///
+/// # Examples
+///
+/// ```
+/// fn distance(a: (f64, f64), b: (f64, f64)) -> f64 {
+///     ((b.0 - a.0).powi(2) + (b.0 - a.0).powi(2)).sqrt()
+/// }
+///
+/// #[magnus::init]
+/// fn init() {
+///     magnus::define_global_function("distance", magnus::function!(distance, 2));
+/// }
+/// # let _cleanup = unsafe { magnus::embed::init() };
+/// # init();
+/// ```
+#[macro_export]
+macro_rules! function {
+    ($name:expr, -2) => {{
+        unsafe extern "C" fn anon(rb_self: $crate::Value, args: $crate::RArray) -> $crate::Value {
+            use $crate::method::{FunctionRbAry, RubyFunctionRbAry};
+            $name.call_handle_error(args)
+        }
+        anon as unsafe extern "C" fn($crate::Value, $crate::RArray) -> $crate::Value
+    }};
+    ($name:expr, -1) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{
+                FunctionCAry, FunctionScanArgs, RubyFunctionCAry, RubyFunctionScanArgs,
+            };
+            $name.call_handle_error(argc, argv)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, 0) => {{
+        use $crate::method::{
+            Function0, FunctionKw0, FunctionOpt0, RubyFunction0, RubyFunctionKw0, RubyFunctionOpt0,
+        };
+        unsafe extern "C" fn anon(rb_self: $crate::Value) -> $crate::Value {
+            $name.call_handle_error()
+        }
+        unsafe extern "C" fn anon_argv(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error_argv(0, argc, argv)
+        }
+        $name.select_wrapper(anon, anon_argv)
+    }};
+    ($name:expr, 1) => {{
+        use $crate::method::{
+            Function1, FunctionKw1, FunctionOpt1, RubyFunction1, RubyFunctionKw1, RubyFunctionOpt1,
+        };
+        unsafe extern "C" fn anon(rb_self: $crate::Value, a: $crate::Value) -> $crate::Value {
+            $name.call_handle_error(a)
+        }
+        unsafe extern "C" fn anon_argv(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error_argv(1, argc, argv)
+        }
+        $name.select_wrapper(anon, anon_argv)
+    }};
+    ($name:expr, 2) => {{
+        use $crate::method::{
+            Function2, FunctionKw2, FunctionOpt2, RubyFunction2, RubyFunctionKw2, RubyFunctionOpt2,
+        };
+        unsafe extern "C" fn anon(
+            rb_self: $crate::Value,
+            a: $crate::Value,
+            b: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error(a, b)
+        }
+        unsafe extern "C" fn anon_argv(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error_argv(2, argc, argv)
+        }
+        $name.select_wrapper(anon, anon_argv)
+    }};
+    ($name:expr, 3) => {{
+        use $crate::method::{
+            Function3, FunctionKw3, FunctionOpt3, RubyFunction3, RubyFunctionKw3, RubyFunctionOpt3,
+        };
+        unsafe extern "C" fn anon(
+            rb_self: $crate::Value,
+            a: $crate::Value,
+            b: $crate::Value,
+            c: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error(a, b, c)
+        }
+        unsafe extern "C" fn anon_argv(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error_argv(3, argc, argv)
+        }
+        $name.select_wrapper(anon, anon_argv)
+    }};
+    ($name:expr, 4) => {{
+        use $crate::method::{
+            Function4, FunctionKw4, FunctionOpt4, RubyFunction4, RubyFunctionKw4, RubyFunctionOpt4,
+        };
+        unsafe extern "C" fn anon(
+            rb_self: $crate::Value,
+            a: $crate::Value,
+            b: $crate::Value,
+            c: $crate::Value,
+            d: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error(a, b, c, d)
+        }
+        unsafe extern "C" fn anon_argv(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error_argv(4, argc, argv)
+        }
+        $name.select_wrapper(anon, anon_argv)
+    }};
+    ($name:expr, 5) => {{
+        use $crate::method::{
+            Function5, FunctionKw5, FunctionOpt5, RubyFunction5, RubyFunctionKw5, RubyFunctionOpt5,
+        };
+        unsafe extern "C" fn anon(
+            rb_self: $crate::Value,
+            a: $crate::Value,
+            b: $crate::Value,
+            c: $crate::Value,
+            d: $crate::Value,
+            e: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error(a, b, c, d, e)
+        }
+        unsafe extern "C" fn anon_argv(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error_argv(5, argc, argv)
+        }
+        $name.select_wrapper(anon, anon_argv)
+    }};
+    ($name:expr, 6) => {{
+        use $crate::method::{
+            Function6, FunctionKw6, FunctionOpt6, RubyFunction6, RubyFunctionKw6, RubyFunctionOpt6,
+        };
+        unsafe extern "C" fn anon(
+            rb_self: $crate::Value,
+            a: $crate::Value,
+            b: $crate::Value,
+            c: $crate::Value,
+            d: $crate::Value,
+            e: $crate::Value,
+            f: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error(a, b, c, d, e, f)
+        }
+        unsafe extern "C" fn anon_argv(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error_argv(6, argc, argv)
+        }
+        $name.select_wrapper(anon, anon_argv)
+    }};
+    ($name:expr, 7) => {{
+        use $crate::method::{
+            Function7, FunctionKw7, FunctionOpt7, RubyFunction7, RubyFunctionKw7, RubyFunctionOpt7,
+        };
+        unsafe extern "C" fn anon(
+            rb_self: $crate::Value,
+            a: $crate::Value,
+            b: $crate::Value,
+            c: $crate::Value,
+            d: $crate::Value,
+            e: $crate::Value,
+            f: $crate::Value,
+            g: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error(a, b, c, d, e, f, g)
+        }
+        unsafe extern "C" fn anon_argv(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error_argv(7, argc, argv)
+        }
+        $name.select_wrapper(anon, anon_argv)
+    }};
+    ($name:expr, 8) => {{
+        use $crate::method::{
+            Function8, FunctionKw8, FunctionOpt8, RubyFunction8, RubyFunctionKw8, RubyFunctionOpt8,
+        };
+        unsafe extern "C" fn anon(
+            rb_self: $crate::Value,
+            a: $crate::Value,
+            b: $crate::Value,
+            c: $crate::Value,
+            d: $crate::Value,
+            e: $crate::Value,
+            f: $crate::Value,
+            g: $crate::Value,
+            h: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error(a, b, c, d, e, f, g, h)
+        }
+        unsafe extern "C" fn anon_argv(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error_argv(8, argc, argv)
+        }
+        $name.select_wrapper(anon, anon_argv)
+    }};
+    ($name:expr, 9) => {{
+        use $crate::method::{
+            Function9, FunctionKw9, FunctionOpt9, RubyFunction9, RubyFunctionKw9, RubyFunctionOpt9,
+        };
+        unsafe extern "C" fn anon(
+            rb_self: $crate::Value,
+            a: $crate::Value,
+            b: $crate::Value,
+            c: $crate::Value,
+            d: $crate::Value,
+            e: $crate::Value,
+            f: $crate::Value,
+            g: $crate::Value,
+            h: $crate::Value,
+            i: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error(a, b, c, d, e, f, g, h, i)
+        }
+        unsafe extern "C" fn anon_argv(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error_argv(9, argc, argv)
+        }
+        $name.select_wrapper(anon, anon_argv)
+    }};
+    ($name:expr, 10) => {{
+        use $crate::method::{
+            Function10, FunctionKw10, FunctionOpt10, RubyFunction10, RubyFunctionKw10,
+            RubyFunctionOpt10,
+        };
+        unsafe extern "C" fn anon(
+            rb_self: $crate::Value,
+            a: $crate::Value,
+            b: $crate::Value,
+            c: $crate::Value,
+            d: $crate::Value,
+            e: $crate::Value,
+            f: $crate::Value,
+            g: $crate::Value,
+            h: $crate::Value,
+            i: $crate::Value,
+            j: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error(a, b, c, d, e, f, g, h, i, j)
+        }
+        unsafe extern "C" fn anon_argv(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error_argv(10, argc, argv)
+        }
+        $name.select_wrapper(anon, anon_argv)
+    }};
+    ($name:expr, 11) => {{
+        use $crate::method::{
+            Function11, FunctionKw11, FunctionOpt11, RubyFunction11, RubyFunctionKw11,
+            RubyFunctionOpt11,
+        };
+        unsafe extern "C" fn anon(
+            rb_self: $crate::Value,
+            a: $crate::Value,
+            b: $crate::Value,
             c: $crate::Value,
             d: $crate::Value,
             e: $crate::Value,
             f: $crate::Value,
+            g: $crate::Value,
+            h: $crate::Value,
+            i: $crate::Value,
+            j: $crate::Value,
+            k: $crate::Value,
         ) -> $crate::Value {
-            use $crate::method::{Method6, RubyMethod6};
-            $name.call_handle_error(rb_self, a, b, c, d, e, f)
+            $name.call_handle_error(a, b, c, d, e, f, g, h, i, j, k)
+        }
+        unsafe extern "C" fn anon_argv(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error_argv(11, argc, argv)
+        }
+        $name.select_wrapper(anon, anon_argv)
+    }};
+    ($name:expr, 12) => {{
+        use $crate::method::{
+            Function12, FunctionKw12, FunctionOpt12, RubyFunction12, RubyFunctionKw12,
+            RubyFunctionOpt12,
+        };
+        unsafe extern "C" fn anon(
+            rb_self: $crate::Value,
+            a: $crate::Value,
+            b: $crate::Value,
+            c: $crate::Value,
+            d: $crate::Value,
+            e: $crate::Value,
+            f: $crate::Value,
+            g: $crate::Value,
+            h: $crate::Value,
+            i: $crate::Value,
+            j: $crate::Value,
+            k: $crate::Value,
+            l: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error(a, b, c, d, e, f, g, h, i, j, k, l)
+        }
+        unsafe extern "C" fn anon_argv(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error_argv(12, argc, argv)
+        }
+        $name.select_wrapper(anon, anon_argv)
+    }};
+    ($name:expr, 13) => {{
+        use $crate::method::{
+            Function13, FunctionKw13, FunctionOpt13, RubyFunction13, RubyFunctionKw13,
+            RubyFunctionOpt13,
+        };
+        unsafe extern "C" fn anon(
+            rb_self: $crate::Value,
+            a: $crate::Value,
+            b: $crate::Value,
+            c: $crate::Value,
+            d: $crate::Value,
+            e: $crate::Value,
+            f: $crate::Value,
+            g: $crate::Value,
+            h: $crate::Value,
+            i: $crate::Value,
+            j: $crate::Value,
+            k: $crate::Value,
+            l: $crate::Value,
+            m: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error(a, b, c, d, e, f, g, h, i, j, k, l, m)
+        }
+        unsafe extern "C" fn anon_argv(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error_argv(13, argc, argv)
+        }
+        $name.select_wrapper(anon, anon_argv)
+    }};
+    ($name:expr, 14) => {{
+        use $crate::method::{
+            Function14, FunctionKw14, FunctionOpt14, RubyFunction14, RubyFunctionKw14,
+            RubyFunctionOpt14,
+        };
+        unsafe extern "C" fn anon(
+            rb_self: $crate::Value,
+            a: $crate::Value,
+            b: $crate::Value,
+            c: $crate::Value,
+            d: $crate::Value,
+            e: $crate::Value,
+            f: $crate::Value,
+            g: $crate::Value,
+            h: $crate::Value,
+            i: $crate::Value,
+            j: $crate::Value,
+            k: $crate::Value,
+            l: $crate::Value,
+            m: $crate::Value,
+            n: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error(a, b, c, d, e, f, g, h, i, j, k, l, m, n)
+        }
+        unsafe extern "C" fn anon_argv(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error_argv(14, argc, argv)
+        }
+        $name.select_wrapper(anon, anon_argv)
+    }};
+    ($name:expr, 15) => {{
+        use $crate::method::{
+            Function15, FunctionKw15, FunctionOpt15, RubyFunction15, RubyFunctionKw15,
+            RubyFunctionOpt15,
+        };
+        unsafe extern "C" fn anon(
+            rb_self: $crate::Value,
+            a: $crate::Value,
+            b: $crate::Value,
+            c: $crate::Value,
+            d: $crate::Value,
+            e: $crate::Value,
+            f: $crate::Value,
+            g: $crate::Value,
+            h: $crate::Value,
+            i: $crate::Value,
+            j: $crate::Value,
+            k: $crate::Value,
+            l: $crate::Value,
+            m: $crate::Value,
+            n: $crate::Value,
+            o: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error(a, b, c, d, e, f, g, h, i, j, k, l, m, n, o)
+        }
+        unsafe extern "C" fn anon_argv(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            $name.call_handle_error_argv(15, argc, argv)
+        }
+        $name.select_wrapper(anon, anon_argv)
+    }};
+    ($name:expr, 16) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{
+                FunctionKw16, FunctionOpt16, RubyFunctionKw16, RubyFunctionOpt16,
+            };
+            $name.call_handle_error_argv(16, argc, argv)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, 17) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{
+                FunctionKw17, FunctionOpt17, RubyFunctionKw17, RubyFunctionOpt17,
+            };
+            $name.call_handle_error_argv(17, argc, argv)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, 18) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{
+                FunctionKw18, FunctionOpt18, RubyFunctionKw18, RubyFunctionOpt18,
+            };
+            $name.call_handle_error_argv(18, argc, argv)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, 19) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{
+                FunctionKw19, FunctionOpt19, RubyFunctionKw19, RubyFunctionOpt19,
+            };
+            $name.call_handle_error_argv(19, argc, argv)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, 20) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{
+                FunctionKw20, FunctionOpt20, RubyFunctionKw20, RubyFunctionOpt20,
+            };
+            $name.call_handle_error_argv(20, argc, argv)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, 21) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{
+                FunctionKw21, FunctionOpt21, RubyFunctionKw21, RubyFunctionOpt21,
+            };
+            $name.call_handle_error_argv(21, argc, argv)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, 22) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{
+                FunctionKw22, FunctionOpt22, RubyFunctionKw22, RubyFunctionOpt22,
+            };
+            $name.call_handle_error_argv(22, argc, argv)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, 23) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{
+                FunctionKw23, FunctionOpt23, RubyFunctionKw23, RubyFunctionOpt23,
+            };
+            $name.call_handle_error_argv(23, argc, argv)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, 24) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{
+                FunctionKw24, FunctionOpt24, RubyFunctionKw24, RubyFunctionOpt24,
+            };
+            $name.call_handle_error_argv(24, argc, argv)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, 25) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{
+                FunctionKw25, FunctionOpt25, RubyFunctionKw25, RubyFunctionOpt25,
+            };
+            $name.call_handle_error_argv(25, argc, argv)
         }
         anon as unsafe extern "C" fn(
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
+            std::os::raw::c_int,
+            *const $crate::Value,
             $crate::Value,
         ) -> $crate::Value
     }};
-    ($name:expr, 7) => {{
+    ($name:expr, 26) => {{
         unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
             rb_self: $crate::Value,
-            a: $crate::Value,
-            b: $crate::Value,
-            c: $crate::Value,
-            d: $crate::Value,
-            e: $crate::Value,
-            f: $crate::Value,
-            g: $crate::Value,
         ) -> $crate::Value {
-            use $crate::method::{Method7, RubyMethod7};
-            $name.call_handle_error(rb_self, a, b, c, d, e, f, g)
+            use $crate::method::{
+                FunctionKw26, FunctionOpt26, RubyFunctionKw26, RubyFunctionOpt26,
+            };
+            $name.call_handle_error_argv(26, argc, argv)
         }
         anon as unsafe extern "C" fn(
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
+            std::os::raw::c_int,
+            *const $crate::Value,
             $crate::Value,
         ) -> $crate::Value
     }};
-    ($name:expr, 8) => {{
+    ($name:expr, 27) => {{
         unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
             rb_self: $crate::Value,
-            a: $crate::Value,
-            b: $crate::Value,
-            c: $crate::Value,
-            d: $crate::Value,
-            e: $crate::Value,
-            f: $crate::Value,
-            g: $crate::Value,
-            h: $crate::Value,
         ) -> $crate::Value {
-            use $crate::method::{Method8, RubyMethod8};
-            $name.call_handle_error(rb_self, a, b, c, d, e, f, g, h)
+            use $crate::method::{
+                FunctionKw27, FunctionOpt27, RubyFunctionKw27, RubyFunctionOpt27,
+            };
+            $name.call_handle_error_argv(27, argc, argv)
         }
         anon as unsafe extern "C" fn(
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
+            std::os::raw::c_int,
+            *const $crate::Value,
             $crate::Value,
         ) -> $crate::Value
     }};
-    ($name:expr, 9) => {{
+    ($name:expr, 28) => {{
         unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
             rb_self: $crate::Value,
-            a: $crate::Value,
-            b: $crate::Value,
-            c: $crate::Value,
-            d: $crate::Value,
-            e: $crate::Value,
-            f: $crate::Value,
-            g: $crate::Value,
-            h: $crate::Value,
-            i: $crate::Value,
         ) -> $crate::Value {
-            use $crate::method::{Method9, RubyMethod9};
-            $name.call_handle_error(rb_self, a, b, c, d, e, f, g, h, i)
+            use $crate::method::{
+                FunctionKw28, FunctionOpt28, RubyFunctionKw28, RubyFunctionOpt28,
+            };
+            $name.call_handle_error_argv(28, argc, argv)
         }
         anon as unsafe extern "C" fn(
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
+            std::os::raw::c_int,
+            *const $crate::Value,
             $crate::Value,
         ) -> $crate::Value
     }};
-    ($name:expr, 10) => {{
+    ($name:expr, 29) => {{
         unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
             rb_self: $crate::Value,
-            a: $crate::Value,
-            b: $crate::Value,
-            c: $crate::Value,
-            d: $crate::Value,
-            e: $crate::Value,
-            f: $crate::Value,
-            g: $crate::Value,
-            h: $crate::Value,
-            i: $crate::Value,
-            j: $crate::Value,
         ) -> $crate::Value {
-            use $crate::method::{Method10, RubyMethod10};
-            $name.call_handle_error(rb_self, a, b, c, d, e, f, g, h, i, j)
+            use $crate::method::{
+                FunctionKw29, FunctionOpt29, RubyFunctionKw29, RubyFunctionOpt29,
+            };
+            $name.call_handle_error_argv(29, argc, argv)
         }
         anon as unsafe extern "C" fn(
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
+            std::os::raw::c_int,
+            *const $crate::Value,
             $crate::Value,
         ) -> $crate::Value
     }};
-    ($name:expr, 11) => {{
+    ($name:expr, 30) => {{
         unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
             rb_self: $crate::Value,
-            a: $crate::Value,
-            b: $crate::Value,
-            c: $crate::Value,
-            d: $crate::Value,
-            e: $crate::Value,
-            f: $crate::Value,
-            g: $crate::Value,
-            h: $crate::Value,
-            i: $crate::Value,
-            j: $crate::Value,
-            k: $crate::Value,
         ) -> $crate::Value {
-            use $crate::method::{Method11, RubyMethod11};
-            $name.call_handle_error(rb_self, a, b, c, d, e, f, g, h, i, j, k)
+            use $crate::method::{
+                FunctionKw30, FunctionOpt30, RubyFunctionKw30, RubyFunctionOpt30,
+            };
+            $name.call_handle_error_argv(30, argc, argv)
         }
         anon as unsafe extern "C" fn(
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
+            std::os::raw::c_int,
+            *const $crate::Value,
             $crate::Value,
         ) -> $crate::Value
     }};
-    ($name:expr, 12) => {{
+    ($name:expr, 31) => {{
         unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
             rb_self: $crate::Value,
-            a: $crate::Value,
-            b: $crate::Value,
-            c: $crate::Value,
-            d: $crate::Value,
-            e: $crate::Value,
-            f: $crate::Value,
-            g: $crate::Value,
-            h: $crate::Value,
-            i: $crate::Value,
-            j: $crate::Value,
-            k: $crate::Value,
-            l: $crate::Value,
         ) -> $crate::Value {
-            use $crate::method::{Method12, RubyMethod12};
-            $name.call_handle_error(rb_self, a, b, c, d, e, f, g, h, i, j, k, l)
+            use $crate::method::{
+                FunctionKw31, FunctionOpt31, RubyFunctionKw31, RubyFunctionOpt31,
+            };
+            $name.call_handle_error_argv(31, argc, argv)
         }
         anon as unsafe extern "C" fn(
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
+            std::os::raw::c_int,
+            *const $crate::Value,
             $crate::Value,
         ) -> $crate::Value
     }};
-    ($name:expr, 13) => {{
+    ($name:expr, 32) => {{
         unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
             rb_self: $crate::Value,
-            a: $crate::Value,
-            b: $crate::Value,
-            c: $crate::Value,
-            d: $crate::Value,
-            e: $crate::Value,
-            f: $crate::Value,
-            g: $crate::Value,
-            h: $crate::Value,
-            i: $crate::Value,
-            j: $crate::Value,
-            k: $crate::Value,
-            l: $crate::Value,
-            m: $crate::Value,
         ) -> $crate::Value {
-            use $crate::method::{Method13, RubyMethod13};
-            $name.call_handle_error(rb_self, a, b, c, d, e, f, g, h, i, j, k, l, m)
+            use $crate::method::{
+                FunctionKw32, FunctionOpt32, RubyFunctionKw32, RubyFunctionOpt32,
+            };
+            $name.call_handle_error_argv(32, argc, argv)
         }
         anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
             $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=1) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{FunctionKw1, FunctionOpt1, RubyFunctionKw1, RubyFunctionOpt1};
+            const _: () = assert!($min <= 1, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
             $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=2) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{FunctionKw2, FunctionOpt2, RubyFunctionKw2, RubyFunctionOpt2};
+            const _: () = assert!($min <= 2, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
             $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=3) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{FunctionKw3, FunctionOpt3, RubyFunctionKw3, RubyFunctionOpt3};
+            const _: () = assert!($min <= 3, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
             $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=4) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{FunctionKw4, FunctionOpt4, RubyFunctionKw4, RubyFunctionOpt4};
+            const _: () = assert!($min <= 4, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
             $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=5) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{FunctionKw5, FunctionOpt5, RubyFunctionKw5, RubyFunctionOpt5};
+            const _: () = assert!($min <= 5, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
             $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=6) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{FunctionKw6, FunctionOpt6, RubyFunctionKw6, RubyFunctionOpt6};
+            const _: () = assert!($min <= 6, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
             $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=7) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{FunctionKw7, FunctionOpt7, RubyFunctionKw7, RubyFunctionOpt7};
+            const _: () = assert!($min <= 7, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
             $crate::Value,
         ) -> $crate::Value
     }};
-    ($name:expr, 14) => {{
+    ($name:expr, $min:literal..=8) => {{
         unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
             rb_self: $crate::Value,
-            a: $crate::Value,
-            b: $crate::Value,
-            c: $crate::Value,
-            d: $crate::Value,
-            e: $crate::Value,
-            f: $crate::Value,
-            g: $crate::Value,
-            h: $crate::Value,
-            i: $crate::Value,
-            j: $crate::Value,
-            k: $crate::Value,
-            l: $crate::Value,
-            m: $crate::Value,
-            n: $crate::Value,
         ) -> $crate::Value {
-            use $crate::method::{Method14, RubyMethod14};
-            $name.call_handle_error(rb_self, a, b, c, d, e, f, g, h, i, j, k, l, m, n)
+            use $crate::method::{FunctionKw8, FunctionOpt8, RubyFunctionKw8, RubyFunctionOpt8};
+            const _: () = assert!($min <= 8, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv)
         }
         anon as unsafe extern "C" fn(
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
+            std::os::raw::c_int,
+            *const $crate::Value,
             $crate::Value,
         ) -> $crate::Value
     }};
-    ($name:expr, 15) => {{
+    ($name:expr, $min:literal..=9) => {{
         unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
             rb_self: $crate::Value,
-            a: $crate::Value,
-            b: $crate::Value,
-            c: $crate::Value,
-            d: $crate::Value,
-            e: $crate::Value,
-            f: $crate::Value,
-            g: $crate::Value,
-            h: $crate::Value,
-            i: $crate::Value,
-            j: $crate::Value,
-            k: $crate::Value,
-            l: $crate::Value,
-            m: $crate::Value,
-            n: $crate::Value,
-            o: $crate::Value,
         ) -> $crate::Value {
-            use $crate::method::{Method15, RubyMethod15};
-            $name.call_handle_error(rb_self, a, b, c, d, e, f, g, h, i, j, k, l, m, n, o)
+            use $crate::method::{FunctionKw9, FunctionOpt9, RubyFunctionKw9, RubyFunctionOpt9};
+            const _: () = assert!($min <= 9, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv)
         }
         anon as unsafe extern "C" fn(
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
+            std::os::raw::c_int,
+            *const $crate::Value,
             $crate::Value,
         ) -> $crate::Value
     }};
-    ($name:expr, 16) => {{
+    ($name:expr, $min:literal..=10) => {{
         unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
             rb_self: $crate::Value,
-            a: $crate::Value,
-            b: $crate::Value,
-            c: $crate::Value,
-            d: $crate::Value,
-            e: $crate::Value,
-            f: $crate::Value,
-            g: $crate::Value,
-            h: $crate::Value,
-            i: $crate::Value,
-            j: $crate::Value,
-            k: $crate::Value,
-            l: $crate::Value,
-            m: $crate::Value,
-            n: $crate::Value,
-            o: $crate::Value,
-            p: $crate::Value,
         ) -> $crate::Value {
-            use $crate::method::{Method16, RubyMethod16};
-            $name.call_handle_error(rb_self, a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p)
+            use $crate::method::{
+                FunctionKw10, FunctionOpt10, RubyFunctionKw10, RubyFunctionOpt10,
+            };
+            const _: () = assert!($min <= 10, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv)
         }
         anon as unsafe extern "C" fn(
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
+            std::os::raw::c_int,
+            *const $crate::Value,
             $crate::Value,
         ) -> $crate::Value
     }};
-    ($name:expr, $arity:expr) => {
-        compile_error!("arity must be an integer literal between -2..=16")
-    };
-}
-
-/// Helper trait for wrapping a function as a Ruby method ignoring self and
-/// taking a Ruby array of arguments, with type conversions and error handling.
-///
-/// See the [`function`](crate::function!) macro.
-#[doc(hidden)]
-pub trait FunctionRbAry<Args, Res>
-where
-    Self: Sized + Fn(Args) -> Res,
-    Args: TryConvert,
-    Res: ReturnValue,
-{
-    #[inline]
-    fn call_convert_value(self, args: RArray) -> Result<Value, Error> {
-        (self)(TryConvert::try_convert(args.as_value())?).into_return_value()
-    }
-
-    #[inline]
-    unsafe fn call_handle_error(self, args: RArray) -> Value {
-        let res = match std::panic::catch_unwind(AssertUnwindSafe(|| self.call_convert_value(args)))
-        {
-            Ok(v) => v,
-            Err(e) => Err(Error::from_panic(e)),
-        };
-        match res {
-            Ok(v) => v,
-            Err(e) => raise(e),
-        }
-    }
-}
-
-impl<Func, Args, Res> FunctionRbAry<Args, Res> for Func
-where
-    Func: Fn(Args) -> Res,
-    Args: TryConvert,
-    Res: ReturnValue,
-{
-}
-
-/// Helper trait for wrapping a function as a Ruby method taking
-/// [`&Ruby`](Ruby), ignoring self, and taking a Ruby array of arguments, with
-/// type conversions and error handling.
-///
-/// See the [`function`](crate::function!) macro.
-#[doc(hidden)]
-pub trait RubyFunctionRbAry<Args, Res>
-where
-    Self: Sized + Fn(&Ruby, Args) -> Res,
-    Args: TryConvert,
-    Res: ReturnValue,
-{
-    #[inline]
-    fn call_convert_value(self, args: RArray) -> Result<Value, Error> {
-        (self)(
-            &Ruby::get_with(args),
-            TryConvert::try_convert(args.as_value())?,
-        )
-        .into_return_value()
-    }
-
-    #[inline]
-    unsafe fn call_handle_error(self, args: RArray) -> Value {
-        let res = match std::panic::catch_unwind(AssertUnwindSafe(|| self.call_convert_value(args)))
-        {
-            Ok(v) => v,
-            Err(e) => Err(Error::from_panic(e)),
-        };
-        match res {
-            Ok(v) => v,
-            Err(e) => raise(e),
-        }
-    }
-}
-
-impl<Func, Args, Res> RubyFunctionRbAry<Args, Res> for Func
-where
-    Func: Fn(&Ruby, Args) -> Res,
-    Args: TryConvert,
-    Res: ReturnValue,
-{
-}
-
-/// Helper trait for wrapping a function as a Ruby method ignoring self and
-/// taking a slice of arguments, with type conversions and error handling.
-///
-/// See the [`function`](crate::function!) macro.
-#[doc(hidden)]
-pub trait FunctionCAry<Res>
-where
-    Self: Sized + Fn(&[Value]) -> Res,
-    Res: ReturnValue,
-{
-    #[inline]
-    unsafe fn call_convert_value(self, argc: c_int, argv: *const Value) -> Result<Value, Error> {
-        let args = slice::from_raw_parts(argv, argc as usize);
-        (self)(args).into_return_value()
-    }
-
-    #[inline]
-    unsafe fn call_handle_error(self, argc: c_int, argv: *const Value) -> Value {
-        let res = match std::panic::catch_unwind(AssertUnwindSafe(|| {
-            self.call_convert_value(argc, argv)
-        })) {
-            Ok(v) => v,
-            Err(e) => Err(Error::from_panic(e)),
-        };
-        match res {
-            Ok(v) => v,
-            Err(e) => raise(e),
-        }
-    }
-}
-
-impl<Func, Res> FunctionCAry<Res> for Func
-where
-    Func: Fn(&[Value]) -> Res,
-    Res: ReturnValue,
-{
-}
-
-/// Helper trait for wrapping a function as a Ruby method taking
-/// [`&Ruby`](Ruby), ignoring self, and taking a slice of arguments, with type
-/// conversions and error handling.
-///
-/// See the [`function`](crate::function!) macro.
-#[doc(hidden)]
-pub trait RubyFunctionCAry<Res>
-where
-    Self: Sized + Fn(&Ruby, &[Value]) -> Res,
-    Res: ReturnValue,
-{
-    #[inline]
-    unsafe fn call_convert_value(self, argc: c_int, argv: *const Value) -> Result<Value, Error> {
-        let args = slice::from_raw_parts(argv, argc as usize);
-        (self)(&Ruby::get_unchecked(), args).into_return_value()
-    }
-
-    #[inline]
-    unsafe fn call_handle_error(self, argc: c_int, argv: *const Value) -> Value {
-        let res = match std::panic::catch_unwind(AssertUnwindSafe(|| {
-            self.call_convert_value(argc, argv)
-        })) {
-            Ok(v) => v,
-            Err(e) => Err(Error::from_panic(e)),
-        };
-        match res {
-            Ok(v) => v,
-            Err(e) => raise(e),
+    ($name:expr, $min:literal..=11) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{
+                FunctionKw11, FunctionOpt11, RubyFunctionKw11, RubyFunctionOpt11,
+            };
+            const _: () = assert!($min <= 11, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv)
         }
-    }
-}
-
-impl<Func, Res> RubyFunctionCAry<Res> for Func
-where
-    Func: Fn(&Ruby, &[Value]) -> Res,
-    Res: ReturnValue,
-{
-}
-
-macro_rules! function_n {
-    ($name:ident, $ruby_name:ident, $n:literal) => {
-        seq!(N in 0..$n {
-            /// Helper trait for wrapping a function as a Ruby method ignoring
-            /// self and taking N arguments, with type conversions and error
-            /// handling.
-            ///
-            /// See the [`function`](crate::function!) macro.
-            #[doc(hidden)]
-            pub trait $name<#(T~N,)* Res>
-            where
-                Self: Sized + Fn(#(T~N,)*) -> Res,
-                #(T~N: TryConvert,)*
-                Res: ReturnValue,
-            {
-                #[inline]
-                fn call_convert_value(self, #(arg~N: Value,)*) -> Result<Value, Error> {
-                    (self)(
-                        #(TryConvert::try_convert(arg~N)?,)*
-                    ).into_return_value()
-                }
-
-                #[inline]
-                unsafe fn call_handle_error(self, #(arg~N: Value,)*) -> Value {
-                    let res =
-                        match std::panic::catch_unwind(AssertUnwindSafe(|| {
-                            self.call_convert_value(#(arg~N,)*)
-                        })) {
-                            Ok(v) => v,
-                            Err(e) => Err(Error::from_panic(e)),
-                        };
-                    match res {
-                        Ok(v) => v,
-                        Err(e) => raise(e),
-                    }
-                }
-            }
-
-            impl<Func, #(T~N,)* Res> $name<#(T~N,)* Res> for Func
-            where
-                Func: Fn(#(T~N,)*) -> Res,
-                #(T~N: TryConvert,)*
-                Res: ReturnValue,
-            {}
-
-            /// Helper trait for wrapping a function as a Ruby method taking
-            /// [`&Ruby`](Ruby), ignoring self, and taking N arguments, with
-            /// type conversions and error handling.
-            ///
-            /// See the [`function`](crate::function!) macro.
-            #[doc(hidden)]
-            pub trait $ruby_name<#(T~N,)* Res>
-            where
-                Self: Sized + Fn(&Ruby, #(T~N,)*) -> Res,
-                #(T~N: TryConvert,)*
-                Res: ReturnValue,
-            {
-                #[inline]
-                unsafe fn call_convert_value(self, #(arg~N: Value,)*) -> Result<Value, Error> {
-                    (self)(
-                        &Ruby::get_unchecked(),
-                        #(TryConvert::try_convert(arg~N)?,)*
-                    ).into_return_value()
-                }
-
-                #[inline]
-                unsafe fn call_handle_error(self, #(arg~N: Value,)*) -> Value {
-                    let res =
-                        match std::panic::catch_unwind(AssertUnwindSafe(|| {
-                            self.call_convert_value(#(arg~N,)*)
-                        })) {
-                            Ok(v) => v,
-                            Err(e) => Err(Error::from_panic(e)),
-                        };
-                    match res {
-                        Ok(v) => v,
-                        Err(e) => raise(e),
-                    }
-                }
-            }
-
-            impl<Func, #(T~N,)* Res> $ruby_name<#(T~N,)* Res> for Func
-            where
-                Func: Fn(&Ruby, #(T~N,)*) -> Res,
-                #(T~N: TryConvert,)*
-                Res: ReturnValue,
-            {}
-        });
-    }
-}
-
-seq!(N in 0..=16 {
-    function_n!(Function~N, RubyFunction~N, N);
-});
-
-/// Wrap a Rust function item with Ruby type conversion and error handling,
-/// ignoring Ruby's `self` argument.
-///
-/// This macro wraps the given function and returns a function pointer
-/// implementing the [`Method`] trait, suitable for passing to functions that
-/// define Ruby methods such as
-/// [`define_method`](crate::module::Module::define_method).
-///
-/// Ruby code implicitly always has a `self` parameter available. In the
-/// extention API this is passed explicitly. The wrapper this macro generates
-/// ignores that argument, and does not pass it to the wrapped function.
-///
-/// The values `-2` and `-1` for `arity` have special meaning. Both indicate
-/// functions with any number of arguments, with `-2` the arguments are passed
-/// as a [`RArray`], with `-1` they are passed as a slice of [`Value`]s.
-/// Arity of `-1` can be used with [`scan_args`](crate::scan_args::scan_args)
-/// and [`get_kwargs`](crate::scan_args::get_kwargs) for more complex method
-/// signatures.
-///
-/// | Arity | Signature                                     |
-/// |-------|-----------------------------------------------|
-/// |    -2 | `fn(arguments: RArray) -> Result<R, Error>`   |
-/// |    -1 | `fn(arguments: &[Value]) -> Result<R, Error>` |
-/// |     0 | `fn()-> Result<R, Error>`                     |
-/// |     1 | `fn(arg1: T) -> Result<R, Error>`             |
-/// |     2 | `fn(arg1: T, arg2: U) -> Result<R, Error>`    |
-/// |   ... | ...                                           |
-/// |    16 | ...                                           |
-///
-/// Where `T`, `U`, and so on are any types that implement `TryConvert`,
-/// and `R` implements [`IntoValue`]. It is also possible to return just `R`
-/// rather than a `Result` for functions that will never error, and omit the
-/// return value (i.e. return `()`) for a function that returns `nil` to Ruby.
-/// See [`ReturnValue`] for more details on what can be returned.
-///
-/// See the [`method`](crate::method!) macro for cases where the `self`
-/// argument is required.
-///
-/// # Examples
-///
-/// ```
-/// fn distance(a: (f64, f64), b: (f64, f64)) -> f64 {
-///     ((b.0 - a.0).powi(2) + (b.0 - a.0).powi(2)).sqrt()
-/// }
-///
-/// #[magnus::init]
-/// fn init() {
-///     magnus::define_global_function("distance", magnus::function!(distance, 2));
-/// }
-/// # let _cleanup = unsafe { magnus::embed::init() };
-/// # init();
-/// ```
-#[macro_export]
-macro_rules! function {
-    ($name:expr, -2) => {{
-        unsafe extern "C" fn anon(rb_self: $crate::Value, args: $crate::RArray) -> $crate::Value {
-            use $crate::method::{FunctionRbAry, RubyFunctionRbAry};
-            $name.call_handle_error(args)
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=12) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{
+                FunctionKw12, FunctionOpt12, RubyFunctionKw12, RubyFunctionOpt12,
+            };
+            const _: () = assert!($min <= 12, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv)
         }
-        anon as unsafe extern "C" fn($crate::Value, $crate::RArray) -> $crate::Value
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
     }};
-    ($name:expr, -1) => {{
+    ($name:expr, $min:literal..=13) => {{
         unsafe extern "C" fn anon(
             argc: std::os::raw::c_int,
             argv: *const $crate::Value,
             rb_self: $crate::Value,
         ) -> $crate::Value {
-            use $crate::method::{FunctionCAry, RubyFunctionCAry};
-            $name.call_handle_error(argc, argv)
+            use $crate::method::{
+                FunctionKw13, FunctionOpt13, RubyFunctionKw13, RubyFunctionOpt13,
+            };
+            const _: () = assert!($min <= 13, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv)
         }
         anon as unsafe extern "C" fn(
             std::os::raw::c_int,
@@ -1550,452 +4061,754 @@ macro_rules! function {
             $crate::Value,
         ) -> $crate::Value
     }};
-    ($name:expr, 0) => {{
-        unsafe extern "C" fn anon(rb_self: $crate::Value) -> $crate::Value {
-            use $crate::method::{Function0, RubyFunction0};
-            $name.call_handle_error()
+    ($name:expr, $min:literal..=14) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{
+                FunctionKw14, FunctionOpt14, RubyFunctionKw14, RubyFunctionOpt14,
+            };
+            const _: () = assert!($min <= 14, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=15) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{
+                FunctionKw15, FunctionOpt15, RubyFunctionKw15, RubyFunctionOpt15,
+            };
+            const _: () = assert!($min <= 15, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv)
         }
-        anon as unsafe extern "C" fn($crate::Value) -> $crate::Value
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
     }};
-    ($name:expr, 1) => {{
-        unsafe extern "C" fn anon(rb_self: $crate::Value, a: $crate::Value) -> $crate::Value {
-            use $crate::method::{Function1, RubyFunction1};
-            $name.call_handle_error(a)
+    ($name:expr, $min:literal..=16) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{
+                FunctionKw16, FunctionOpt16, RubyFunctionKw16, RubyFunctionOpt16,
+            };
+            const _: () = assert!($min <= 16, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv)
         }
-        anon as unsafe extern "C" fn($crate::Value, $crate::Value) -> $crate::Value
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
     }};
-    ($name:expr, 2) => {{
+    ($name:expr, $min:literal..=17) => {{
         unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
             rb_self: $crate::Value,
-            a: $crate::Value,
-            b: $crate::Value,
         ) -> $crate::Value {
-            use $crate::method::{Function2, RubyFunction2};
-            $name.call_handle_error(a, b)
+            use $crate::method::{
+                FunctionKw17, FunctionOpt17, RubyFunctionKw17, RubyFunctionOpt17,
+            };
+            const _: () = assert!($min <= 17, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv)
         }
-        anon as unsafe extern "C" fn($crate::Value, $crate::Value, $crate::Value) -> $crate::Value
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
     }};
-    ($name:expr, 3) => {{
+    ($name:expr, $min:literal..=18) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{
+                FunctionKw18, FunctionOpt18, RubyFunctionKw18, RubyFunctionOpt18,
+            };
+            const _: () = assert!($min <= 18, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=19) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::{
+                FunctionKw19, FunctionOpt19, RubyFunctionKw19, RubyFunctionOpt19,
+            };
+            const _: () = assert!($min <= 19, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv)
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
+            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($name:expr, $min:literal..=20) => {{
         unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
             rb_self: $crate::Value,
-            a: $crate::Value,
-            b: $crate::Value,
-            c: $crate::Value,
         ) -> $crate::Value {
-            use $crate::method::{Function3, RubyFunction3};
-            $name.call_handle_error(a, b, c)
+            use $crate::method::{
+                FunctionKw20, FunctionOpt20, RubyFunctionKw20, RubyFunctionOpt20,
+            };
+            const _: () = assert!($min <= 20, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv)
         }
         anon as unsafe extern "C" fn(
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
+            std::os::raw::c_int,
+            *const $crate::Value,
             $crate::Value,
         ) -> $crate::Value
     }};
-    ($name:expr, 4) => {{
+    ($name:expr, $min:literal..=21) => {{
         unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
             rb_self: $crate::Value,
-            a: $crate::Value,
-            b: $crate::Value,
-            c: $crate::Value,
-            d: $crate::Value,
         ) -> $crate::Value {
-            use $crate::method::{Function4, RubyFunction4};
-            $name.call_handle_error(a, b, c, d)
+            use $crate::method::{
+                FunctionKw21, FunctionOpt21, RubyFunctionKw21, RubyFunctionOpt21,
+            };
+            const _: () = assert!($min <= 21, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv)
         }
         anon as unsafe extern "C" fn(
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
+            std::os::raw::c_int,
+            *const $crate::Value,
             $crate::Value,
         ) -> $crate::Value
     }};
-    ($name:expr, 5) => {{
+    ($name:expr, $min:literal..=22) => {{
         unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
             rb_self: $crate::Value,
-            a: $crate::Value,
-            b: $crate::Value,
-            c: $crate::Value,
-            d: $crate::Value,
-            e: $crate::Value,
         ) -> $crate::Value {
-            use $crate::method::{Function5, RubyFunction5};
-            $name.call_handle_error(a, b, c, d, e)
+            use $crate::method::{
+                FunctionKw22, FunctionOpt22, RubyFunctionKw22, RubyFunctionOpt22,
+            };
+            const _: () = assert!($min <= 22, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv)
         }
         anon as unsafe extern "C" fn(
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
+            std::os::raw::c_int,
+            *const $crate::Value,
             $crate::Value,
         ) -> $crate::Value
     }};
-    ($name:expr, 6) => {{
+    ($name:expr, $min:literal..=23) => {{
         unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
             rb_self: $crate::Value,
-            a: $crate::Value,
-            b: $crate::Value,
-            c: $crate::Value,
-            d: $crate::Value,
-            e: $crate::Value,
-            f: $crate::Value,
         ) -> $crate::Value {
-            use $crate::method::{Function6, RubyFunction6};
-            $name.call_handle_error(a, b, c, d, e, f)
+            use $crate::method::{
+                FunctionKw23, FunctionOpt23, RubyFunctionKw23, RubyFunctionOpt23,
+            };
+            const _: () = assert!($min <= 23, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv)
         }
         anon as unsafe extern "C" fn(
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
+            std::os::raw::c_int,
+            *const $crate::Value,
             $crate::Value,
         ) -> $crate::Value
     }};
-    ($name:expr, 7) => {{
+    ($name:expr, $min:literal..=24) => {{
         unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
             rb_self: $crate::Value,
-            a: $crate::Value,
-            b: $crate::Value,
-            c: $crate::Value,
-            d: $crate::Value,
-            e: $crate::Value,
-            f: $crate::Value,
-            g: $crate::Value,
         ) -> $crate::Value {
-            use $crate::method::{Function7, RubyFunction7};
-            $name.call_handle_error(a, b, c, d, e, f, g)
+            use $crate::method::{
+                FunctionKw24, FunctionOpt24, RubyFunctionKw24, RubyFunctionOpt24,
+            };
+            const _: () = assert!($min <= 24, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv)
         }
         anon as unsafe extern "C" fn(
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
+            std::os::raw::c_int,
+            *const $crate::Value,
             $crate::Value,
         ) -> $crate::Value
     }};
-    ($name:expr, 8) => {{
+    ($name:expr, $min:literal..=25) => {{
         unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
             rb_self: $crate::Value,
-            a: $crate::Value,
-            b: $crate::Value,
-            c: $crate::Value,
-            d: $crate::Value,
-            e: $crate::Value,
-            f: $crate::Value,
-            g: $crate::Value,
-            h: $crate::Value,
         ) -> $crate::Value {
-            use $crate::method::{Function8, RubyFunction8};
-            $name.call_handle_error(a, b, c, d, e, f, g, h)
+            use $crate::method::{
+                FunctionKw25, FunctionOpt25, RubyFunctionKw25, RubyFunctionOpt25,
+            };
+            const _: () = assert!($min <= 25, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv)
         }
         anon as unsafe extern "C" fn(
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
+            std::os::raw::c_int,
+            *const $crate::Value,
             $crate::Value,
         ) -> $crate::Value
     }};
-    ($name:expr, 9) => {{
+    ($name:expr, $min:literal..=26) => {{
         unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
             rb_self: $crate::Value,
-            a: $crate::Value,
-            b: $crate::Value,
-            c: $crate::Value,
-            d: $crate::Value,
-            e: $crate::Value,
-            f: $crate::Value,
-            g: $crate::Value,
-            h: $crate::Value,
-            i: $crate::Value,
         ) -> $crate::Value {
-            use $crate::method::{Function9, RubyFunction9};
-            $name.call_handle_error(a, b, c, d, e, f, g, h, i)
+            use $crate::method::{
+                FunctionKw26, FunctionOpt26, RubyFunctionKw26, RubyFunctionOpt26,
+            };
+            const _: () = assert!($min <= 26, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv)
         }
         anon as unsafe extern "C" fn(
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
+            std::os::raw::c_int,
+            *const $crate::Value,
             $crate::Value,
         ) -> $crate::Value
     }};
-    ($name:expr, 10) => {{
+    ($name:expr, $min:literal..=27) => {{
         unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
             rb_self: $crate::Value,
-            a: $crate::Value,
-            b: $crate::Value,
-            c: $crate::Value,
-            d: $crate::Value,
-            e: $crate::Value,
-            f: $crate::Value,
-            g: $crate::Value,
-            h: $crate::Value,
-            i: $crate::Value,
-            j: $crate::Value,
         ) -> $crate::Value {
-            use $crate::method::{Function10, RubyFunction10};
-            $name.call_handle_error(a, b, c, d, e, f, g, h, i, j)
+            use $crate::method::{
+                FunctionKw27, FunctionOpt27, RubyFunctionKw27, RubyFunctionOpt27,
+            };
+            const _: () = assert!($min <= 27, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv)
         }
         anon as unsafe extern "C" fn(
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
+            std::os::raw::c_int,
+            *const $crate::Value,
             $crate::Value,
         ) -> $crate::Value
     }};
-    ($name:expr, 11) => {{
+    ($name:expr, $min:literal..=28) => {{
         unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
             rb_self: $crate::Value,
-            a: $crate::Value,
-            b: $crate::Value,
-            c: $crate::Value,
-            d: $crate::Value,
-            e: $crate::Value,
-            f: $crate::Value,
-            g: $crate::Value,
-            h: $crate::Value,
-            i: $crate::Value,
-            j: $crate::Value,
-            k: $crate::Value,
         ) -> $crate::Value {
-            use $crate::method::{Function11, RubyFunction11};
-            $name.call_handle_error(a, b, c, d, e, f, g, h, i, j, k)
+            use $crate::method::{
+                FunctionKw28, FunctionOpt28, RubyFunctionKw28, RubyFunctionOpt28,
+            };
+            const _: () = assert!($min <= 28, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv)
         }
         anon as unsafe extern "C" fn(
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
+            std::os::raw::c_int,
+            *const $crate::Value,
             $crate::Value,
         ) -> $crate::Value
     }};
-    ($name:expr, 12) => {{
+    ($name:expr, $min:literal..=29) => {{
         unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
             rb_self: $crate::Value,
-            a: $crate::Value,
-            b: $crate::Value,
-            c: $crate::Value,
-            d: $crate::Value,
-            e: $crate::Value,
-            f: $crate::Value,
-            g: $crate::Value,
-            h: $crate::Value,
-            i: $crate::Value,
-            j: $crate::Value,
-            k: $crate::Value,
-            l: $crate::Value,
         ) -> $crate::Value {
-            use $crate::method::{Function12, RubyFunction12};
-            $name.call_handle_error(a, b, c, d, e, f, g, h, i, j, k, l)
+            use $crate::method::{
+                FunctionKw29, FunctionOpt29, RubyFunctionKw29, RubyFunctionOpt29,
+            };
+            const _: () = assert!($min <= 29, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv)
         }
         anon as unsafe extern "C" fn(
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
+            std::os::raw::c_int,
+            *const $crate::Value,
             $crate::Value,
         ) -> $crate::Value
     }};
-    ($name:expr, 13) => {{
+    ($name:expr, $min:literal..=30) => {{
         unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
             rb_self: $crate::Value,
-            a: $crate::Value,
-            b: $crate::Value,
-            c: $crate::Value,
-            d: $crate::Value,
-            e: $crate::Value,
-            f: $crate::Value,
-            g: $crate::Value,
-            h: $crate::Value,
-            i: $crate::Value,
-            j: $crate::Value,
-            k: $crate::Value,
-            l: $crate::Value,
-            m: $crate::Value,
         ) -> $crate::Value {
-            use $crate::method::{Function13, RubyFunction13};
-            $name.call_handle_error(a, b, c, d, e, f, g, h, i, j, k, l, m)
+            use $crate::method::{
+                FunctionKw30, FunctionOpt30, RubyFunctionKw30, RubyFunctionOpt30,
+            };
+            const _: () = assert!($min <= 30, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv)
         }
         anon as unsafe extern "C" fn(
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
+            std::os::raw::c_int,
+            *const $crate::Value,
             $crate::Value,
         ) -> $crate::Value
     }};
-    ($name:expr, 14) => {{
-        unsafe extern "C" fn anon(
-            rb_self: $crate::Value,
-            a: $crate::Value,
-            b: $crate::Value,
-            c: $crate::Value,
-            d: $crate::Value,
-            e: $crate::Value,
-            f: $crate::Value,
-            g: $crate::Value,
-            h: $crate::Value,
-            i: $crate::Value,
-            j: $crate::Value,
-            k: $crate::Value,
-            l: $crate::Value,
-            m: $crate::Value,
-            n: $crate::Value,
+    ($name:expr, $min:literal..=31) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
         ) -> $crate::Value {
-            use $crate::method::{Function14, RubyFunction14};
-            $name.call_handle_error(a, b, c, d, e, f, g, h, i, j, k, l, m, n)
+            use $crate::method::{
+                FunctionKw31, FunctionOpt31, RubyFunctionKw31, RubyFunctionOpt31,
+            };
+            const _: () = assert!($min <= 31, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv)
         }
         anon as unsafe extern "C" fn(
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
+            std::os::raw::c_int,
+            *const $crate::Value,
             $crate::Value,
         ) -> $crate::Value
     }};
-    ($name:expr, 15) => {{
+    ($name:expr, $min:literal..=32) => {{
         unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
             rb_self: $crate::Value,
-            a: $crate::Value,
-            b: $crate::Value,
-            c: $crate::Value,
-            d: $crate::Value,
-            e: $crate::Value,
-            f: $crate::Value,
-            g: $crate::Value,
-            h: $crate::Value,
-            i: $crate::Value,
-            j: $crate::Value,
-            k: $crate::Value,
-            l: $crate::Value,
-            m: $crate::Value,
-            n: $crate::Value,
-            o: $crate::Value,
         ) -> $crate::Value {
-            use $crate::method::{Function15, RubyFunction15};
-            $name.call_handle_error(a, b, c, d, e, f, g, h, i, j, k, l, m, n, o)
+            use $crate::method::{
+                FunctionKw32, FunctionOpt32, RubyFunctionKw32, RubyFunctionOpt32,
+            };
+            const _: () = assert!($min <= 32, "minimum arity must not exceed maximum");
+            $name.call_handle_error_argv($min, argc, argv)
         }
         anon as unsafe extern "C" fn(
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
+            std::os::raw::c_int,
+            *const $crate::Value,
             $crate::Value,
         ) -> $crate::Value
     }};
-    ($name:expr, 16) => {{
+    ($name:expr, $arity:expr) => {
+        compile_error!(concat!(
+            "arity must be an integer literal between -2..=32, ",
+            "or a range with a maximum between 1..=32",
+        ))
+    };
+}
+
+/// Marker for functions taking [`&Ruby`](Ruby) as their first argument.
+///
+/// See the [`overload`](crate::overload!) macro and [`MethodFn`].
+#[doc(hidden)]
+pub struct WithRuby<T>(PhantomData<T>);
+
+/// Helper trait for wrapping a function as a candidate of an overloaded Ruby
+/// method ignoring self, with type conversions.
+///
+/// See the [`overload`](crate::overload!) macro.
+#[doc(hidden)]
+pub trait FunctionOverload<Args, Res> {
+    /// Calls the function, or returns `Ok(None)` if `args` do not match the
+    /// function's arguments.
+    ///
+    /// Errors other than a `TypeError` when converting the arguments are
+    /// returned rather than trying the next function.
+    fn try_call(&self, args: &[Value]) -> Result<Option<Value>, Error>;
+
+    /// Describes the function's arguments, for error messages.
+    fn signature(&self) -> String;
+}
+
+/// Helper trait for wrapping a function as a candidate of an overloaded Ruby
+/// method taking self, with type conversions.
+///
+/// See the [`overload`](crate::overload!) macro.
+#[doc(hidden)]
+pub trait MethodOverload<RbSelf, Args, Res> {
+    /// Calls the function, or returns `Ok(None)` if `rb_self` or `args` do not
+    /// match the function's arguments.
+    ///
+    /// Errors other than a `TypeError` when converting the arguments are
+    /// returned rather than trying the next function.
+    fn try_call(&self, rb_self: Value, args: &[Value]) -> Result<Option<Value>, Error>;
+
+    /// Describes the function's arguments, not including self, for error
+    /// messages.
+    fn signature(&self) -> String;
+}
+
+// Converts an argument for an overload candidate, returning `None` if it is
+// the wrong type so the next candidate can be tried.
+fn overload_arg<T>(val: Value) -> Result<Option<T>, Error>
+where
+    T: TryConvert,
+{
+    match T::try_convert(val) {
+        Ok(v) => Ok(Some(v)),
+        Err(e) if e.is_kind_of(Ruby::get_with(val).exception_type_error()) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+macro_rules! overload_n {
+    ($n:literal) => {
+        seq!(N in 0..$n {
+            impl<Func, #(T~N,)* Res> FunctionOverload<(#(T~N,)*), Res> for Func
+            where
+                Func: Fn(#(T~N,)*) -> Res,
+                #(T~N: TryConvert,)*
+                Res: ReturnValue,
+            {
+                #[allow(unused_variables)]
+                fn try_call(&self, args: &[Value]) -> Result<Option<Value>, Error> {
+                    if args.len() != $n {
+                        return Ok(None);
+                    }
+                    (self)(
+                        #(match overload_arg(args[N])? { Some(v) => v, None => return Ok(None) },)*
+                    )
+                    .into_return_value()
+                    .map(Some)
+                }
+
+                fn signature(&self) -> String {
+                    signature(&[#(short_type_name::<T~N>(),)*])
+                }
+            }
+
+            impl<Func, #(T~N,)* Res> FunctionOverload<WithRuby<(#(T~N,)*)>, Res> for Func
+            where
+                Func: Fn(&Ruby, #(T~N,)*) -> Res,
+                #(T~N: TryConvert,)*
+                Res: ReturnValue,
+            {
+                #[allow(unused_variables)]
+                fn try_call(&self, args: &[Value]) -> Result<Option<Value>, Error> {
+                    if args.len() != $n {
+                        return Ok(None);
+                    }
+                    let handle = unsafe { Ruby::get_unchecked() };
+                    (self)(
+                        &handle,
+                        #(match overload_arg(args[N])? { Some(v) => v, None => return Ok(None) },)*
+                    )
+                    .into_return_value()
+                    .map(Some)
+                }
+
+                fn signature(&self) -> String {
+                    signature(&[#(short_type_name::<T~N>(),)*])
+                }
+            }
+
+            impl<Func, RbSelf, #(T~N,)* Res> MethodOverload<RbSelf, (#(T~N,)*), Res> for Func
+            where
+                Func: Fn(RbSelf, #(T~N,)*) -> Res,
+                RbSelf: TryConvert,
+                #(T~N: TryConvert,)*
+                Res: ReturnValue,
+            {
+                #[allow(unused_variables)]
+                fn try_call(&self, rb_self: Value, args: &[Value]) -> Result<Option<Value>, Error> {
+                    if args.len() != $n {
+                        return Ok(None);
+                    }
+                    (self)(
+                        match overload_arg(rb_self)? { Some(v) => v, None => return Ok(None) },
+                        #(match overload_arg(args[N])? { Some(v) => v, None => return Ok(None) },)*
+                    )
+                    .into_return_value()
+                    .map(Some)
+                }
+
+                fn signature(&self) -> String {
+                    signature(&[#(short_type_name::<T~N>(),)*])
+                }
+            }
+
+            impl<Func, RbSelf, #(T~N,)* Res> MethodOverload<RbSelf, WithRuby<(#(T~N,)*)>, Res>
+                for Func
+            where
+                Func: Fn(&Ruby, RbSelf, #(T~N,)*) -> Res,
+                RbSelf: TryConvert,
+                #(T~N: TryConvert,)*
+                Res: ReturnValue,
+            {
+                #[allow(unused_variables)]
+                fn try_call(&self, rb_self: Value, args: &[Value]) -> Result<Option<Value>, Error> {
+                    if args.len() != $n {
+                        return Ok(None);
+                    }
+                    (self)(
+                        &Ruby::get_with(rb_self),
+                        match overload_arg(rb_self)? { Some(v) => v, None => return Ok(None) },
+                        #(match overload_arg(args[N])? { Some(v) => v, None => return Ok(None) },)*
+                    )
+                    .into_return_value()
+                    .map(Some)
+                }
+
+                fn signature(&self) -> String {
+                    signature(&[#(short_type_name::<T~N>(),)*])
+                }
+            }
+        });
+    }
+}
+
+seq!(N in 0..=32 {
+    overload_n!(N);
+});
+
+// Shortens a type name by removing module paths, e.g.
+// `core::option::Option<alloc::string::String>` becomes `Option<String>`.
+fn short_type_name<T>() -> String {
+    fn strip_path(s: &str) -> &str {
+        s.rsplit("::").next().unwrap_or(s)
+    }
+
+    let name = type_name::<T>();
+    let mut out = String::with_capacity(name.len());
+    let mut start = 0;
+    for (i, c) in name.char_indices() {
+        if c.is_alphanumeric() || c == '_' || c == ':' {
+            continue;
+        }
+        out.push_str(strip_path(&name[start..i]));
+        out.push(c);
+        start = i + c.len_utf8();
+    }
+    out.push_str(strip_path(&name[start..]));
+    out
+}
+
+fn signature(types: &[String]) -> String {
+    format!("({})", types.join(", "))
+}
+
+/// Creates the `TypeError` raised when no candidate of an overloaded method
+/// matches the given arguments.
+///
+/// See the [`overload`](crate::overload!) macro.
+#[doc(hidden)]
+pub fn overload_error(args: &[Value], signatures: &[String]) -> Error {
+    let handle = unsafe { Ruby::get_unchecked() };
+    let given = args
+        .iter()
+        .map(|arg| arg.class().inspect())
+        .collect::<Vec<_>>()
+        .join(", ");
+    Error::new(
+        handle.exception_type_error(),
+        format!(
+            "wrong argument types ({}), expected one of: {}",
+            given,
+            signatures.join(", ")
+        ),
+    )
+}
+
+/// Runs `func`, converting panics to Ruby exceptions, and raising any error.
+///
+/// See the [`overload`](crate::overload!) macro.
+#[doc(hidden)]
+pub unsafe fn overload_handle_error<F>(func: F) -> Value
+where
+    F: FnOnce() -> Result<Value, Error>,
+{
+    let res = match catch_unwind(AssertUnwindSafe(func)) {
+        Ok(v) => v,
+        Err(e) => Err(Error::from_panic(e)),
+    };
+    match res {
+        Ok(v) => v,
+        Err(e) => raise(e),
+    }
+}
+
+/// Wrap several Rust functions as a single Ruby method, calling the first
+/// function whose arguments match.
+///
+/// Each function's arguments are converted in turn, and the first function
+/// with the same number of arguments as given, and where all arguments
+/// successfully convert, is called. An argument failing to convert with a
+/// `TypeError` moves on to the next function, any other error is raised. If no
+/// function matches a `TypeError` is raised listing the accepted arguments.
+///
+/// By default the functions ignore `self`, as with the
+/// [`function`](crate::function!) macro. Prefix the list with `method:` for
+/// functions that take `self` as their first argument, as with the
+/// [`method`](crate::method!) macro. Functions may also take
+/// [`&Ruby`](Ruby) as their first argument.
+///
+/// The method will have an arity of `-1` in Ruby. Functions may take up to
+/// `32` arguments (not including `self` or `&Ruby`).
+///
+/// As candidates are tried in order, more specific types should be listed
+/// first, e.g. a function taking [`RString`](crate::RString) before one taking
+/// [`Value`].
+///
+/// # Examples
+///
+/// ```
+/// use magnus::{overload, prelude::*, rb_assert, Error, RString, Ruby, Symbol};
+///
+/// fn from_string(s: RString) -> String {
+///     format!("string: {}", s)
+/// }
+///
+/// fn from_symbol(s: Symbol) -> Result<String, Error> {
+///     Ok(format!("symbol: {}", s.name()?))
+/// }
+///
+/// fn from_pair(a: i64, b: i64) -> String {
+///     format!("pair: {}", a + b)
+/// }
+///
+/// fn example(ruby: &Ruby) -> Result<(), Error> {
+///     ruby.define_global_function("describe", overload!(from_string, from_symbol, from_pair));
+///
+///     rb_assert!(ruby, r#"describe("a") == "string: a""#);
+///     rb_assert!(ruby, r#"describe(:b) == "symbol: b""#);
+///     rb_assert!(ruby, r#"describe(1, 2) == "pair: 3""#);
+///     rb_assert!(
+///         ruby,
+///         r#"(describe(1.5) rescue $!).message == "wrong argument types (Float), expected one of: (RString), (Symbol), (i64, i64)""#
+///     );
+///
+///     Ok(())
+/// }
+/// # Ruby::init(example).unwrap()
+/// ```
+#[macro_export]
+macro_rules! overload {
+    (method: $($name:expr),+ $(,)?) => {{
         unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
             rb_self: $crate::Value,
-            a: $crate::Value,
-            b: $crate::Value,
-            c: $crate::Value,
-            d: $crate::Value,
-            e: $crate::Value,
-            f: $crate::Value,
-            g: $crate::Value,
-            h: $crate::Value,
-            i: $crate::Value,
-            j: $crate::Value,
-            k: $crate::Value,
-            l: $crate::Value,
-            m: $crate::Value,
-            n: $crate::Value,
-            o: $crate::Value,
-            p: $crate::Value,
         ) -> $crate::Value {
-            use $crate::method::{Function16, RubyFunction16};
-            $name.call_handle_error(a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p)
+            use $crate::method::MethodOverload;
+            let args = std::slice::from_raw_parts(argv, argc as usize);
+            $crate::method::overload_handle_error(|| {
+                $(
+                    if let Some(res) = $name.try_call(rb_self, args)? {
+                        return Ok(res);
+                    }
+                )+
+                Err($crate::method::overload_error(args, &[$($name.signature()),+]))
+            })
         }
         anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
             $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
-            $crate::Value,
+        ) -> $crate::Value
+    }};
+    ($($name:expr),+ $(,)?) => {{
+        unsafe extern "C" fn anon(
+            argc: std::os::raw::c_int,
+            argv: *const $crate::Value,
+            rb_self: $crate::Value,
+        ) -> $crate::Value {
+            use $crate::method::FunctionOverload;
+            let args = std::slice::from_raw_parts(argv, argc as usize);
+            $crate::method::overload_handle_error(|| {
+                $(
+                    if let Some(res) = $name.try_call(args)? {
+                        return Ok(res);
+                    }
+                )+
+                Err($crate::method::overload_error(args, &[$($name.signature()),+]))
+            })
+        }
+        anon as unsafe extern "C" fn(
+            std::os::raw::c_int,
+            *const $crate::Value,
             $crate::Value,
         ) -> $crate::Value
     }};
-    ($name:expr, $arity:expr) => {
-        compile_error!("arity must be an integer literal between -2..=16")
-    };
 }
+
+/// Trait for closures that can be defined as Ruby methods with
+/// [`Module::define_method_fn`](crate::Module::define_method_fn).
+///
+/// This trait is implemented for `'static + Send` closures taking `self`
+/// followed by up to `15` arguments, optionally preceded by
+/// [`&Ruby`](Ruby), where each argument implements [`TryConvert`] and the
+/// return value implements [`ReturnValue`]. The `Marker` type parameter
+/// distinguishes the closure's signature, and can be left to type inference.
+pub trait MethodFn<Marker>: 'static + Send {
+    #[doc(hidden)]
+    fn call_convert_value(&self, rb_self: Value, args: &[Value]) -> Result<Value, Error>;
+}
+
+macro_rules! method_fn_n {
+    ($n:literal) => {
+        seq!(N in 0..$n {
+            impl<Func, RbSelf, #(T~N,)* Res> MethodFn<(RbSelf, (#(T~N,)*), Res)> for Func
+            where
+                Func: 'static + Send + Fn(RbSelf, #(T~N,)*) -> Res,
+                RbSelf: TryConvert,
+                #(T~N: TryConvert,)*
+                Res: ReturnValue,
+            {
+                fn call_convert_value(
+                    &self,
+                    rb_self: Value,
+                    args: &[Value],
+                ) -> Result<Value, Error> {
+                    Ruby::get_with(rb_self).check_arity(args.len(), $n..=$n)?;
+                    (self)(
+                        TryConvert::try_convert(rb_self)?,
+                        #(TryConvert::try_convert(args[N])?,)*
+                    )
+                    .into_return_value()
+                }
+            }
+
+            impl<Func, RbSelf, #(T~N,)* Res> MethodFn<WithRuby<(RbSelf, (#(T~N,)*), Res)>>
+                for Func
+            where
+                Func: 'static + Send + Fn(&Ruby, RbSelf, #(T~N,)*) -> Res,
+                RbSelf: TryConvert,
+                #(T~N: TryConvert,)*
+                Res: ReturnValue,
+            {
+                fn call_convert_value(
+                    &self,
+                    rb_self: Value,
+                    args: &[Value],
+                ) -> Result<Value, Error> {
+                    let handle = Ruby::get_with(rb_self);
+                    handle.check_arity(args.len(), $n..=$n)?;
+                    (self)(
+                        &handle,
+                        TryConvert::try_convert(rb_self)?,
+                        #(TryConvert::try_convert(args[N])?,)*
+                    )
+                    .into_return_value()
+                }
+            }
+        });
+    }
+}
+
+seq!(N in 0..=15 {
+    method_fn_n!(N);
+});
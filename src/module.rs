@@ -5,26 +5,30 @@
 use std::{ffi::CString, fmt, mem::transmute, os::raw::c_int};
 
 use rb_sys::{
-    rb_alias, rb_attr, rb_class_inherited_p, rb_const_get, rb_const_set, rb_define_class_id_under,
-    rb_define_method_id, rb_define_module_function, rb_define_module_id_under,
-    rb_define_private_method, rb_define_protected_method, rb_include_module, rb_mComparable,
-    rb_mEnumerable, rb_mErrno, rb_mFileTest, rb_mGC, rb_mKernel, rb_mMath, rb_mProcess,
-    rb_mWaitReadable, rb_mWaitWritable, rb_mod_ancestors, rb_module_new, rb_prepend_module,
-    ruby_value_type, VALUE,
+    rb_alias, rb_attr, rb_autoload, rb_autoload_p, rb_class_inherited_p, rb_const_defined,
+    rb_const_get, rb_const_remove, rb_const_set, rb_cvar_defined, rb_cvar_get, rb_cvar_set,
+    rb_define_class_id_under, rb_define_method_id, rb_define_module_function,
+    rb_define_module_id_under, rb_define_private_method, rb_define_protected_method,
+    rb_include_module, rb_mComparable, rb_mEnumerable, rb_mErrno, rb_mFileTest, rb_mGC, rb_mKernel,
+    rb_mMath, rb_mProcess, rb_mWaitReadable, rb_mWaitWritable, rb_mod_ancestors, rb_module_new,
+    rb_prepend_module, ruby_value_type, VALUE,
 };
 
 use crate::{
+    block::Proc,
     class::{Class, RClass},
     error::{protect, Error},
     exception::ExceptionClass,
     into_value::IntoValue,
-    method::Method,
+    method::{Method, MethodFn},
     object::Object,
     r_array::RArray,
+    r_method::RUnboundMethod,
+    symbol::Symbol,
     try_convert::TryConvert,
     value::{
         private::{self, ReprValue as _},
-        IntoId, NonZeroValue, ReprValue, Value,
+        IntoId, Lazy, NonZeroValue, Opaque, OpaqueId, ReprValue, StaticSymbol, Value,
     },
     Ruby,
 };
@@ -163,6 +167,66 @@ impl RModule {
         })?;
         Ok(())
     }
+
+    /// Create a refinement of `class` in `self`, returning the refinement
+    /// module.
+    ///
+    /// Methods defined on the returned module (e.g. with
+    /// [`define_method`](Module::define_method)) override or add to the
+    /// methods of `class`, but only where the refinement has been activated.
+    /// Calling `refine` multiple times with the same `class` returns the same
+    /// refinement module.
+    ///
+    /// Refinements are activated from Ruby with `using`, e.g. `using
+    /// MyRefinements`. Activation is lexically scoped: with `using` at the top
+    /// level of a file the refinement applies from that point to the end of
+    /// the file, with `using` in a class or module body it applies until the
+    /// end of that body, and with `using` in an `eval` string it applies to
+    /// the end of that string. Code elsewhere, including code in other files
+    /// called from the refined scope, sees the original methods.
+    ///
+    /// There is no `Ruby::using`. Ruby's `using` activates a refinement for
+    /// the lexical scope of its caller, and Rust code has no lexical scope in
+    /// the Ruby sense, so there is nothing for it to apply to (Ruby itself
+    /// raises an error if `using` is called from within a method). Methods
+    /// called from Rust always see the unrefined class. To use a refinement
+    /// from Rust, evaluate the Ruby code that needs it along with `using`, as
+    /// in the example below.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{method, prelude::*, rb_assert, Error, RString, Ruby};
+    ///
+    /// fn shout(s: RString) -> Result<String, Error> {
+    ///     Ok(s.to_string()?.to_uppercase())
+    /// }
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let module = ruby.define_module("Shouting")?;
+    ///     let refinement = module.refine(ruby.class_string())?;
+    ///     refinement.define_method("shout", method!(shout, 0))?;
+    ///
+    ///     rb_assert!(ruby, r#"!"hello".respond_to?(:shout)"#);
+    ///     let res: String = ruby.eval(r#"using Shouting; "hello".shout"#)?;
+    ///     assert_eq!(res, "HELLO");
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    pub fn refine<T>(self, class: T) -> Result<RModule, Error>
+    where
+        T: Module,
+    {
+        // `Module#refine` only accepts a block written in Ruby
+        static REFINE: Lazy<Proc> = Lazy::new(|ruby| {
+            ruby.eval("proc { |m, k| m.module_eval { refine(k) { self } } }")
+                .unwrap()
+        });
+        let handle = Ruby::get_with(self);
+        handle.get_inner(&REFINE).call((self, class.as_value()))
+    }
 }
 
 impl fmt::Display for RModule {
@@ -421,6 +485,484 @@ pub trait Module: Object + ReprValue + Copy {
         res.and_then(TryConvert::try_convert)
     }
 
+    /// Returns whether the constant `name` is defined within `self`'s scope,
+    /// including constants inherited from ancestors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, Error, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let class = ruby.define_class("Example", ruby.class_object())?;
+    ///     class.const_set("VALUE", 42)?;
+    ///
+    ///     assert!(class.const_defined("VALUE"));
+    ///     assert!(class.const_defined("String"));
+    ///     assert!(!class.const_defined("MISSING"));
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    fn const_defined<T>(self, name: T) -> bool
+    where
+        T: IntoId,
+    {
+        debug_assert_value!(self);
+        let id = name.into_id_with(&Ruby::get_with(self));
+        unsafe { rb_const_defined(self.as_rb_value(), id.as_rb_id()) != 0 }
+    }
+
+    /// Remove the constant `name` from `self`, returning its value.
+    ///
+    /// Returns `Err` if `self` does not define the constant `name`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, Error, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let class = ruby.define_class("Example", ruby.class_object())?;
+    ///     class.const_set("VALUE", 42)?;
+    ///
+    ///     let value: i64 = class.remove_const("VALUE")?;
+    ///     assert_eq!(value, 42);
+    ///     assert!(!class.const_defined("VALUE"));
+    ///     assert!(class.remove_const::<_, i64>("VALUE").is_err());
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    fn remove_const<T, U>(self, name: T) -> Result<U, Error>
+    where
+        T: IntoId,
+        U: TryConvert,
+    {
+        debug_assert_value!(self);
+        let id = name.into_id_with(&Ruby::get_with(self));
+        let res =
+            unsafe { protect(|| Value::new(rb_const_remove(self.as_rb_value(), id.as_rb_id()))) };
+        res.and_then(TryConvert::try_convert)
+    }
+
+    /// Returns the names of the constants accessible in `self`.
+    ///
+    /// If `inherit` is `true` this includes constants from `self`'s ancestors,
+    /// other than `Object`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, Error, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let module = ruby.define_module("Example")?;
+    ///     module.const_set("A", 1)?;
+    ///     module.const_set("B", 2)?;
+    ///
+    ///     let names = module
+    ///         .constants(false)?
+    ///         .into_iter()
+    ///         .map(|sym| sym.name().map(String::from))
+    ///         .collect::<Result<Vec<_>, _>>()?;
+    ///     assert_eq!(names, ["A", "B"]);
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    fn constants(self, inherit: bool) -> Result<Vec<StaticSymbol>, Error> {
+        self.funcall("constants", (inherit,))
+    }
+
+    /// Returns the file name and line number where the constant `name` was
+    /// defined.
+    ///
+    /// Returns `None` if the constant is not defined, or was not defined in
+    /// Ruby code, e.g. it was set with [`const_set`](Module::const_set).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, Error, RModule, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let module: RModule = ruby.eval("module Example\n  VALUE = 1\nend\nExample")?;
+    ///     module.const_set("OTHER", 2)?;
+    ///
+    ///     let (_file, line) = module.const_source_location("VALUE")?.unwrap();
+    ///     assert_eq!(line, 2);
+    ///     assert_eq!(module.const_source_location("OTHER")?, None);
+    ///     assert_eq!(module.const_source_location("MISSING")?, None);
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    #[cfg(any(ruby_gte_2_7, docsrs))]
+    #[cfg_attr(docsrs, doc(cfg(ruby_gte_2_7)))]
+    fn const_source_location<T>(self, name: T) -> Result<Option<(String, usize)>, Error>
+    where
+        T: IntoId,
+    {
+        let handle = Ruby::get_with(self);
+        let name = handle.into_value(name.into_id_with(&handle));
+        let location: Option<RArray> = self.funcall("const_source_location", (name,))?;
+        match location {
+            Some(location) if !location.is_empty() => {
+                TryConvert::try_convert(location.as_value()).map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Make the constant `name` private, so it can only be accessed without
+    /// an explicit receiver from within `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, rb_assert, Error, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let module = ruby.define_module("Example")?;
+    ///     module.const_set("SECRET", 42)?;
+    ///     module.private_constant("SECRET")?;
+    ///
+    ///     rb_assert!(ruby, "(Example::SECRET rescue $!).is_a?(NameError)");
+    ///     rb_assert!(ruby, "module Example; SECRET == 42; end");
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    fn private_constant<T>(self, name: T) -> Result<(), Error>
+    where
+        T: IntoId,
+    {
+        let handle = Ruby::get_with(self);
+        let name = handle.into_value(name.into_id_with(&handle));
+        let _: Value = self.funcall("private_constant", (name,))?;
+        Ok(())
+    }
+
+    /// Mark the constant `name` as deprecated, so that a warning is issued
+    /// when it is accessed (if deprecation warnings are enabled).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, Error, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let module = ruby.define_module("Example")?;
+    ///     module.const_set("OLD", 42)?;
+    ///     module.deprecate_constant("OLD")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    fn deprecate_constant<T>(self, name: T) -> Result<(), Error>
+    where
+        T: IntoId,
+    {
+        let handle = Ruby::get_with(self);
+        let name = handle.into_value(name.into_id_with(&handle));
+        let _: Value = self.funcall("deprecate_constant", (name,))?;
+        Ok(())
+    }
+
+    /// Define the constant `name` within `self`'s scope, with a value
+    /// computed by `func` on first access.
+    ///
+    /// This avoids the cost of building large or rarely used constants when
+    /// the extension is loaded. The value is computed when `self::NAME`, or
+    /// `NAME` within the body of `self`, is first evaluated, and is then set
+    /// as a regular constant.
+    ///
+    /// Until it has been accessed the constant is not defined, so it will not
+    /// be listed by [`constants`](Module::constants) or reported by
+    /// [`const_defined`](Module::const_defined). Access from a nested
+    /// module's body does not trigger the computation.
+    ///
+    /// This is implemented with `self`'s `const_missing` hook, calling the
+    /// existing hook for any other missing constant. If `func` returns `Err`
+    /// the error is raised from the constant access and the constant remains
+    /// undefined, so the next access will call `func` again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, rb_assert, Error, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let module = ruby.define_module("Tables")?;
+    ///     module.define_lazy_const("SQUARES", |ruby: &Ruby| {
+    ///         Ok(ruby.ary_from_iter((0..10).map(|i| i * i)))
+    ///     })?;
+    ///
+    ///     assert!(!module.const_defined("SQUARES"));
+    ///     rb_assert!(ruby, "Tables::SQUARES[3] == 9");
+    ///     assert!(module.const_defined("SQUARES"));
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    fn define_lazy_const<T, F, U>(self, name: T, func: F) -> Result<(), Error>
+    where
+        T: IntoId,
+        F: 'static + Send + FnMut(&Ruby) -> Result<U, Error>,
+        U: IntoValue,
+    {
+        let handle = Ruby::get_with(self);
+        let name = OpaqueId::from(name.into_id_with(&handle));
+        let singleton = self.singleton_class()?;
+        let previous = singleton.instance_method("const_missing")?;
+        let opaque_previous = Opaque::from(previous);
+        let module = Opaque::from(self);
+        let mut func = Some(func);
+        let proc = handle.proc_from_fn(move |args, _block| {
+            let ruby = unsafe { Ruby::get_unchecked() };
+            ruby.check_arity(args.len(), 1..=1)?;
+            let missing = Symbol::try_convert(args[0])?;
+            if name == missing {
+                if let Some(f) = func.as_mut() {
+                    let value = ruby.into_value(f(&ruby)?);
+                    ruby.get_inner(module).const_set(name, value)?;
+                    func = None;
+                    return Ok(value);
+                }
+            }
+            let rb_self: Value = ruby.current_receiver()?;
+            ruby.get_inner(opaque_previous)
+                .bind(rb_self)?
+                .call((missing,))
+        });
+        // keep `previous` alive for as long as the proc that calls it, ivar
+        // without @ prefix is invisible from Ruby
+        proc.ivar_set("__previous_hook", previous)?;
+        let _: Value = singleton.funcall("define_method", ("const_missing", proc))?;
+        Ok(())
+    }
+
+    /// Register `path` to be loaded with `require` when the constant `name`
+    /// is first accessed within `self`'s scope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, Error, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let module = ruby.define_module("Example")?;
+    ///     module.autoload("Parser", "example/parser")?;
+    ///
+    ///     assert_eq!(
+    ///         module.autoload_path("Parser")?.as_deref(),
+    ///         Some("example/parser")
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    fn autoload<T>(self, name: T, path: &str) -> Result<(), Error>
+    where
+        T: IntoId,
+    {
+        debug_assert_value!(self);
+        let handle = Ruby::get_with(self);
+        let id = name.into_id_with(&handle);
+        let path = CString::new(path).unwrap();
+        protect(|| {
+            unsafe { rb_autoload(self.as_rb_value(), id.as_rb_id(), path.as_ptr()) };
+            handle.qnil()
+        })?;
+        Ok(())
+    }
+
+    /// Returns the path registered with [`autoload`](Module::autoload) for
+    /// the constant `name`, or `None` if there is no autoload registered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, Error, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let module = ruby.define_module("Example")?;
+    ///     assert!(module.autoload_path("Parser")?.is_none());
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    fn autoload_path<T>(self, name: T) -> Result<Option<String>, Error>
+    where
+        T: IntoId,
+    {
+        debug_assert_value!(self);
+        let id = name.into_id_with(&Ruby::get_with(self));
+        let res =
+            unsafe { protect(|| Value::new(rb_autoload_p(self.as_rb_value(), id.as_rb_id()))) };
+        res.and_then(TryConvert::try_convert)
+    }
+
+    /// Get the value of the class variable `name` of `self`.
+    ///
+    /// `name` must include the `@@` prefix. Returns `Err` if the class
+    /// variable is not defined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, Error, RClass, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let class: RClass = ruby.eval("class Example; @@count = 3; end; Example")?;
+    ///
+    ///     let count: i64 = class.cvar_get("@@count")?;
+    ///     assert_eq!(count, 3);
+    ///     assert!(class.cvar_get::<_, i64>("@@missing").is_err());
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    fn cvar_get<T, U>(self, name: T) -> Result<U, Error>
+    where
+        T: IntoId,
+        U: TryConvert,
+    {
+        debug_assert_value!(self);
+        let id = name.into_id_with(&Ruby::get_with(self));
+        let res = unsafe { protect(|| Value::new(rb_cvar_get(self.as_rb_value(), id.as_rb_id()))) };
+        res.and_then(TryConvert::try_convert)
+    }
+
+    /// Set the value of the class variable `name` of `self`.
+    ///
+    /// `name` must include the `@@` prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, rb_assert, Error, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let class = ruby.define_class("Example", ruby.class_object())?;
+    ///     class.cvar_set("@@count", 3)?;
+    ///
+    ///     rb_assert!(ruby, "Example.class_variable_get(:@@count) == 3");
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    fn cvar_set<T, U>(self, name: T, value: U) -> Result<(), Error>
+    where
+        T: IntoId,
+        U: IntoValue,
+    {
+        debug_assert_value!(self);
+        let handle = Ruby::get_with(self);
+        let id = name.into_id_with(&handle);
+        let value = handle.into_value(value);
+        protect(|| {
+            unsafe { rb_cvar_set(self.as_rb_value(), id.as_rb_id(), value.as_rb_value()) };
+            handle.qnil()
+        })?;
+        Ok(())
+    }
+
+    /// Returns whether the class variable `name` is defined for `self`.
+    ///
+    /// `name` must include the `@@` prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, Error, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let class = ruby.define_class("Example", ruby.class_object())?;
+    ///     class.cvar_set("@@count", 3)?;
+    ///
+    ///     assert!(class.cvar_defined("@@count"));
+    ///     assert!(!class.cvar_defined("@@missing"));
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    fn cvar_defined<T>(self, name: T) -> bool
+    where
+        T: IntoId,
+    {
+        debug_assert_value!(self);
+        let id = name.into_id_with(&Ruby::get_with(self));
+        unsafe { Value::new(rb_cvar_defined(self.as_rb_value(), id.as_rb_id())).to_bool() }
+    }
+
+    /// Returns the names of the class variables of `self`, including those
+    /// inherited from ancestors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, Error, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let class = ruby.define_class("Example", ruby.class_object())?;
+    ///     class.cvar_set("@@count", 3)?;
+    ///
+    ///     let names = class.class_variables()?;
+    ///     assert_eq!(names.len(), 1);
+    ///     assert_eq!(names[0].name()?, "@@count");
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    fn class_variables(self) -> Result<Vec<StaticSymbol>, Error> {
+        self.funcall("class_variables", ())
+    }
+
+    /// Returns the instance method `name` of `self` as a
+    /// [`RUnboundMethod`].
+    ///
+    /// Returns `Err` if `self` does not define or inherit a method `name`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, Error, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let method = ruby.class_string().instance_method("upcase")?;
+    ///     let res: String = method.bind(ruby.str_new("example"))?.call(())?;
+    ///     assert_eq!(res, "EXAMPLE");
+    ///
+    ///     assert!(ruby.class_string().instance_method("non_existant").is_err());
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    fn instance_method<T>(self, name: T) -> Result<RUnboundMethod, Error>
+    where
+        T: IntoId,
+    {
+        let handle = Ruby::get_with(self);
+        let name = handle.into_value(name.into_id_with(&handle));
+        self.funcall("instance_method", (name,))
+    }
+
     /// Returns whether or not `self` inherits from `other`.
     ///
     /// Classes including a module are considered to inherit from that module.
@@ -510,6 +1052,163 @@ pub trait Module: Object + ReprValue + Copy {
         Ok(())
     }
 
+    /// Define a method in `self`'s scope from a closure.
+    ///
+    /// Unlike [`define_method`](Module::define_method), which requires a
+    /// function pointer from the [`method`](crate::method!) or
+    /// [`function`](crate::function!) macros, `func` may capture state. The
+    /// closure is kept alive by the method, and any Ruby values it captures
+    /// are marked by the garbage collector.
+    ///
+    /// `func` takes `self` followed by up to 15 arguments, optionally preceded
+    /// by [`&Ruby`](Ruby), with type conversions and error handling as with
+    /// the [`method`](crate::method!) macro. See [`MethodFn`].
+    ///
+    /// The method is defined with Ruby's `Module#define_method`, so will
+    /// report an arity of `-1`, but raise an `ArgumentError` when called with
+    /// the wrong number of arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, rb_assert, Error, RString, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let prefix = String::from("> ");
+    ///     ruby.class_string().define_method_fn("quote", move |s: RString| {
+    ///         Ok::<_, Error>(format!("{}{}", prefix, s.to_string()?))
+    ///     })?;
+    ///
+    ///     rb_assert!(ruby, r#""hello".quote == "> hello""#);
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    fn define_method_fn<T, F, M>(self, name: T, func: F) -> Result<(), Error>
+    where
+        T: IntoId,
+        F: MethodFn<M>,
+    {
+        debug_assert_value!(self);
+        let handle = Ruby::get_with(self);
+        let proc = handle.proc_from_fn(move |args, _block| {
+            let rb_self = unsafe { Ruby::get_unchecked() }.current_receiver()?;
+            func.call_convert_value(rb_self, args)
+        });
+        let _: Value = self.funcall("define_method", (name.into_id_with(&handle), proc))?;
+        Ok(())
+    }
+
+    /// Register `func` to be called when `self` is included in a class or
+    /// module.
+    ///
+    /// `func` is called with the class or module `self` was included in,
+    /// converted to `T`. The existing `included` hook of `self` is called
+    /// first, so multiple hooks can be registered, and hooks defined in Ruby
+    /// continue to work.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, rb_assert, Error, RClass, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let module = ruby.define_module("Tracked")?;
+    ///     module.on_included(|_ruby: &Ruby, target: RClass| {
+    ///         target.ivar_set("@tracked", true)
+    ///     })?;
+    ///
+    ///     rb_assert!(ruby, "Class.new { include Tracked }.instance_variable_get(:@tracked)");
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    fn on_included<T, F>(self, func: F) -> Result<(), Error>
+    where
+        T: TryConvert,
+        F: 'static + Send + Fn(&Ruby, T) -> Result<(), Error>,
+    {
+        define_hook(self, "included", move |ruby, val| {
+            func(ruby, TryConvert::try_convert(val)?)
+        })
+    }
+
+    /// Register `func` to be called when an object is extended with `self`.
+    ///
+    /// `func` is called with the object that was extended, converted to `T`.
+    /// The existing `extended` hook of `self` is called first, so multiple
+    /// hooks can be registered, and hooks defined in Ruby continue to work.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, rb_assert, Error, Ruby, Value};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let module = ruby.define_module("Flagged")?;
+    ///     module.on_extended(|ruby: &Ruby, obj: Value| {
+    ///         let _: Value = obj.funcall("instance_variable_set", ("@flagged", ruby.qtrue()))?;
+    ///         Ok(())
+    ///     })?;
+    ///
+    ///     rb_assert!(ruby, "Object.new.extend(Flagged).instance_variable_get(:@flagged)");
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    fn on_extended<T, F>(self, func: F) -> Result<(), Error>
+    where
+        T: TryConvert,
+        F: 'static + Send + Fn(&Ruby, T) -> Result<(), Error>,
+    {
+        define_hook(self, "extended", move |ruby, val| {
+            func(ruby, TryConvert::try_convert(val)?)
+        })
+    }
+
+    /// Register `func` to be called when an instance method is added to
+    /// `self`.
+    ///
+    /// `func` is called with the name of the new method. The existing
+    /// `method_added` hook of `self` is called first, so multiple hooks can
+    /// be registered, and hooks defined in Ruby continue to work.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// use magnus::{prelude::*, Error, Ruby, Symbol, Value};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let added = Arc::new(Mutex::new(Vec::new()));
+    ///     let names = added.clone();
+    ///
+    ///     let class = ruby.define_class("Example", ruby.class_object())?;
+    ///     class.on_method_added(move |_ruby: &Ruby, name: Symbol| {
+    ///         names.lock().unwrap().push(name.name()?.into_owned());
+    ///         Ok(())
+    ///     })?;
+    ///     let _: Value = ruby.eval("class Example; def foo; end; def bar; end; end")?;
+    ///
+    ///     assert_eq!(*added.lock().unwrap(), ["foo", "bar"]);
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    fn on_method_added<F>(self, func: F) -> Result<(), Error>
+    where
+        F: 'static + Send + Fn(&Ruby, Symbol) -> Result<(), Error>,
+    {
+        define_hook(self, "method_added", move |ruby, val| {
+            func(ruby, TryConvert::try_convert(val)?)
+        })
+    }
+
     /// Define a private method in `self`'s scope.
     ///
     /// # Examples
@@ -1057,3 +1756,32 @@ pub fn wait_readable() -> RModule {
 pub fn wait_writable() -> RModule {
     get_ruby!().module_wait_writable()
 }
+
+/// Redefine the hook method `name` on `module`'s singleton class to call the
+/// previous implementation followed by `func`.
+pub(crate) fn define_hook<T, F>(module: T, name: &str, func: F) -> Result<(), Error>
+where
+    T: Module,
+    F: 'static + Send + Fn(&Ruby, Value) -> Result<(), Error>,
+{
+    let handle = Ruby::get_with(module);
+    let singleton = module.singleton_class()?;
+    let previous = singleton.instance_method(name)?;
+    let opaque_previous = Opaque::from(previous);
+    let proc = handle.proc_from_fn(move |args, _block| {
+        let ruby = unsafe { Ruby::get_unchecked() };
+        ruby.check_arity(args.len(), 1..=1)?;
+        let rb_self: Value = ruby.current_receiver()?;
+        let _: Value = ruby
+            .get_inner(opaque_previous)
+            .bind(rb_self)?
+            .call((args[0],))?;
+        func(&ruby, args[0])
+    });
+    // keep `previous` alive for as long as the proc that calls it, ivar
+    // without @ prefix is invisible from Ruby
+    proc.ivar_set("__previous_hook", previous)?;
+    let _: Value = singleton.funcall("define_method", (name, proc))?;
+    let _: Value = singleton.funcall("private", (name,))?;
+    Ok(())
+}
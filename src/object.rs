@@ -1,17 +1,26 @@
-use std::{ffi::CString, mem::transmute};
+use std::{
+    ffi::CString,
+    mem::transmute,
+    os::raw::{c_char, c_int},
+    panic::AssertUnwindSafe,
+};
 
 use rb_sys::{
-    rb_define_singleton_method, rb_extend_object, rb_ivar_get, rb_ivar_set, rb_singleton_class,
+    rb_define_singleton_method, rb_extend_object, rb_id2name, rb_ivar_defined, rb_ivar_foreach,
+    rb_ivar_get, rb_ivar_set, rb_obj_instance_variables, rb_obj_remove_instance_variable,
+    rb_singleton_class, st_data_t, ID, VALUE,
 };
 
 use crate::{
     class::RClass,
-    error::{protect, Error},
+    error::{catch_unwind, protect, raise, Error},
     into_value::IntoValue,
     method::Method,
     module::RModule,
+    r_array::RArray,
+    r_hash::ForEach,
     try_convert::TryConvert,
-    value::{private::ReprValue as _, IntoId, ReprValue, Value},
+    value::{private::ReprValue as _, Id, IntoId, ReprValue, StaticSymbol, Value},
     Ruby,
 };
 
@@ -172,6 +181,198 @@ pub trait Object: ReprValue + Copy {
         Ok(())
     }
 
+    /// Returns the names of `self`'s instance variables.
+    ///
+    /// Only instance variables visible to Ruby (those with a name starting
+    /// with `@`) are included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, Error, RObject, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let obj: RObject = ruby.eval("Object.new")?;
+    ///     obj.ivar_set("@foo", 1)?;
+    ///     obj.ivar_set("@bar", 2)?;
+    ///
+    ///     let names = obj
+    ///         .instance_variables()?
+    ///         .into_iter()
+    ///         .map(|s| s.name().map(String::from))
+    ///         .collect::<Result<Vec<String>, Error>>()?;
+    ///     assert_eq!(names, ["@foo", "@bar"]);
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    fn instance_variables(self) -> Result<Vec<StaticSymbol>, Error> {
+        debug_assert_value!(self);
+        protect(|| unsafe {
+            RArray::from_rb_value_unchecked(rb_obj_instance_variables(self.as_rb_value()))
+        })?
+        .to_vec()
+    }
+
+    /// Check if the instance variable `name` is defined within `self`'s scope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, Error, RObject, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let obj: RObject = ruby.eval("Object.new")?;
+    ///     assert!(!obj.ivar_defined("@value"));
+    ///     obj.ivar_set("@value", 42)?;
+    ///     assert!(obj.ivar_defined("@value"));
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    fn ivar_defined<T>(self, name: T) -> bool
+    where
+        T: IntoId,
+    {
+        debug_assert_value!(self);
+        let id = name.into_id_with(&Ruby::get_with(self));
+        unsafe { Value::new(rb_ivar_defined(self.as_rb_value(), id.as_rb_id())).to_bool() }
+    }
+
+    /// Remove the instance variable `name` from `self`, returning its value.
+    ///
+    /// Errors with `NameError` if the instance variable is not defined, or
+    /// `FrozenError` if `self` is frozen.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, Error, RObject, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let obj: RObject = ruby.eval("Object.new")?;
+    ///     obj.ivar_set("@value", 42)?;
+    ///
+    ///     assert_eq!(obj.remove_ivar::<_, i64>("@value")?, 42);
+    ///     assert!(!obj.ivar_defined("@value"));
+    ///     assert!(obj.remove_ivar::<_, i64>("@value").is_err());
+    ///
+    ///     obj.ivar_set("@value", 42)?;
+    ///     obj.freeze();
+    ///     let err = obj.remove_ivar::<_, i64>("@value").unwrap_err();
+    ///     assert!(err.is_kind_of(ruby.exception_frozen_error()));
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    fn remove_ivar<T, U>(self, name: T) -> Result<U, Error>
+    where
+        T: IntoId,
+        U: TryConvert,
+    {
+        debug_assert_value!(self);
+        let handle = Ruby::get_with(self);
+        let name = StaticSymbol::from(name.into_id_with(&handle));
+        protect(|| unsafe {
+            Value::new(rb_obj_remove_instance_variable(
+                self.as_rb_value(),
+                name.as_rb_value(),
+            ))
+        })
+        .and_then(TryConvert::try_convert)
+    }
+
+    /// Run `func` for each instance variable in `self`, without allocating an
+    /// intermediate array.
+    ///
+    /// The result of `func` is checked on each call, when it is
+    /// [`ForEach::Continue`] the iteration will continue, and
+    /// [`ForEach::Stop`] will cause the iteration to stop. Instance variables
+    /// can not be removed during iteration, returning [`ForEach::Delete`]
+    /// will stop iteration and return an `ArgumentError`.
+    ///
+    /// Returning an error from `func` behaves like [`ForEach::Stop`], and that
+    /// error is returned.
+    ///
+    /// As with [`instance_variables`](Object::instance_variables), only
+    /// instance variables with a name starting `@` are included. Variables set
+    /// without a preceding `@`, and those Ruby uses internally, are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, r_hash::ForEach, Error, RObject, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let obj: RObject = ruby.eval("Object.new")?;
+    ///     obj.ivar_set("@x", 1)?;
+    ///     obj.ivar_set("@y", 2)?;
+    ///
+    ///     let mut pairs = Vec::new();
+    ///     obj.ivar_foreach(|name, value: i64| {
+    ///         pairs.push((name.name()?, value));
+    ///         Ok(ForEach::Continue)
+    ///     })?;
+    ///     assert_eq!(pairs, [("@x", 1), ("@y", 2)]);
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    fn ivar_foreach<F, V>(self, mut func: F) -> Result<(), Error>
+    where
+        F: FnMut(Id, V) -> Result<ForEach, Error>,
+        V: TryConvert,
+    {
+        unsafe extern "C" fn iter<F, V>(key: ID, value: VALUE, arg: st_data_t) -> c_int
+        where
+            F: FnMut(Id, V) -> Result<ForEach, Error>,
+            V: TryConvert,
+        {
+            // skip hidden and internal ivars, as `instance_variables` does
+            let name = rb_id2name(key);
+            if name.is_null() || *name != b'@' as c_char || *name.add(1) == b'@' as c_char {
+                return ForEach::Continue as c_int;
+            }
+            let closure = &mut *(arg as *mut F);
+            let res = match catch_unwind(AssertUnwindSafe(|| {
+                match closure(
+                    Id::from_rb_id(key),
+                    TryConvert::try_convert(Value::new(value))?,
+                )? {
+                    ForEach::Delete => Err(Error::new(
+                        Ruby::get_unchecked().exception_arg_error(),
+                        "can't remove instance variables during iteration",
+                    )),
+                    v => Ok(v),
+                }
+            })) {
+                Ok(v) => v,
+                Err(e) => Err(Error::from_panic(e)),
+            };
+            match res {
+                Ok(v) => v as c_int,
+                Err(e) => raise(e),
+            }
+        }
+
+        debug_assert_value!(self);
+        unsafe {
+            let arg = &mut func as *mut F as st_data_t;
+            protect(|| {
+                let fptr = iter::<F, V> as unsafe extern "C" fn(ID, VALUE, st_data_t) -> c_int;
+                #[cfg(ruby_lt_2_7)]
+                let fptr: unsafe extern "C" fn() -> c_int = std::mem::transmute(fptr);
+                rb_ivar_foreach(self.as_rb_value(), Some(fptr), arg);
+                Ruby::get_with(self).qnil()
+            })?;
+        }
+        Ok(())
+    }
+
     /// Finds or creates the singleton class of `self`.
     ///
     /// Returns `Err` if `self` can not have a singleton class.
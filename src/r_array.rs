@@ -12,6 +12,7 @@ use rb_sys::{
     rb_ary_sort_bang, rb_ary_store, rb_ary_subseq, rb_ary_to_ary, rb_ary_unshift,
     rb_check_array_type, ruby_rarray_flags, ruby_value_type, VALUE,
 };
+use seq_macro::seq;
 
 use crate::{
     enumerator::Enumerator,
@@ -1302,416 +1303,31 @@ impl IntoValue for RArray {
     }
 }
 
-impl<T0> IntoValue for (T0,)
-where
-    T0: IntoValue,
-{
-    fn into_value_with(self, handle: &Ruby) -> Value {
-        let ary = [handle.into_value(self.0)];
-        handle.ary_new_from_values(&ary).into_value_with(handle)
-    }
-}
-
-unsafe impl<T0> IntoValueFromNative for (T0,) where T0: IntoValueFromNative {}
-
-impl<T0, T1> IntoValue for (T0, T1)
-where
-    T0: IntoValue,
-    T1: IntoValue,
-{
-    fn into_value_with(self, handle: &Ruby) -> Value {
-        let ary = [handle.into_value(self.0), handle.into_value(self.1)];
-        handle.ary_new_from_values(&ary).into_value_with(handle)
-    }
-}
-
-unsafe impl<T0, T1> IntoValueFromNative for (T0, T1)
-where
-    T0: IntoValueFromNative,
-    T1: IntoValueFromNative,
-{
-}
-
-impl<T0, T1, T2> IntoValue for (T0, T1, T2)
-where
-    T0: IntoValue,
-    T1: IntoValue,
-    T2: IntoValue,
-{
-    fn into_value_with(self, handle: &Ruby) -> Value {
-        let ary = [
-            handle.into_value(self.0),
-            handle.into_value(self.1),
-            handle.into_value(self.2),
-        ];
-        handle.ary_new_from_values(&ary).into_value_with(handle)
-    }
-}
-
-unsafe impl<T0, T1, T2> IntoValueFromNative for (T0, T1, T2)
-where
-    T0: IntoValueFromNative,
-    T1: IntoValueFromNative,
-    T2: IntoValueFromNative,
-{
-}
-
-impl<T0, T1, T2, T3> IntoValue for (T0, T1, T2, T3)
-where
-    T0: IntoValue,
-    T1: IntoValue,
-    T2: IntoValue,
-    T3: IntoValue,
-{
-    fn into_value_with(self, handle: &Ruby) -> Value {
-        let ary = [
-            handle.into_value(self.0),
-            handle.into_value(self.1),
-            handle.into_value(self.2),
-            handle.into_value(self.3),
-        ];
-        handle.ary_new_from_values(&ary).into_value_with(handle)
-    }
-}
-
-unsafe impl<T0, T1, T2, T3> IntoValueFromNative for (T0, T1, T2, T3)
-where
-    T0: IntoValueFromNative,
-    T1: IntoValueFromNative,
-    T2: IntoValueFromNative,
-    T3: IntoValueFromNative,
-{
-}
-
-impl<T0, T1, T2, T3, T4> IntoValue for (T0, T1, T2, T3, T4)
-where
-    T0: IntoValue,
-    T1: IntoValue,
-    T2: IntoValue,
-    T3: IntoValue,
-    T4: IntoValue,
-{
-    fn into_value_with(self, handle: &Ruby) -> Value {
-        let ary = [
-            handle.into_value(self.0),
-            handle.into_value(self.1),
-            handle.into_value(self.2),
-            handle.into_value(self.3),
-            handle.into_value(self.4),
-        ];
-        handle.ary_new_from_values(&ary).into_value_with(handle)
-    }
-}
-
-unsafe impl<T0, T1, T2, T3, T4> IntoValueFromNative for (T0, T1, T2, T3, T4)
-where
-    T0: IntoValueFromNative,
-    T1: IntoValueFromNative,
-    T2: IntoValueFromNative,
-    T3: IntoValueFromNative,
-    T4: IntoValueFromNative,
-{
-}
-
-impl<T0, T1, T2, T3, T4, T5> IntoValue for (T0, T1, T2, T3, T4, T5)
-where
-    T0: IntoValue,
-    T1: IntoValue,
-    T2: IntoValue,
-    T3: IntoValue,
-    T4: IntoValue,
-    T5: IntoValue,
-{
-    fn into_value_with(self, handle: &Ruby) -> Value {
-        let ary = [
-            handle.into_value(self.0),
-            handle.into_value(self.1),
-            handle.into_value(self.2),
-            handle.into_value(self.3),
-            handle.into_value(self.4),
-            handle.into_value(self.5),
-        ];
-        handle.ary_new_from_values(&ary).into_value_with(handle)
-    }
-}
-
-unsafe impl<T0, T1, T2, T3, T4, T5> IntoValueFromNative for (T0, T1, T2, T3, T4, T5)
-where
-    T0: IntoValueFromNative,
-    T1: IntoValueFromNative,
-    T2: IntoValueFromNative,
-    T3: IntoValueFromNative,
-    T4: IntoValueFromNative,
-    T5: IntoValueFromNative,
-{
-}
-
-impl<T0, T1, T2, T3, T4, T5, T6> IntoValue for (T0, T1, T2, T3, T4, T5, T6)
-where
-    T0: IntoValue,
-    T1: IntoValue,
-    T2: IntoValue,
-    T3: IntoValue,
-    T4: IntoValue,
-    T5: IntoValue,
-    T6: IntoValue,
-{
-    fn into_value_with(self, handle: &Ruby) -> Value {
-        let ary = [
-            handle.into_value(self.0),
-            handle.into_value(self.1),
-            handle.into_value(self.2),
-            handle.into_value(self.3),
-            handle.into_value(self.4),
-            handle.into_value(self.5),
-            handle.into_value(self.6),
-        ];
-        handle.ary_new_from_values(&ary).into_value_with(handle)
-    }
-}
-
-unsafe impl<T0, T1, T2, T3, T4, T5, T6> IntoValueFromNative for (T0, T1, T2, T3, T4, T5, T6)
-where
-    T0: IntoValueFromNative,
-    T1: IntoValueFromNative,
-    T2: IntoValueFromNative,
-    T3: IntoValueFromNative,
-    T4: IntoValueFromNative,
-    T5: IntoValueFromNative,
-    T6: IntoValueFromNative,
-{
-}
-
-impl<T0, T1, T2, T3, T4, T5, T6, T7> IntoValue for (T0, T1, T2, T3, T4, T5, T6, T7)
-where
-    T0: IntoValue,
-    T1: IntoValue,
-    T2: IntoValue,
-    T3: IntoValue,
-    T4: IntoValue,
-    T5: IntoValue,
-    T6: IntoValue,
-    T7: IntoValue,
-{
-    fn into_value_with(self, handle: &Ruby) -> Value {
-        let ary = [
-            handle.into_value(self.0),
-            handle.into_value(self.1),
-            handle.into_value(self.2),
-            handle.into_value(self.3),
-            handle.into_value(self.4),
-            handle.into_value(self.5),
-            handle.into_value(self.6),
-            handle.into_value(self.7),
-        ];
-        handle.ary_new_from_values(&ary).into_value_with(handle)
-    }
-}
-
-unsafe impl<T0, T1, T2, T3, T4, T5, T6, T7> IntoValueFromNative for (T0, T1, T2, T3, T4, T5, T6, T7)
-where
-    T0: IntoValueFromNative,
-    T1: IntoValueFromNative,
-    T2: IntoValueFromNative,
-    T3: IntoValueFromNative,
-    T4: IntoValueFromNative,
-    T5: IntoValueFromNative,
-    T6: IntoValueFromNative,
-    T7: IntoValueFromNative,
-{
-}
-
-impl<T0, T1, T2, T3, T4, T5, T6, T7, T8> IntoValue for (T0, T1, T2, T3, T4, T5, T6, T7, T8)
-where
-    T0: IntoValue,
-    T1: IntoValue,
-    T2: IntoValue,
-    T3: IntoValue,
-    T4: IntoValue,
-    T5: IntoValue,
-    T6: IntoValue,
-    T7: IntoValue,
-    T8: IntoValue,
-{
-    fn into_value_with(self, handle: &Ruby) -> Value {
-        let ary = [
-            handle.into_value(self.0),
-            handle.into_value(self.1),
-            handle.into_value(self.2),
-            handle.into_value(self.3),
-            handle.into_value(self.4),
-            handle.into_value(self.5),
-            handle.into_value(self.6),
-            handle.into_value(self.7),
-            handle.into_value(self.8),
-        ];
-        handle.ary_new_from_values(&ary).into_value_with(handle)
-    }
-}
-
-unsafe impl<T0, T1, T2, T3, T4, T5, T6, T7, T8> IntoValueFromNative
-    for (T0, T1, T2, T3, T4, T5, T6, T7, T8)
-where
-    T0: IntoValueFromNative,
-    T1: IntoValueFromNative,
-    T2: IntoValueFromNative,
-    T3: IntoValueFromNative,
-    T4: IntoValueFromNative,
-    T5: IntoValueFromNative,
-    T6: IntoValueFromNative,
-    T7: IntoValueFromNative,
-    T8: IntoValueFromNative,
-{
-}
-
-impl<T0, T1, T2, T3, T4, T5, T6, T7, T8, T9> IntoValue for (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9)
-where
-    T0: IntoValue,
-    T1: IntoValue,
-    T2: IntoValue,
-    T3: IntoValue,
-    T4: IntoValue,
-    T5: IntoValue,
-    T6: IntoValue,
-    T7: IntoValue,
-    T8: IntoValue,
-    T9: IntoValue,
-{
-    fn into_value_with(self, handle: &Ruby) -> Value {
-        let ary = [
-            handle.into_value(self.0),
-            handle.into_value(self.1),
-            handle.into_value(self.2),
-            handle.into_value(self.3),
-            handle.into_value(self.4),
-            handle.into_value(self.5),
-            handle.into_value(self.6),
-            handle.into_value(self.7),
-            handle.into_value(self.8),
-            handle.into_value(self.9),
-        ];
-        handle.ary_new_from_values(&ary).into_value_with(handle)
-    }
-}
-
-unsafe impl<T0, T1, T2, T3, T4, T5, T6, T7, T8, T9> IntoValueFromNative
-    for (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9)
-where
-    T0: IntoValueFromNative,
-    T1: IntoValueFromNative,
-    T2: IntoValueFromNative,
-    T3: IntoValueFromNative,
-    T4: IntoValueFromNative,
-    T5: IntoValueFromNative,
-    T6: IntoValueFromNative,
-    T7: IntoValueFromNative,
-    T8: IntoValueFromNative,
-    T9: IntoValueFromNative,
-{
-}
-
-impl<T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10> IntoValue
-    for (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10)
-where
-    T0: IntoValue,
-    T1: IntoValue,
-    T2: IntoValue,
-    T3: IntoValue,
-    T4: IntoValue,
-    T5: IntoValue,
-    T6: IntoValue,
-    T7: IntoValue,
-    T8: IntoValue,
-    T9: IntoValue,
-    T10: IntoValue,
-{
-    fn into_value_with(self, handle: &Ruby) -> Value {
-        let ary = [
-            handle.into_value(self.0),
-            handle.into_value(self.1),
-            handle.into_value(self.2),
-            handle.into_value(self.3),
-            handle.into_value(self.4),
-            handle.into_value(self.5),
-            handle.into_value(self.6),
-            handle.into_value(self.7),
-            handle.into_value(self.8),
-            handle.into_value(self.9),
-            handle.into_value(self.10),
-        ];
-        handle.ary_new_from_values(&ary).into_value_with(handle)
-    }
-}
-
-unsafe impl<T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10> IntoValueFromNative
-    for (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10)
-where
-    T0: IntoValueFromNative,
-    T1: IntoValueFromNative,
-    T2: IntoValueFromNative,
-    T3: IntoValueFromNative,
-    T4: IntoValueFromNative,
-    T5: IntoValueFromNative,
-    T6: IntoValueFromNative,
-    T7: IntoValueFromNative,
-    T8: IntoValueFromNative,
-    T9: IntoValueFromNative,
-    T10: IntoValueFromNative,
-{
-}
+macro_rules! impl_into_value {
+    ($n:literal) => {
+        seq!(N in 0..$n {
+            impl<#(T~N,)*> IntoValue for (#(T~N,)*)
+            where
+                #(T~N: IntoValue,)*
+            {
+                fn into_value_with(self, handle: &Ruby) -> Value {
+                    let ary = [#(handle.into_value(self.N),)*];
+                    handle.ary_new_from_values(&ary).into_value_with(handle)
+                }
+            }
 
-impl<T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11> IntoValue
-    for (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11)
-where
-    T0: IntoValue,
-    T1: IntoValue,
-    T2: IntoValue,
-    T3: IntoValue,
-    T4: IntoValue,
-    T5: IntoValue,
-    T6: IntoValue,
-    T7: IntoValue,
-    T8: IntoValue,
-    T9: IntoValue,
-    T10: IntoValue,
-    T11: IntoValue,
-{
-    fn into_value_with(self, handle: &Ruby) -> Value {
-        let ary = [
-            handle.into_value(self.0),
-            handle.into_value(self.1),
-            handle.into_value(self.2),
-            handle.into_value(self.3),
-            handle.into_value(self.4),
-            handle.into_value(self.5),
-            handle.into_value(self.6),
-            handle.into_value(self.7),
-            handle.into_value(self.8),
-            handle.into_value(self.9),
-            handle.into_value(self.10),
-            handle.into_value(self.11),
-        ];
-        handle.ary_new_from_values(&ary).into_value_with(handle)
+            unsafe impl<#(T~N,)*> IntoValueFromNative for (#(T~N,)*)
+            where
+                #(T~N: IntoValueFromNative,)*
+            {
+            }
+        });
     }
 }
 
-unsafe impl<T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11> IntoValueFromNative
-    for (T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11)
-where
-    T0: IntoValueFromNative,
-    T1: IntoValueFromNative,
-    T2: IntoValueFromNative,
-    T3: IntoValueFromNative,
-    T4: IntoValueFromNative,
-    T5: IntoValueFromNative,
-    T6: IntoValueFromNative,
-    T7: IntoValueFromNative,
-    T8: IntoValueFromNative,
-    T9: IntoValueFromNative,
-    T10: IntoValueFromNative,
-    T11: IntoValueFromNative,
-{
-}
+seq!(N in 1..=32 {
+    impl_into_value!(N);
+});
 
 impl<T> IntoValue for Vec<T>
 where
@@ -19,7 +19,7 @@ use rb_sys::{
 };
 
 use crate::{
-    error::{protect, raise, Error},
+    error::{catch_unwind, protect, raise, Error},
     into_value::{IntoValue, IntoValueFromNative},
     object::Object,
     try_convert::{TryConvert, TryConvertOwned},
@@ -59,9 +59,7 @@ where
 
     #[inline]
     unsafe fn call_handle_error(self, key: Value, value: Value) -> ForEach {
-        let res = match std::panic::catch_unwind(AssertUnwindSafe(|| {
-            self.call_convert_value(key, value)
-        })) {
+        let res = match catch_unwind(AssertUnwindSafe(|| self.call_convert_value(key, value))) {
             Ok(v) => v,
             Err(e) => Err(Error::from_panic(e)),
         };
@@ -703,11 +701,36 @@ where
     V: IntoValueFromNative,
 {
     fn into_value_with(self, handle: &Ruby) -> Value {
+        self.into_r_hash_with(handle).into_value_with(handle)
+    }
+}
+
+/// Conversions from Rust types into [`RHash`].
+///
+/// Used by [`KwSplat`](crate::KwSplat) to pass keyword arguments.
+pub trait IntoRHash: Sized {
+    /// Convert `self` into [`RHash`].
+    fn into_r_hash_with(self, handle: &Ruby) -> RHash;
+}
+
+impl IntoRHash for RHash {
+    #[inline]
+    fn into_r_hash_with(self, _: &Ruby) -> RHash {
+        self
+    }
+}
+
+impl<K, V> IntoRHash for HashMap<K, V>
+where
+    K: IntoValueFromNative,
+    V: IntoValueFromNative,
+{
+    fn into_r_hash_with(self, handle: &Ruby) -> RHash {
         let hash = handle.hash_new();
         for (k, v) in self {
             let _ = hash.aset(k, v);
         }
-        hash.into_value_with(handle)
+        hash
     }
 }
 
@@ -0,0 +1,488 @@
+use std::{fmt, os::raw::c_int};
+
+use rb_sys::VALUE;
+
+use crate::{
+    compat::rb_method_call_kw,
+    error::{protect, Error},
+    into_value::{ArgList, IntoValue},
+    object::Object,
+    r_array::RArray,
+    symbol::Symbol,
+    try_convert::TryConvert,
+    value::{
+        private::{self, ReprValue as _},
+        NonZeroValue, ReprValue, Value,
+    },
+    Ruby,
+};
+
+/// A Value known to be an instance of Method, a method bound to a receiver.
+///
+/// See the [`ReprValue`] and [`Object`] traits for additional methods
+/// available on this type. See [`ReprValue::method`] to get a method from an
+/// object.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct RMethod(NonZeroValue);
+
+impl RMethod {
+    /// Return `Some(RMethod)` if `val` is a `Method`, `None` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{eval, RMethod};
+    /// # let _cleanup = unsafe { magnus::embed::init() };
+    ///
+    /// assert!(RMethod::from_value(eval("1.method(:+)").unwrap()).is_some());
+    /// assert!(RMethod::from_value(eval("Integer.instance_method(:+)").unwrap()).is_none());
+    /// ```
+    #[inline]
+    pub fn from_value(val: Value) -> Option<Self> {
+        unsafe {
+            val.is_kind_of(Ruby::get_with(val).class_method())
+                .then(|| Self(NonZeroValue::new_unchecked(val)))
+        }
+    }
+
+    #[inline]
+    pub(crate) unsafe fn from_rb_value_unchecked(val: VALUE) -> Self {
+        Self(NonZeroValue::new_unchecked(Value::new(val)))
+    }
+
+    /// Call the method with `args`.
+    ///
+    /// Returns `Ok(T)` if the method returns without error and the return
+    /// value converts into a `T`, or returns `Err` if the method raises or the
+    /// conversion fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, Error, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let method = ruby.str_new("example").method("center")?;
+    ///     let res: String = method.call((11, "*"))?;
+    ///     assert_eq!(res, "**example**");
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    pub fn call<A, T>(self, args: A) -> Result<T, Error>
+    where
+        A: ArgList,
+        T: TryConvert,
+    {
+        let handle = Ruby::get_with(self);
+        let kw_splat = args.contains_kw_args() as c_int;
+        let args = args.into_arg_list_with(&handle);
+        let slice = args.as_ref();
+        unsafe {
+            protect(|| {
+                Value::new(rb_method_call_kw(
+                    slice.len() as c_int,
+                    slice.as_ptr() as *const VALUE,
+                    self.as_rb_value(),
+                    kw_splat,
+                ))
+            })
+            .and_then(TryConvert::try_convert)
+        }
+    }
+
+    /// Returns the object the method is bound to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, rb_assert, Error, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let s = ruby.str_new("example");
+    ///     let method = s.method("upcase")?;
+    ///     rb_assert!(ruby, "receiver.equal?(s)", receiver = method.receiver()?, s);
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    pub fn receiver(self) -> Result<Value, Error> {
+        self.funcall("receiver", ())
+    }
+
+    /// Returns the number of arguments the method takes.
+    ///
+    /// Returns the number of required arguments, or `-n-1` where `n` is the
+    /// number of required arguments if the method takes optional arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, Error, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     assert_eq!(ruby.integer_from_i64(1).method("+")?.arity()?, 1);
+    ///     assert_eq!(ruby.str_new("").method("center")?.arity()?, -2);
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    pub fn arity(self) -> Result<i64, Error> {
+        self.funcall("arity", ())
+    }
+
+    /// Returns the class or module that defines the method.
+    ///
+    /// See [`RClass::from_value`](crate::RClass::from_value) and
+    /// [`RModule::from_value`](crate::RModule::from_value) to check which.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, rb_assert, Error, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let method = ruby.str_new("").method("then")?;
+    ///     rb_assert!(ruby, "owner == Kernel", owner = method.owner()?);
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    pub fn owner(self) -> Result<Value, Error> {
+        self.funcall("owner", ())
+    }
+
+    /// Returns the name of the method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, Error, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let method = ruby.str_new("").method("upcase")?;
+    ///     assert_eq!(method.name()?.name()?, "upcase");
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    pub fn name(self) -> Result<Symbol, Error> {
+        self.funcall("name", ())
+    }
+
+    /// Returns a description of the method's parameters.
+    ///
+    /// The returned array contains an array for each parameter, of the
+    /// parameter's kind (e.g. `:req`, `:opt`, `:rest`, `:key`) and, if known,
+    /// its name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, rb_assert, Error, Ruby, Value};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let obj: Value = ruby.eval("o = Object.new; def o.test(a, b = 1, *c, d:); end; o")?;
+    ///     let params = obj.method("test")?.parameters()?;
+    ///     rb_assert!(ruby, "params == [[:req, :a], [:opt, :b], [:rest, :c], [:keyreq, :d]]", params);
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    pub fn parameters(self) -> Result<RArray, Error> {
+        self.funcall("parameters", ())
+    }
+
+    /// Returns the file name and line number where the method is defined.
+    ///
+    /// Returns `None` if the method is not defined in Ruby, e.g. it is a
+    /// method defined in C or Rust.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, Error, Ruby, Value};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let obj: Value = ruby.eval("o = Object.new; def o.test; end; o")?;
+    ///     let (_file, line) = obj.method("test")?.source_location()?.unwrap();
+    ///     assert_eq!(line, 1);
+    ///
+    ///     assert!(ruby.str_new("").method("upcase")?.source_location()?.is_none());
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    pub fn source_location(self) -> Result<Option<(String, usize)>, Error> {
+        self.funcall("source_location", ())
+    }
+
+    /// Dissociates the method from its receiver.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, Error, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let method = ruby.str_new("a").method("upcase")?.unbind()?;
+    ///     let res: String = method.bind(ruby.str_new("b"))?.call(())?;
+    ///     assert_eq!(res, "B");
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    pub fn unbind(self) -> Result<RUnboundMethod, Error> {
+        self.funcall("unbind", ())
+    }
+}
+
+impl fmt::Display for RMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", unsafe { self.to_s_infallible() })
+    }
+}
+
+impl fmt::Debug for RMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.inspect())
+    }
+}
+
+impl IntoValue for RMethod {
+    #[inline]
+    fn into_value_with(self, _: &Ruby) -> Value {
+        self.0.get()
+    }
+}
+
+impl Object for RMethod {}
+
+unsafe impl private::ReprValue for RMethod {}
+
+impl ReprValue for RMethod {}
+
+impl TryConvert for RMethod {
+    fn try_convert(val: Value) -> Result<Self, Error> {
+        Self::from_value(val).ok_or_else(|| {
+            Error::new(
+                Ruby::get_with(val).exception_type_error(),
+                format!("no implicit conversion of {} into Method", unsafe {
+                    val.classname()
+                },),
+            )
+        })
+    }
+}
+
+/// A Value known to be an instance of UnboundMethod, a method not bound to
+/// a receiver.
+///
+/// See the [`ReprValue`] and [`Object`] traits for additional methods
+/// available on this type. See
+/// [`Module::instance_method`](crate::Module::instance_method) to get an
+/// unbound method from a class or module.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct RUnboundMethod(NonZeroValue);
+
+impl RUnboundMethod {
+    /// Return `Some(RUnboundMethod)` if `val` is an `UnboundMethod`, `None`
+    /// otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{eval, RUnboundMethod};
+    /// # let _cleanup = unsafe { magnus::embed::init() };
+    ///
+    /// assert!(RUnboundMethod::from_value(eval("Integer.instance_method(:+)").unwrap()).is_some());
+    /// assert!(RUnboundMethod::from_value(eval("1.method(:+)").unwrap()).is_none());
+    /// ```
+    #[inline]
+    pub fn from_value(val: Value) -> Option<Self> {
+        unsafe {
+            val.is_kind_of(Ruby::get_with(val).class_unbound_method())
+                .then(|| Self(NonZeroValue::new_unchecked(val)))
+        }
+    }
+
+    /// Bind the method to `obj`.
+    ///
+    /// Returns `Err` if `obj` is not an instance of the method's owner.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, Error, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let method = ruby.class_string().instance_method("length")?;
+    ///     let res: usize = method.bind(ruby.str_new("example"))?.call(())?;
+    ///     assert_eq!(res, 7);
+    ///
+    ///     assert!(method.bind(ruby.integer_from_i64(1)).is_err());
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    pub fn bind<T>(self, obj: T) -> Result<RMethod, Error>
+    where
+        T: IntoValue,
+    {
+        let handle = Ruby::get_with(self);
+        self.funcall("bind", (handle.into_value(obj),))
+    }
+
+    /// Returns the number of arguments the method takes.
+    ///
+    /// See [`RMethod::arity`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, Error, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     assert_eq!(ruby.class_string().instance_method("center")?.arity()?, -2);
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    pub fn arity(self) -> Result<i64, Error> {
+        self.funcall("arity", ())
+    }
+
+    /// Returns the class or module that defines the method.
+    ///
+    /// See [`RClass::from_value`](crate::RClass::from_value) and
+    /// [`RModule::from_value`](crate::RModule::from_value) to check which.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, rb_assert, Error, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let method = ruby.class_string().instance_method("then")?;
+    ///     rb_assert!(ruby, "owner == Kernel", owner = method.owner()?);
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    pub fn owner(self) -> Result<Value, Error> {
+        self.funcall("owner", ())
+    }
+
+    /// Returns the name of the method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, Error, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let method = ruby.class_string().instance_method("upcase")?;
+    ///     assert_eq!(method.name()?.name()?, "upcase");
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    pub fn name(self) -> Result<Symbol, Error> {
+        self.funcall("name", ())
+    }
+
+    /// Returns a description of the method's parameters.
+    ///
+    /// See [`RMethod::parameters`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, rb_assert, Error, RClass, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let class: RClass = ruby.eval("Class.new { def test(a, *b, &c); end }")?;
+    ///     let params = class.instance_method("test")?.parameters()?;
+    ///     rb_assert!(ruby, "params == [[:req, :a], [:rest, :b], [:block, :c]]", params);
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    pub fn parameters(self) -> Result<RArray, Error> {
+        self.funcall("parameters", ())
+    }
+
+    /// Returns the file name and line number where the method is defined.
+    ///
+    /// See [`RMethod::source_location`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, Error, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let method = ruby.class_string().instance_method("upcase")?;
+    ///     assert!(method.source_location()?.is_none());
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    pub fn source_location(self) -> Result<Option<(String, usize)>, Error> {
+        self.funcall("source_location", ())
+    }
+}
+
+impl fmt::Display for RUnboundMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", unsafe { self.to_s_infallible() })
+    }
+}
+
+impl fmt::Debug for RUnboundMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.inspect())
+    }
+}
+
+impl IntoValue for RUnboundMethod {
+    #[inline]
+    fn into_value_with(self, _: &Ruby) -> Value {
+        self.0.get()
+    }
+}
+
+impl Object for RUnboundMethod {}
+
+unsafe impl private::ReprValue for RUnboundMethod {}
+
+impl ReprValue for RUnboundMethod {}
+
+impl TryConvert for RUnboundMethod {
+    fn try_convert(val: Value) -> Result<Self, Error> {
+        Self::from_value(val).ok_or_else(|| {
+            Error::new(
+                Ruby::get_with(val).exception_type_error(),
+                format!("no implicit conversion of {} into UnboundMethod", unsafe {
+                    val.classname()
+                },),
+            )
+        })
+    }
+}
@@ -366,7 +366,7 @@ impl Ruby {
 
 /// Define a Ruby Struct class.
 ///
-/// `members` is a tuple of `&str`, of between lengths 1 to 12 inclusive.
+/// `members` is a tuple of `&str`, of between lengths 1 to 32 inclusive.
 ///
 /// # Panics
 ///
@@ -443,6 +443,6 @@ macro_rules! impl_struct_members {
     }
 }
 
-seq!(N in 1..=12 {
+seq!(N in 1..=32 {
     impl_struct_members!(N);
 });
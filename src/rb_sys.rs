@@ -181,7 +181,7 @@ pub fn catch_unwind<F, T>(func: F) -> Result<T, Error>
 where
     F: FnOnce() -> T + UnwindSafe,
 {
-    std::panic::catch_unwind(func).map_err(Error::from_panic)
+    error::catch_unwind(func).map_err(Error::from_panic)
 }
 
 /// Resumes an [`Error`] previously caught by [`protect`].
@@ -0,0 +1,297 @@
+//! Automatic definition of classes and methods.
+//!
+//! With the `registry` feature enabled, types using the
+//! [`wrap`](macro@crate::wrap)/[`TypedData`](macro@crate::TypedData) macros
+//! can set the `register` attribute, and `impl` blocks with the
+//! [`methods`](macro@crate::methods) attribute can set `register`, to have
+//! their classes and methods recorded at compile time.
+//!
+//! All registered classes and methods are then defined with
+//! [`Ruby::define_registered`], or by setting the `registry` attribute on the
+//! [`init`](macro@crate::init) function.
+//!
+//! Free functions can be registered with the [`register`](macro@crate::register)
+//! attribute, to be defined as global functions, or module functions on a
+//! given module.
+//!
+//! Classes are defined in dependency order, so a class is only defined after
+//! its superclass and any enclosing modules/classes that are also registered.
+//! Enclosing modules that are not registered are defined as needed. Methods
+//! and functions are defined after all classes.
+//!
+//! The `registry` feature depends on the [`inventory`] crate.
+//!
+//! # Examples
+//!
+//! ```
+//! use magnus::Error;
+//!
+//! #[magnus::wrap(class = "Euclid::Shape", register)]
+//! struct Shape {}
+//!
+//! #[magnus::wrap(class = "Euclid::Point", superclass = "Euclid::Shape", register)]
+//! struct Point {
+//!     x: isize,
+//!     y: isize,
+//! }
+//!
+//! #[magnus::methods(register)]
+//! impl Point {
+//!     fn new(x: isize, y: isize) -> Self {
+//!         Self { x, y }
+//!     }
+//!
+//!     fn x(&self) -> isize {
+//!         self.x
+//!     }
+//!
+//!     fn y(&self) -> isize {
+//!         self.y
+//!     }
+//! }
+//!
+//! #[magnus::register(module = "Euclid")]
+//! fn distance(a: &Point, b: &Point) -> f64 {
+//!     (((b.x - a.x).pow(2) + (b.y - a.y).pow(2)) as f64).sqrt()
+//! }
+//!
+//! #[magnus::init(registry)]
+//! fn init() -> Result<(), Error> {
+//!     // `Euclid`, `Euclid::Shape`, and `Euclid::Point`, `Point`'s methods,
+//!     // and `Euclid.distance` have already been defined
+//!     Ok(())
+//! }
+//! ```
+
+use std::collections::HashSet;
+
+#[doc(hidden)]
+pub use inventory;
+
+use crate::{
+    class::RClass,
+    error::Error,
+    method::Method,
+    module::{Module, RModule},
+    object::Object,
+    try_convert::TryConvert,
+    typed_data::TypedData,
+    value::ReprValue,
+    Ruby,
+};
+
+/// A class or set of methods to be defined by [`Ruby::define_registered`].
+///
+/// Values of this type are created by the `register` attribute of the
+/// [`wrap`](macro@crate::wrap), [`TypedData`](macro@crate::TypedData), and
+/// [`methods`](macro@crate::methods) macros, and the
+/// [`register`](macro@crate::register) attribute. They can also be created
+/// manually and submitted with [`inventory::submit!`].
+pub struct Registration(Kind);
+
+enum Kind {
+    Class {
+        path: &'static str,
+        superclass: Option<&'static str>,
+    },
+    Methods {
+        name: &'static str,
+        define: fn(&Ruby) -> Result<(), Error>,
+    },
+    Function {
+        name: &'static str,
+        define: fn(&Ruby) -> Result<(), Error>,
+    },
+}
+
+impl Registration {
+    /// Register a class to be defined at `path`, e.g. `"Foo::Bar::Baz"`.
+    ///
+    /// `superclass` is the path of the superclass, if `None` `Object` will
+    /// be used.
+    pub const fn class(path: &'static str, superclass: Option<&'static str>) -> Self {
+        Self(Kind::Class { path, superclass })
+    }
+
+    /// Register a function to define methods, which will be run after all
+    /// classes have been defined.
+    ///
+    /// `name` is used in error messages.
+    pub const fn methods(name: &'static str, define: fn(&Ruby) -> Result<(), Error>) -> Self {
+        Self(Kind::Methods { name, define })
+    }
+
+    /// Register a function to define a function, which will be run after all
+    /// classes have been defined.
+    ///
+    /// `name` is used in error messages.
+    pub const fn function(name: &'static str, define: fn(&Ruby) -> Result<(), Error>) -> Self {
+        Self(Kind::Function { name, define })
+    }
+}
+
+inventory::collect!(Registration);
+
+/// # Registry
+///
+/// Functions for defining classes and methods registered at compile time.
+///
+/// See also the [`registry`](crate::registry) module.
+impl Ruby {
+    /// Define all registered classes and methods.
+    ///
+    /// Classes are defined before methods, and each class is defined after
+    /// any registered class it depends on (its superclass and enclosing
+    /// classes).
+    ///
+    /// Definition continues after a failure, with all failures reported in a
+    /// single `RuntimeError`.
+    ///
+    /// This is called automatically by the [`init`](macro@crate::init)
+    /// function when the `registry` attribute is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{rb_assert, Error, Ruby};
+    ///
+    /// #[magnus::wrap(class = "Example", register)]
+    /// struct Example {}
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     ruby.define_registered()?;
+    ///     rb_assert!(ruby, "Example.is_a?(Class)");
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    pub fn define_registered(&self) -> Result<(), Error> {
+        let mut classes = Vec::new();
+        let mut methods = Vec::new();
+        for registration in inventory::iter::<Registration> {
+            match registration.0 {
+                Kind::Class { path, superclass } => classes.push((path, superclass)),
+                Kind::Methods { name, define } => methods.push(("methods for", name, define)),
+                Kind::Function { name, define } => methods.push(("function", name, define)),
+            }
+        }
+        classes.sort_unstable();
+
+        let registered = classes
+            .iter()
+            .map(|(path, _)| *path)
+            .collect::<HashSet<_>>();
+        let mut defined = HashSet::new();
+        let mut failed = HashSet::new();
+        let mut errors = Vec::new();
+        loop {
+            let pending = classes.len();
+            classes.retain(|&(path, superclass)| {
+                let mut deps = namespaces(path).chain(superclass);
+                if let Some(dep) = deps.clone().find(|dep| failed.contains(dep)) {
+                    errors.push(format!("class `{}`: depends on `{}`", path, dep));
+                    failed.insert(path);
+                    return false;
+                }
+                if deps.any(|dep| registered.contains(dep) && !defined.contains(dep)) {
+                    return true;
+                }
+                match self.define_registered_class(path, superclass) {
+                    Ok(()) => {
+                        defined.insert(path);
+                    }
+                    Err(e) => {
+                        errors.push(format!("class `{}`: {}", path, e));
+                        failed.insert(path);
+                    }
+                }
+                false
+            });
+            if classes.is_empty() || classes.len() == pending {
+                break;
+            }
+        }
+        for (path, _) in classes {
+            errors.push(format!("class `{}`: circular dependency", path));
+        }
+
+        for (kind, name, define) in methods {
+            if let Err(e) = define(self) {
+                errors.push(format!("{} `{}`: {}", kind, name, e));
+            }
+        }
+
+        if errors.is_empty() {
+            return Ok(());
+        }
+        Err(Error::new(
+            self.exception_runtime_error(),
+            format!("failed to define:\n  {}", errors.join("\n  ")),
+        ))
+    }
+
+    fn define_registered_class(&self, path: &str, superclass: Option<&str>) -> Result<(), Error> {
+        let superclass = match superclass {
+            Some(superclass) => self.class_object().funcall("const_get", (superclass,))?,
+            None => self.class_object(),
+        };
+        let (namespace, name) = match path.rsplit_once("::") {
+            Some((namespace, name)) => (self.define_namespace(namespace)?, name),
+            None => (self.class_object().as_value(), path),
+        };
+        match RClass::from_value(namespace) {
+            Some(namespace) => namespace.define_class(name, superclass)?,
+            None => RModule::try_convert(namespace)?.define_class(name, superclass)?,
+        };
+        Ok(())
+    }
+}
+
+/// Returns the class of `T`, erroring rather than panicking if the class
+/// isn't defined.
+///
+/// Used by the `register` attribute of the [`methods`](macro@crate::methods)
+/// macro.
+#[doc(hidden)]
+pub fn typed_data_class<T>(ruby: &Ruby) -> Result<RClass, Error>
+where
+    T: TypedData,
+{
+    if let Some(path) = T::class_path() {
+        let _: RClass = ruby.class_object().funcall("const_get", (path,))?;
+    }
+    Ok(T::class(ruby))
+}
+
+/// Defines `func` as a global function, or a module function on the
+/// module/class at `module`, defining any modules as needed.
+///
+/// Used by the [`register`](macro@crate::register) attribute.
+#[doc(hidden)]
+pub fn define_function<M>(
+    ruby: &Ruby,
+    module: Option<&str>,
+    name: &str,
+    func: M,
+) -> Result<(), Error>
+where
+    M: Method,
+{
+    let module = match module {
+        Some(module) => ruby.define_namespace(module)?,
+        None => {
+            ruby.define_global_function(name, func);
+            return Ok(());
+        }
+    };
+    match RClass::from_value(module) {
+        Some(class) => class.define_singleton_method(name, func),
+        None => RModule::try_convert(module)?.define_module_function(name, func),
+    }
+}
+
+/// Iterates over the paths of the enclosing namespaces of `path`, e.g.
+/// `"Foo::Bar::Baz"` produces `"Foo"` and `"Foo::Bar"`.
+fn namespaces(path: &str) -> impl Iterator<Item = &str> + Clone {
+    path.match_indices("::").map(move |(i, _)| &path[..i])
+}
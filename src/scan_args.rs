@@ -33,10 +33,12 @@ use std::{
     ffi::CString,
     fmt,
     mem::transmute,
-    ops::{Bound, RangeBounds},
+    ops::{Bound, Deref, RangeBounds},
     os::raw::c_int,
 };
 
+#[cfg(ruby_gte_2_7)]
+use rb_sys::rb_keyword_given_p;
 use rb_sys::{rb_error_arity, rb_get_kwargs, rb_scan_args, ID, VALUE};
 use seq_macro::seq;
 
@@ -630,8 +632,17 @@ unsafe fn scan_args_impl(args: &[Value], fmt: &str, out: &mut [Value]) -> Result
     Ok(result)
 }
 
-/// Arguments returned from [`get_kwargs`].
-pub struct KwArgs<Req, Opt, Splat> {
+/// Keyword arguments.
+///
+/// Returned from [`get_kwargs`], with the types of the fields determined by
+/// type parameters.
+///
+/// `KwArgs<T>`, where `T` implements [`FromKwArgs`], can also be used as the
+/// final argument of a function wrapped with the [`method`](crate::method!) or
+/// [`function`](crate::function!) macros to receive that method's keyword
+/// arguments. The keyword arguments are available as the `required` field,
+/// or via [`Deref`].
+pub struct KwArgs<Req, Opt = (), Splat = ()> {
     /// Required arguments.
     pub required: Req,
     /// Optional arguments.
@@ -640,6 +651,15 @@ pub struct KwArgs<Req, Opt, Splat> {
     pub splat: Splat,
 }
 
+impl<T> Deref for KwArgs<T, (), ()> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.required
+    }
+}
+
 /// Deconstruct keyword arguments.
 ///
 /// Extracts `required` and `optional` arguments from the given `kw` hash.
@@ -799,6 +819,238 @@ where
     })
 }
 
+/// Trait for types that can be created from a Ruby `Hash` of keyword
+/// arguments.
+///
+/// Types implementing this trait can be wrapped in [`KwArgs`] and used as the
+/// final argument of a function wrapped with the [`method`](crate::method!) or
+/// [`function`](crate::function!) macros, e.g. `kwargs: KwArgs<MyOpts>`, to
+/// receive that method's keyword arguments.
+///
+/// This trait can be derived with
+/// [`#[derive(FromKwArgs)]`](macro@crate::FromKwArgs). Fields of type
+/// `Option<T>`, or marked `#[magnus(default)]`, are optional keywords, all
+/// other fields are required keywords. Missing or unknown keywords raise an
+/// `ArgumentError`.
+///
+/// # Examples
+///
+/// ```
+/// use magnus::{method, prelude::*, rb_assert, scan_args::KwArgs, Error, FromKwArgs, Ruby};
+///
+/// #[derive(FromKwArgs)]
+/// struct Options {
+///     count: usize,
+///     sep: Option<String>,
+///     #[magnus(default)]
+///     upcase: bool,
+/// }
+///
+/// fn repeat(rb_self: String, opts: KwArgs<Options>) -> String {
+///     let s = if opts.upcase {
+///         rb_self.to_uppercase()
+///     } else {
+///         rb_self
+///     };
+///     vec![s; opts.count].join(opts.sep.as_deref().unwrap_or(""))
+/// }
+///
+/// fn example(ruby: &Ruby) -> Result<(), Error> {
+///     ruby.class_string()
+///         .define_method("repeat", method!(repeat, 0))?;
+///
+///     rb_assert!(ruby, r#""a".repeat(count: 3) == "aaa""#);
+///     rb_assert!(ruby, r#""a".repeat(count: 2, sep: ", ", upcase: true) == "A, A""#);
+///     rb_assert!(ruby, r#"("a".repeat rescue $!).message == "missing keyword: :count""#);
+///     rb_assert!(ruby, r#"("a".repeat(count: 1, x: 1) rescue $!).message == "unknown keyword: :x""#);
+///
+///     Ok(())
+/// }
+/// # Ruby::init(example).unwrap()
+/// ```
+pub trait FromKwArgs: Sized {
+    /// Create `Self` from the keyword arguments `kw`.
+    fn from_kwargs(kw: RHash) -> Result<Self, Error>;
+}
+
+impl FromKwArgs for RHash {
+    #[inline]
+    fn from_kwargs(kw: RHash) -> Result<Self, Error> {
+        Ok(kw)
+    }
+}
+
+impl<T> FromKwArgs for KwArgs<T, (), ()>
+where
+    T: FromKwArgs,
+{
+    #[inline]
+    fn from_kwargs(kw: RHash) -> Result<Self, Error> {
+        Ok(KwArgs {
+            required: T::from_kwargs(kw)?,
+            optional: (),
+            splat: (),
+        })
+    }
+}
+
+/// Trait for types that can be created from a method's arguments.
+///
+/// Types implementing this trait can be used as the only argument (other than
+/// `self` or `&Ruby`) of a function wrapped with the [`method`](crate::method!)
+/// or [`function`](crate::function!) macros with an arity of `-1`, to receive
+/// that method's arguments.
+///
+/// This trait can be derived with
+/// [`#[derive(ScanArgs)]`](macro@crate::ScanArgs), which describes the
+/// method's arguments with named fields, rather than the positional tuples
+/// used by [`scan_args`].
+///
+/// # Examples
+///
+/// ```
+/// use magnus::{function, prelude::*, rb_assert, Error, RArray, Ruby, ScanArgs};
+///
+/// #[derive(ScanArgs)]
+/// struct Args {
+///     #[magnus(required)]
+///     name: String,
+///     #[magnus(optional, default = 1)]
+///     count: usize,
+///     #[magnus(splat)]
+///     rest: RArray,
+/// }
+///
+/// fn describe(args: Args) -> String {
+///     format!("{} x{} (+{})", args.name, args.count, args.rest.len())
+/// }
+///
+/// fn example(ruby: &Ruby) -> Result<(), Error> {
+///     ruby.define_global_function("describe", function!(describe, -1));
+///
+///     rb_assert!(ruby, r#"describe("a") == "a x1 (+0)""#);
+///     rb_assert!(ruby, r#"describe("a", 2, :b, :c) == "a x2 (+2)""#);
+///     rb_assert!(ruby, r#"(describe rescue $!).message == "wrong number of arguments (given 0, expected 1+)""#);
+///
+///     Ok(())
+/// }
+/// # Ruby::init(example).unwrap()
+/// ```
+pub trait ScanArgs: Sized {
+    /// Create `Self` from the arguments `args`.
+    fn from_args(ruby: &Ruby, args: &[Value]) -> Result<Self, Error>;
+}
+
+// Splits off trailing keyword arguments from `args`, if present.
+pub(crate) fn split_kwargs<'a>(handle: &Ruby, args: &'a [Value]) -> (&'a [Value], RHash) {
+    #[cfg(ruby_gte_2_7)]
+    let given = unsafe { rb_keyword_given_p() != 0 };
+    #[cfg(ruby_lt_2_7)]
+    let given = true;
+    if given {
+        if let Some((kw, rest)) = args
+            .split_last()
+            .and_then(|(last, rest)| RHash::from_value(*last).map(|kw| (kw, rest)))
+        {
+            return (rest, kw);
+        }
+    }
+    (args, handle.hash_new())
+}
+
+/// Extracts keyword arguments by name, for use by the
+/// [`FromKwArgs`](macro@crate::FromKwArgs) derive macro.
+///
+/// Returns a `Vec` with a value for each of `required` followed by each of
+/// `optional`, with `None` for optional arguments that were not given. If
+/// `splat` is `true` unknown keywords are left in `kw`, otherwise they raise
+/// an `ArgumentError`.
+#[doc(hidden)]
+pub fn get_kwargs_values(
+    kw: RHash,
+    required: &[&str],
+    optional: &[&str],
+    splat: bool,
+) -> Result<Vec<Option<Value>>, Error> {
+    let handle = Ruby::get_with(kw);
+    let ids = required
+        .iter()
+        .chain(optional.iter())
+        .map(|name| name.into_id_with(&handle))
+        .collect::<Vec<Id>>();
+    let optional_len = if splat {
+        -(optional.len() as c_int + 1)
+    } else {
+        optional.len() as c_int
+    };
+    let mut out = vec![handle.qnil().as_value(); ids.len()];
+    unsafe {
+        protect(|| {
+            rb_get_kwargs(
+                kw.as_rb_value(),
+                ids.as_ptr() as *const ID,
+                required.len() as c_int,
+                optional_len,
+                out.as_mut_ptr() as *mut VALUE,
+            );
+            handle.qnil()
+        })?;
+    }
+    Ok(out
+        .into_iter()
+        .map(|v| if v.is_undef() { None } else { Some(v) })
+        .collect())
+}
+
+/// Arguments extracted by [`scan_args_values`].
+#[doc(hidden)]
+pub struct ScannedValues {
+    pub required: Vec<Value>,
+    pub optional: Vec<Option<Value>>,
+    pub splat: Value,
+    pub trailing: Vec<Value>,
+    pub keywords: RHash,
+    pub block: Value,
+}
+
+/// Extracts arguments by position, for use by the
+/// [`ScanArgs`](macro@crate::ScanArgs) derive macro.
+///
+/// Optional arguments that were not given are `None`. The splat and block are
+/// `nil` if not requested or not given, keywords are an empty hash if not
+/// requested or not given.
+#[doc(hidden)]
+#[allow(clippy::too_many_arguments)]
+pub fn scan_args_values(
+    ruby: &Ruby,
+    args: &[Value],
+    required: usize,
+    optional: usize,
+    splat: bool,
+    trailing: usize,
+    keywords: bool,
+    block: bool,
+) -> Result<ScannedValues, Error> {
+    let arg_spec = ArgSpec::new(required, optional, splat, trailing, keywords, block);
+    let scanned_args = scan_args_untyped(args, arg_spec)?;
+    let given = scanned_args.optional();
+    Ok(ScannedValues {
+        required: scanned_args.required().to_vec(),
+        optional: (0..optional).map(|i| given.get(i).copied()).collect(),
+        splat: scanned_args
+            .splat()
+            .unwrap_or_else(|| ruby.qnil().as_value()),
+        trailing: scanned_args.trailing().to_vec(),
+        keywords: scanned_args
+            .keywords()
+            .and_then(RHash::from_value)
+            .unwrap_or_else(|| ruby.hash_new()),
+        block: scanned_args
+            .block()
+            .unwrap_or_else(|| ruby.qnil().as_value()),
+    })
+}
+
 /// # Argument Parsing
 ///
 /// Functions for handling argument parsing.
@@ -247,7 +247,7 @@ macro_rules! impl_try_convert {
     }
 }
 
-seq!(N in 1..=12 {
+seq!(N in 1..=32 {
     impl_try_convert!(N);
 });
 
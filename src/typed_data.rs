@@ -4,22 +4,24 @@
 //! `rb_data_typed_object_wrap` function from Ruby's C API.
 
 use std::{
+    cell::UnsafeCell,
     collections::hash_map::DefaultHasher,
     ffi::{c_void, CStr},
     fmt,
     hash::Hasher,
     marker::PhantomData,
-    mem::size_of_val,
+    mem::{size_of_val, MaybeUninit},
     ops::Deref,
     panic::catch_unwind,
     ptr,
+    sync::Once,
 };
 
 #[cfg(ruby_gte_3_0)]
 use rb_sys::rbimpl_typeddata_flags::{self, RUBY_TYPED_FREE_IMMEDIATELY, RUBY_TYPED_WB_PROTECTED};
 use rb_sys::{
     self, rb_data_type_struct__bindgen_ty_1, rb_data_type_t, rb_obj_reveal,
-    rb_singleton_class_attached, rb_singleton_class_clone, size_t, VALUE,
+    rb_singleton_class_attached, rb_singleton_class_clone, rb_typeddata_inherited_p, size_t, VALUE,
 };
 
 #[cfg(ruby_lt_3_0)]
@@ -64,6 +66,38 @@ impl DataType {
         DataTypeBuilder::new(name)
     }
 
+    /// Returns whether `self` is `parent`, or has `parent` as an ancestor.
+    ///
+    /// See [`DataTypeBuilder::parent`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{data_type_builder, DataType, DataTypeFunctions};
+    ///
+    /// #[derive(DataTypeFunctions)]
+    /// struct Shape {}
+    ///
+    /// #[derive(DataTypeFunctions)]
+    /// #[repr(C)]
+    /// struct Circle {
+    ///     shape: Shape,
+    ///     r: f64,
+    /// }
+    ///
+    /// static SHAPE: DataType = data_type_builder!(Shape, "shape").build();
+    /// static CIRCLE: DataType = data_type_builder!(Circle, "circle")
+    ///     .parent(&SHAPE)
+    ///     .build();
+    ///
+    /// assert!(CIRCLE.is_inherited(&SHAPE));
+    /// assert!(SHAPE.is_inherited(&SHAPE));
+    /// assert!(!SHAPE.is_inherited(&CIRCLE));
+    /// ```
+    pub fn is_inherited(&self, parent: &DataType) -> bool {
+        unsafe { rb_typeddata_inherited_p(self.as_rb_data_type(), parent.as_rb_data_type()) != 0 }
+    }
+
     #[inline]
     pub(crate) fn as_rb_data_type(&self) -> &rb_data_type_t {
         &self.0
@@ -223,6 +257,7 @@ pub struct DataTypeBuilder<T> {
     free_immediately: bool,
     wb_protected: bool,
     frozen_shareable: bool,
+    parent: Option<&'static DataType>,
     phantom: PhantomData<T>,
 }
 
@@ -263,6 +298,7 @@ where
             free_immediately: false,
             wb_protected: false,
             frozen_shareable: false,
+            parent: None,
             phantom: PhantomData,
         }
     }
@@ -305,6 +341,25 @@ where
         self
     }
 
+    /// Set the parent [`DataType`].
+    ///
+    /// Ruby will accept a value wrapping this type anywhere a value wrapping
+    /// the type described by `parent` is expected, so a value wrapping `T`
+    /// will successfully convert to a reference to the parent type.
+    ///
+    /// For this to be sound `T` must be `#[repr(C)]` and have the parent type
+    /// as its first field, or have the same layout as the parent type. This is
+    /// upheld by implementing [`TypedData`] (an `unsafe` trait), see the
+    /// [`TypedData`](derive@crate::TypedData) derive macro's `parent`
+    /// attribute for a way to have this checked at compile time.
+    ///
+    /// The Ruby class for `T` should also be a subclass of the parent type's
+    /// class, so that methods defined on the parent are available.
+    pub const fn parent(mut self, parent: &'static DataType) -> Self {
+        self.parent = Some(parent);
+        self
+    }
+
     /// Consume the builder and create a DataType.
     pub const fn build(self) -> DataType {
         let mut flags = 0_usize as VALUE;
@@ -335,6 +390,10 @@ where
         } else {
             None
         };
+        let parent = match self.parent {
+            Some(parent) => &parent.0 as *const _,
+            None => ptr::null(),
+        };
         DataType(rb_data_type_t {
             wrap_struct_name: self.name.as_ptr() as _,
             function: rb_data_type_struct__bindgen_ty_1 {
@@ -348,13 +407,90 @@ where
                 #[cfg(ruby_lt_2_7)]
                 reserved: [ptr::null_mut(); 2],
             },
-            parent: ptr::null(),
+            parent,
             data: ptr::null_mut(),
             flags,
         })
     }
 }
 
+/// A [`DataType`] that is created on first use.
+///
+/// [`DataTypeBuilder::parent`] can only be used in a `const` context with a
+/// reference to a `static` [`DataType`], this allows assigning a [`DataType`]
+/// with a parent only known at runtime (such as from another type's
+/// [`TypedData::data_type`]) to a `static`.
+///
+/// This is used by the [`TypedData`](derive@crate::TypedData) derive macro
+/// when the `parent` attribute is set.
+///
+/// # Examples
+///
+/// ```
+/// use magnus::{
+///     data_type_builder, typed_data::LazyDataType, DataType, DataTypeFunctions, TypedData,
+/// };
+/// # use magnus::{RClass, Ruby};
+///
+/// #[magnus::wrap(class = "Shape")]
+/// struct Shape {}
+///
+/// #[derive(DataTypeFunctions)]
+/// #[repr(C)]
+/// struct Circle {
+///     shape: Shape,
+///     r: f64,
+/// }
+///
+/// unsafe impl TypedData for Circle {
+/// #   fn class(_: &Ruby) -> RClass { unimplemented!() }
+///     // ...
+///
+///     fn data_type() -> &'static DataType {
+///         static DATA_TYPE: LazyDataType = LazyDataType::new(|| {
+///             data_type_builder!(Circle, "circle")
+///                 .parent(Shape::data_type())
+///                 .build()
+///         });
+///         DATA_TYPE.get()
+///     }
+/// }
+///
+/// assert!(Circle::data_type().is_inherited(Shape::data_type()));
+/// ```
+pub struct LazyDataType {
+    init: Once,
+    func: fn() -> DataType,
+    inner: UnsafeCell<MaybeUninit<DataType>>,
+}
+
+impl LazyDataType {
+    /// Create a new `LazyDataType`.
+    ///
+    /// This function can be called in a `const` context. `func` is evaluated
+    /// once when the `LazyDataType` is first accessed.
+    pub const fn new(func: fn() -> DataType) -> Self {
+        Self {
+            init: Once::new(),
+            func,
+            inner: UnsafeCell::new(MaybeUninit::uninit()),
+        }
+    }
+
+    /// Get the inner [`DataType`], creating it if this is the first access.
+    pub fn get(&self) -> &DataType {
+        unsafe {
+            self.init.call_once(|| {
+                (*self.inner.get()).write((self.func)());
+            });
+            (*self.inner.get()).assume_init_ref()
+        }
+    }
+}
+
+unsafe impl Send for LazyDataType {}
+unsafe impl Sync for LazyDataType {}
+
 impl<T> DataTypeBuilder<T>
 where
     T: DataTypeFunctions + Sync,
@@ -493,6 +629,16 @@ where
     fn class_for(ruby: &Ruby, value: &Self) -> RClass {
         Self::class(ruby)
     }
+
+    /// Returns the path of the class returned by [`TypedData::class`], e.g.
+    /// `"Foo::Bar"`, if known.
+    ///
+    /// Used by the registry to check the class exists before calling
+    /// [`TypedData::class`], which may panic if it doesn't.
+    #[doc(hidden)]
+    fn class_path() -> Option<&'static str> {
+        None
+    }
 }
 
 impl<T> TryConvert for &T
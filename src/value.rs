@@ -23,12 +23,11 @@ use std::{
 #[cfg(ruby_use_flonum)]
 pub use flonum::Flonum;
 use rb_sys::{
-    rb_any_to_s, rb_block_call, rb_check_funcall, rb_check_id, rb_check_id_cstr,
-    rb_check_symbol_cstr, rb_enumeratorize_with_size, rb_eql, rb_equal, rb_funcall_with_block,
-    rb_funcallv, rb_funcallv_public, rb_gc_register_address, rb_gc_unregister_address, rb_hash,
-    rb_id2name, rb_id2sym, rb_inspect, rb_intern3, rb_ll2inum, rb_obj_as_string, rb_obj_classname,
-    rb_obj_freeze, rb_obj_is_kind_of, rb_obj_respond_to, rb_sym2id, rb_ull2inum, ruby_fl_type,
-    ruby_special_consts, ruby_value_type, RBasic, ID, VALUE,
+    rb_any_to_s, rb_check_funcall, rb_check_id, rb_check_id_cstr, rb_check_symbol_cstr,
+    rb_enumeratorize_with_size, rb_eql, rb_equal, rb_gc_register_address, rb_gc_unregister_address,
+    rb_hash, rb_id2name, rb_id2sym, rb_inspect, rb_intern3, rb_ll2inum, rb_obj_as_string,
+    rb_obj_classname, rb_obj_freeze, rb_obj_is_kind_of, rb_obj_method, rb_obj_respond_to,
+    rb_sym2id, rb_ull2inum, ruby_fl_type, ruby_special_consts, ruby_value_type, RBasic, ID, VALUE,
 };
 
 // These don't seem to appear consistently in bindgen output, not sure if they
@@ -40,6 +39,7 @@ const RUBY_FIXNUM_MIN: c_long = c_long::MIN / 2;
 use crate::{
     block::Proc,
     class::RClass,
+    compat::{rb_block_call_kw, rb_funcall_with_block_kw, rb_funcallv_kw, rb_funcallv_public_kw},
     encoding::EncodingCapable,
     enumerator::Enumerator,
     error::{protect, Error},
@@ -50,6 +50,7 @@ use crate::{
     module::Module,
     numeric::Numeric,
     r_bignum::RBignum,
+    r_method::RMethod,
     r_string::RString,
     symbol::{IntoSymbol, Symbol},
     try_convert::{TryConvert, TryConvertOwned},
@@ -1122,15 +1123,17 @@ pub trait ReprValue: private::ReprValue {
     {
         let handle = Ruby::get_with(self);
         let id = method.into_id_with(&handle);
+        let kw_splat = args.contains_kw_args() as c_int;
         let args = args.into_arg_list_with(&handle);
         let slice = args.as_ref();
         unsafe {
             protect(|| {
-                Value::new(rb_funcallv(
+                Value::new(rb_funcallv_kw(
                     self.as_rb_value(),
                     id.as_rb_id(),
                     slice.len() as c_int,
                     slice.as_ptr() as *const VALUE,
+                    kw_splat,
                 ))
             })
             .and_then(TryConvert::try_convert)
@@ -1182,15 +1185,17 @@ pub trait ReprValue: private::ReprValue {
     {
         let handle = Ruby::get_with(self);
         let id = method.into_id_with(&handle);
+        let kw_splat = args.contains_kw_args() as c_int;
         let args = args.into_arg_list_with(&handle);
         let slice = args.as_ref();
         unsafe {
             protect(|| {
-                Value::new(rb_funcallv_public(
+                Value::new(rb_funcallv_public_kw(
                     self.as_rb_value(),
                     id.as_rb_id(),
                     slice.len() as c_int,
                     slice.as_ptr() as *const VALUE,
+                    kw_splat,
                 ))
             })
             .and_then(TryConvert::try_convert)
@@ -1269,16 +1274,18 @@ pub trait ReprValue: private::ReprValue {
     {
         let handle = Ruby::get_with(self);
         let id = method.into_id_with(&handle);
+        let kw_splat = args.contains_kw_args() as c_int;
         let args = args.into_arg_list_with(&handle);
         let slice = args.as_ref();
         unsafe {
             protect(|| {
-                Value::new(rb_funcall_with_block(
+                Value::new(rb_funcall_with_block_kw(
                     self.as_rb_value(),
                     id.as_rb_id(),
                     slice.len() as c_int,
                     slice.as_ptr() as *const VALUE,
                     block.as_rb_value(),
+                    kw_splat,
                 ))
             })
             .and_then(TryConvert::try_convert)
@@ -1346,6 +1353,7 @@ pub trait ReprValue: private::ReprValue {
 
         let handle = Ruby::get_with(self);
         let id = method.into_id_with(&handle);
+        let kw_splat = args.contains_kw_args() as c_int;
         let args = args.into_arg_list_with(&handle);
         let slice = args.as_ref();
         let call_func =
@@ -1355,13 +1363,14 @@ pub trait ReprValue: private::ReprValue {
 
         protect(|| unsafe {
             #[allow(clippy::fn_to_numeric_cast)]
-            Value::new(rb_block_call(
+            Value::new(rb_block_call_kw(
                 self.as_rb_value(),
                 id.as_rb_id(),
                 slice.len() as c_int,
                 slice.as_ptr() as *const VALUE,
                 Some(call_func),
                 block as VALUE,
+                kw_splat,
             ))
         })
         .and_then(TryConvert::try_convert)
@@ -1404,6 +1413,37 @@ pub trait ReprValue: private::ReprValue {
         Ok(res)
     }
 
+    /// Returns the method `name` of `self` as a [`RMethod`] object.
+    ///
+    /// Returns `Err` if `self` does not respond to `name`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use magnus::{prelude::*, Error, Ruby};
+    ///
+    /// fn example(ruby: &Ruby) -> Result<(), Error> {
+    ///     let method = ruby.str_new("example").method("upcase")?;
+    ///     let res: String = method.call(())?;
+    ///     assert_eq!(res, "EXAMPLE");
+    ///
+    ///     assert!(ruby.str_new("example").method("non_existant").is_err());
+    ///
+    ///     Ok(())
+    /// }
+    /// # Ruby::init(example).unwrap()
+    /// ```
+    fn method<M>(self, name: M) -> Result<RMethod, Error>
+    where
+        M: IntoId,
+    {
+        let handle = Ruby::get_with(self);
+        let name = handle.into_value(name.into_id_with(&handle));
+        protect(|| unsafe {
+            RMethod::from_rb_value_unchecked(rb_obj_method(self.as_rb_value(), name.as_rb_value()))
+        })
+    }
+
     /// Convert `self` to a Ruby `String`.
     ///
     /// If `self` is already a `String` is it wrapped as a `RString`, otherwise
@@ -3207,6 +3247,37 @@ impl PartialEq<Symbol> for OpaqueId {
 /// first used. This initialisation must happen on a Ruby thread. If the first
 /// use is from a non-Ruby thread the `LazyId` will panic and then become
 /// *poisoned* and all future use of it will panic.
+///
+/// Passing a `&str` method name to [`ReprValue::funcall`] looks the name up in
+/// Ruby's symbol table on every call. When repeatedly calling the same method,
+/// for example from a callback, a `LazyId` avoids this by interning the name
+/// only once. Method dispatch itself is unchanged, and goes through Ruby's own
+/// method cache.
+///
+/// Magnus doesn't provide a call site type that also caches the resolved
+/// method. Ruby's C API exposes neither method entries nor the class serials
+/// needed to validate them, and `funcall` is already served by Ruby's
+/// internal call cache, keyed on the receiver's class and method name.
+///
+/// # Examples
+///
+/// ```
+/// use magnus::{prelude::*, value::LazyId, Error, Ruby};
+///
+/// static TO_S: LazyId = LazyId::new("to_s");
+///
+/// fn example(ruby: &Ruby) -> Result<(), Error> {
+///     let values = ruby.ary_from_vec(vec![1, 2, 3]);
+///     let strings = values
+///         .each()
+///         .map(|v| v?.funcall(*TO_S, ()))
+///         .collect::<Result<Vec<String>, Error>>()?;
+///     assert_eq!(strings, ["1", "2", "3"]);
+///
+///     Ok(())
+/// }
+/// # Ruby::init(example).unwrap()
+/// ```
 pub struct LazyId {
     init: Once,
     inner: UnsafeCell<LazyIdInner>,
@@ -0,0 +1,88 @@
+use std::cell::Cell;
+
+use magnus::{embed::init, function, prelude::*, rb_assert, Error, Ruby, Value};
+
+fn find_first_negative(ruby: &Ruby, values: Vec<i64>) -> Result<Value, Error> {
+    ruby.catch(ruby.to_symbol("found"), |tag| {
+        for value in values {
+            if value < 0 {
+                return Err(ruby.throw(tag, value));
+            }
+        }
+        Ok(ruby.qnil())
+    })
+}
+
+fn throw_done(ruby: &Ruby) -> Result<(), Error> {
+    Err(ruby.throw(ruby.to_symbol("done"), 42))
+}
+
+#[test]
+fn it_catches_rescues_and_ensures() {
+    let ruby = unsafe { init() };
+
+    ruby.define_global_function("find_first_negative", function!(find_first_negative, 1));
+    ruby.define_global_function("throw_done", function!(throw_done, 0));
+
+    rb_assert!(ruby, "find_first_negative([1, -2, 3]) == -2");
+    rb_assert!(ruby, "find_first_negative([1, 2, 3]).nil?");
+    rb_assert!(ruby, "catch(:done) { throw_done; 0 } == 42");
+    rb_assert!(ruby, "(throw_done rescue $!).is_a?(UncaughtThrowError)");
+
+    // a throw from Ruby code passes through a Rust catch for another tag
+    let res = ruby.catch(ruby.to_symbol("inner"), |_| {
+        ruby.eval::<Value>("catch(:outer) { throw :outer, 1; 2 }")
+    });
+    assert_eq!(i64::try_convert(res.unwrap()).unwrap(), 1);
+
+    let cleaned_up = Cell::new(0);
+    let res = ruby.ensure(
+        || ruby.eval::<i64>("1 + 1"),
+        || cleaned_up.set(cleaned_up.get() + 1),
+    );
+    assert_eq!(res.unwrap(), 2);
+    let res = ruby.ensure(
+        || ruby.eval::<i64>("raise 'oops'"),
+        || cleaned_up.set(cleaned_up.get() + 1),
+    );
+    assert!(res.unwrap_err().is_kind_of(ruby.exception_runtime_error()));
+    assert_eq!(cleaned_up.get(), 2);
+
+    let res = ruby.rescue(
+        || ruby.eval::<i64>("Integer('ten')"),
+        &[ruby.exception_type_error(), ruby.exception_arg_error()],
+        |e| {
+            assert!(e.is_kind_of(ruby.exception_arg_error()));
+            Ok(10)
+        },
+    );
+    assert_eq!(res.unwrap(), 10);
+
+    // defaults to StandardError
+    let res = ruby.rescue(|| ruby.eval::<i64>("raise 'oops'"), &[], |_| Ok(0));
+    assert_eq!(res.unwrap(), 0);
+
+    // non-matching exceptions are passed through
+    let res = ruby.rescue(
+        || ruby.eval::<i64>("raise IndexError"),
+        &[ruby.exception_arg_error()],
+        |_| Ok(0),
+    );
+    assert!(res.unwrap_err().is_kind_of(ruby.exception_index_error()));
+
+    // errors from the handler are returned
+    let res: Result<i64, Error> = ruby.rescue(
+        || ruby.eval("raise ArgumentError"),
+        &[ruby.exception_arg_error()],
+        |_| Err(Error::new(ruby.exception_runtime_error(), "handled")),
+    );
+    assert!(res.unwrap_err().is_kind_of(ruby.exception_runtime_error()));
+
+    // errors created in Rust are rescued too
+    let res = ruby.rescue(
+        || Err(Error::new(ruby.exception_arg_error(), "bad")),
+        &[ruby.exception_arg_error()],
+        |e| e.message(),
+    );
+    assert_eq!(res.unwrap(), "bad");
+}
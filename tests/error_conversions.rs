@@ -0,0 +1,63 @@
+use std::io;
+
+use magnus::{embed::init, error::OpaqueError, function, rb_assert, Error};
+
+fn read(path: String) -> Result<String, Error> {
+    Ok(std::fs::read_to_string(path)?)
+}
+
+fn custom_io() -> Result<(), Error> {
+    Err(io::Error::new(io::ErrorKind::Other, "disk on fire").into())
+}
+
+fn from_utf8(bytes: Vec<u8>) -> Result<String, Error> {
+    Ok(std::str::from_utf8(&bytes)?.to_owned())
+}
+
+fn parse(s: String) -> Result<i64, Error> {
+    Ok(s.parse()?)
+}
+
+fn to_u8(i: i64) -> Result<u8, Error> {
+    Ok(u8::try_from(i)?)
+}
+
+fn assert_send_sync_error<T: std::error::Error + Send + Sync + 'static>(_: &T) {}
+
+#[test]
+fn it_converts_std_errors() {
+    let ruby = unsafe { init() };
+
+    ruby.define_global_function("read", function!(read, 1));
+    ruby.define_global_function("custom_io", function!(custom_io, 0));
+    ruby.define_global_function("from_utf8", function!(from_utf8, 1));
+    ruby.define_global_function("parse", function!(parse, 1));
+    ruby.define_global_function("to_u8", function!(to_u8, 1));
+
+    rb_assert!(
+        ruby,
+        r#"(read("/does/not/exist") rescue $!).is_a?(Errno::ENOENT)"#
+    );
+    rb_assert!(
+        ruby,
+        r#"(read("/does/not/exist") rescue $!).errno == Errno::ENOENT::Errno"#
+    );
+    rb_assert!(ruby, "(custom_io rescue $!).is_a?(IOError)");
+    rb_assert!(ruby, r#"(custom_io rescue $!).message == "disk on fire""#);
+    rb_assert!(ruby, r#"from_utf8([104, 105]) == "hi""#);
+    rb_assert!(ruby, "(from_utf8([255]) rescue $!).is_a?(EncodingError)");
+    rb_assert!(ruby, r#"parse("42") == 42"#);
+    rb_assert!(ruby, r#"(parse("4x2") rescue $!).is_a?(ArgumentError)"#);
+    rb_assert!(ruby, "to_u8(255) == 255");
+    rb_assert!(ruby, "(to_u8(256) rescue $!).is_a?(RangeError)");
+
+    let err = Error::new(ruby.exception_runtime_error(), "boom");
+    let boxed: Box<dyn std::error::Error> = Box::new(err);
+    assert_eq!(boxed.to_string(), "RuntimeError: boom");
+
+    let opaque = OpaqueError::from(Error::new(ruby.exception_arg_error(), "bad"));
+    assert_send_sync_error(&opaque);
+    assert_eq!(opaque.to_string(), "ArgumentError: bad");
+    let err = OpaqueError::into_error_with(opaque, &ruby);
+    assert!(err.is_kind_of(ruby.exception_arg_error()));
+}
@@ -0,0 +1,118 @@
+use magnus::{embed::init, function, method, prelude::*, rb_assert, Error, Exception, Ruby, Value};
+
+#[magnus::wrap(class = "Grammar::SyntaxErrorData", free_immediately, size)]
+struct SyntaxErrorData {
+    line: usize,
+    column: usize,
+    expected: Vec<String>,
+}
+
+fn line(rb_self: Exception) -> Option<usize> {
+    rb_self.data::<SyntaxErrorData>().map(|d| d.line)
+}
+
+fn column(rb_self: Exception) -> Option<usize> {
+    rb_self.data::<SyntaxErrorData>().map(|d| d.column)
+}
+
+fn expected(rb_self: Exception) -> Option<Vec<String>> {
+    rb_self
+        .data::<SyntaxErrorData>()
+        .map(|d| d.expected.clone())
+}
+
+#[magnus::wrap(class = "Grammar::Other")]
+struct Other;
+
+fn parse(ruby: &Ruby, src: String) -> Result<usize, Error> {
+    if src.starts_with('(') {
+        return Ok(src.len());
+    }
+    let class = ruby
+        .class_object()
+        .funcall("const_get", ("Grammar::SyntaxError",))?;
+    Err(Error::new_with_data(
+        class,
+        format!("unexpected {:?}", src),
+        SyntaxErrorData {
+            line: 1,
+            column: 1,
+            expected: vec![String::from("(")],
+        },
+    ))
+}
+
+#[test]
+fn it_raises_exceptions_wrapping_rust_data() {
+    let ruby = unsafe { init() };
+
+    let module = ruby.define_module("Grammar").unwrap();
+    module
+        .define_class("SyntaxErrorData", ruby.class_object())
+        .unwrap();
+    let class = module
+        .define_error("SyntaxError", ruby.exception_standard_error())
+        .unwrap();
+    class.define_method("line", method!(line, 0)).unwrap();
+    class.define_method("column", method!(column, 0)).unwrap();
+    class
+        .define_method("expected", method!(expected, 0))
+        .unwrap();
+    module.define_class("Other", ruby.class_object()).unwrap();
+    ruby.define_global_function("parse", function!(parse, 1));
+
+    rb_assert!(ruby, r#"parse("()") == 2"#);
+    rb_assert!(
+        ruby,
+        r#"
+        begin
+          parse("x")
+        rescue Grammar::SyntaxError => e
+          e.message == 'unexpected "x"' &&
+            e.line == 1 && e.column == 1 && e.expected == ["("] &&
+            e.backtrace.is_a?(Array)
+        end
+        "#
+    );
+
+    // the exception class is still usable from Ruby
+    rb_assert!(
+        ruby,
+        r#"
+        begin
+          raise Grammar::SyntaxError, "from ruby"
+        rescue Grammar::SyntaxError => e
+          e.message == "from ruby" && e.line.nil?
+        end
+        "#
+    );
+    rb_assert!(ruby, "Grammar::SyntaxError.new.line.nil?");
+
+    // re-raising with a new message keeps the data
+    rb_assert!(
+        ruby,
+        r#"
+        begin
+          begin
+            parse("z")
+          rescue Grammar::SyntaxError => e
+            raise e.exception("while loading")
+          end
+        rescue Grammar::SyntaxError => e
+          e.message == "while loading" && e.line == 1 && e.expected == ["("]
+        end
+        "#
+    );
+
+    let err = ruby.eval::<Value>(r#"parse("y")"#).unwrap_err();
+    assert!(err.is_kind_of(class));
+    let data = err.data::<SyntaxErrorData>().unwrap();
+    assert_eq!((data.line, data.column), (1, 1));
+    assert_eq!(data.expected, ["("]);
+    assert!(err.data::<Other>().is_none());
+
+    let err = Error::new_with_data(ruby.exception_runtime_error(), "nope", Other);
+    assert!(err.is_kind_of(ruby.exception_runtime_error()));
+    assert!(err.data::<Other>().is_some());
+    assert!(err.data::<SyntaxErrorData>().is_none());
+}
@@ -0,0 +1,64 @@
+use magnus::{
+    embed::init, exception::BacktraceOrder, function, rb_assert, Error, Exception, Ruby, Value,
+};
+
+fn load_config(ruby: &Ruby, src: String) -> Result<i64, Error> {
+    ruby.eval(&format!("Integer({:?})", src))
+        .map_err(|e| Error::new(ruby.exception_runtime_error(), "invalid config").with_cause(e))
+}
+
+#[test]
+fn it_chains_exception_causes() {
+    let ruby = unsafe { init() };
+
+    ruby.define_global_function("load_config", function!(load_config, 1));
+    rb_assert!(ruby, r#"load_config("42") == 42"#);
+
+    let e: Exception = ruby
+        .eval(r#"begin; load_config("x"); rescue => e; e; end"#)
+        .unwrap();
+    assert!(e.is_kind_of(ruby.exception_runtime_error()));
+    assert_eq!(e.message().unwrap(), "invalid config");
+    let cause = e.cause().unwrap().unwrap();
+    assert!(cause.is_kind_of(ruby.exception_arg_error()));
+    assert!(cause.cause().unwrap().is_none());
+
+    e.set_backtrace(["config.rs:10:in `load'", "main.rs:3:in `main'"])
+        .unwrap();
+    rb_assert!(
+        ruby,
+        r#"e.backtrace == ["config.rs:10:in `load'", "main.rs:3:in `main'"]"#,
+        e
+    );
+
+    let top = e.full_message(false, BacktraceOrder::Top).unwrap();
+    assert!(top.starts_with("config.rs:10:in `load': invalid config (RuntimeError)"));
+    assert!(top.contains("main.rs:3:in `main'"));
+    assert!(!top.contains('\u{1b}'));
+    let bottom = e.full_message(false, BacktraceOrder::Bottom).unwrap();
+    assert!(bottom.contains("config.rs:10:in `load': invalid config (RuntimeError)"));
+    assert!(bottom.find("main.rs").unwrap() < bottom.find("config.rs").unwrap());
+    assert!(e
+        .full_message(true, BacktraceOrder::Top)
+        .unwrap()
+        .contains('\u{1b}'));
+
+    let err = ruby
+        .eval::<Value>("[].fetch(1)")
+        .unwrap_err()
+        .with_cause(Error::new(ruby.exception_type_error(), "inner"));
+    let ex = err.value().unwrap();
+    rb_assert!(ruby, "ex.is_a?(IndexError)", ex);
+    rb_assert!(ruby, r#"ex.cause.message == "inner""#, ex);
+
+    // the cause is set without raising, so there is no backtrace yet
+    let err = Error::new(ruby.exception_runtime_error(), "outer")
+        .with_cause(Error::new(ruby.exception_type_error(), "inner"));
+    let ex = err.value().unwrap();
+    rb_assert!(ruby, "ex.backtrace.nil?", ex);
+    rb_assert!(
+        ruby,
+        r#"(begin; raise ex; rescue => e; e; end).cause.message == "inner""#,
+        ex
+    );
+}
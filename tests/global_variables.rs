@@ -0,0 +1,53 @@
+use std::sync::{
+    atomic::{AtomicI64, Ordering},
+    Arc,
+};
+
+use magnus::{embed::init, rb_assert, Error, Ruby};
+
+#[test]
+fn it_defines_global_variables_backed_by_rust() {
+    let ruby = unsafe { init() };
+
+    ruby.define_readonly_variable("gem_version", "1.2.3")
+        .unwrap();
+    rb_assert!(ruby, r#"$gem_version == "1.2.3""#);
+    rb_assert!(ruby, "($gem_version = 1 rescue $!).is_a?(NameError)");
+
+    let level = Arc::new(AtomicI64::new(0));
+    let get_level = level.clone();
+    let set_level = level.clone();
+    ruby.define_virtual_variable(
+        "$gem_debug",
+        move |_: &Ruby| Ok(get_level.load(Ordering::SeqCst)),
+        move |ruby: &Ruby, val: i64| {
+            if val < 0 {
+                return Err(Error::new(
+                    ruby.exception_arg_error(),
+                    "debug level can't be negative",
+                ));
+            }
+            set_level.store(val, Ordering::SeqCst);
+            Ok(())
+        },
+    )
+    .unwrap();
+
+    rb_assert!(ruby, "$gem_debug == 0");
+    rb_assert!(ruby, "($gem_debug = 2) == 2");
+    assert_eq!(level.load(Ordering::SeqCst), 2);
+    rb_assert!(ruby, "$gem_debug == 2");
+    rb_assert!(ruby, "($gem_debug = -1 rescue $!).is_a?(ArgumentError)");
+    rb_assert!(ruby, r#"($gem_debug = "3" rescue $!).is_a?(TypeError)"#);
+    assert_eq!(level.load(Ordering::SeqCst), 2);
+
+    ruby.define_readonly_virtual_variable("gem_calls", |ruby: &Ruby| {
+        Err::<i64, _>(Error::new(ruby.exception_runtime_error(), "unavailable"))
+    })
+    .unwrap();
+    rb_assert!(ruby, r#"($gem_calls rescue $!).message == "unavailable""#);
+    rb_assert!(
+        ruby,
+        r#"($gem_calls = 1 rescue $!).message == "$gem_calls is a read-only variable""#
+    );
+}
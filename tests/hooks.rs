@@ -0,0 +1,78 @@
+use magnus::{embed::init, prelude::*, rb_assert, value::Opaque, RClass, Ruby, Symbol, Value};
+
+#[test]
+fn it_registers_lifecycle_hooks() {
+    let ruby = unsafe { init() };
+
+    let _: Value = ruby
+        .eval(
+            r#"
+            module Dsl
+              def self.included(base)
+                base.instance_variable_set(:@ruby_hook, true)
+              end
+            end
+            "#,
+        )
+        .unwrap();
+    let dsl = ruby
+        .class_object()
+        .const_get::<_, magnus::RModule>("Dsl")
+        .unwrap();
+    dsl.on_included(|_ruby: &Ruby, target: Value| {
+        let _: Value = target.funcall("instance_variable_set", ("@rust_hook", 1))?;
+        Ok(())
+    })
+    .unwrap();
+    dsl.on_included(|_ruby: &Ruby, target: Value| {
+        let _: Value = target.funcall("instance_variable_set", ("@rust_hook", 2))?;
+        Ok(())
+    })
+    .unwrap();
+    rb_assert!(
+        ruby,
+        "c = Class.new { include Dsl }; c.instance_variable_get(:@ruby_hook) && c.instance_variable_get(:@rust_hook) == 2"
+    );
+    rb_assert!(ruby, "!Dsl.respond_to?(:included)");
+
+    dsl.on_extended(|_ruby: &Ruby, obj: Value| {
+        let _: Value = obj.funcall("instance_variable_set", ("@extended", true))?;
+        Ok(())
+    })
+    .unwrap();
+    rb_assert!(
+        ruby,
+        "Object.new.extend(Dsl).instance_variable_get(:@extended)"
+    );
+
+    let base = ruby.define_class("HookBase", ruby.class_object()).unwrap();
+    let registry = Opaque::from(ruby.ary_new());
+    base.const_set("REGISTRY", registry).unwrap();
+    base.on_inherited(move |ruby: &Ruby, subclass: RClass| ruby.get_inner(registry).push(subclass))
+        .unwrap();
+    rb_assert!(
+        ruby,
+        "class HookA < HookBase; end; class HookB < HookA; end; HookBase::REGISTRY == [HookA, HookB]"
+    );
+
+    let added = Opaque::from(ruby.ary_new());
+    base.const_set("ADDED", added).unwrap();
+    base.on_method_added(move |ruby: &Ruby, name: Symbol| ruby.get_inner(added).push(name))
+        .unwrap();
+    rb_assert!(
+        ruby,
+        "class HookBase; def foo; end; end; HookBase::ADDED == [:foo]"
+    );
+
+    // hooks defined earlier, in Ruby or Rust, are still called through the
+    // chain
+    dsl.on_included(|_ruby: &Ruby, target: RClass| {
+        let _: Value = target.funcall("instance_variable_set", ("@class_hook", true))?;
+        Ok(())
+    })
+    .unwrap();
+    rb_assert!(
+        ruby,
+        "GC.start; c = Class.new { include Dsl }; c.instance_variable_get(:@ruby_hook) && c.instance_variable_get(:@rust_hook) == 2 && c.instance_variable_get(:@class_hook)"
+    );
+}
@@ -0,0 +1,70 @@
+use magnus::{embed::init, prelude::*, r_hash::ForEach, Error, RObject};
+
+#[test]
+fn it_reflects_on_instance_variables() {
+    let ruby = unsafe { init() };
+
+    let obj: RObject = ruby
+        .eval(
+            r#"
+            class Point
+              def initialize(x, y)
+                @x = x
+                @y = y
+              end
+            end
+            Point.new(1, 2)
+            "#,
+        )
+        .unwrap();
+    obj.ivar_set("hidden", 3).unwrap();
+
+    let names = obj
+        .instance_variables()
+        .unwrap()
+        .into_iter()
+        .map(|s| s.name())
+        .collect::<Result<Vec<_>, Error>>()
+        .unwrap();
+    assert_eq!(names, ["@x", "@y"]);
+
+    assert!(obj.ivar_defined("@x"));
+    assert!(!obj.ivar_defined("@z"));
+
+    let mut seen = Vec::new();
+    obj.ivar_foreach(|name, value: i64| {
+        seen.push((name.name()?, value));
+        Ok(ForEach::Continue)
+    })
+    .unwrap();
+    assert_eq!(seen, [("@x", 1), ("@y", 2)]);
+
+    let mut count = 0;
+    obj.ivar_foreach(|_, _: i64| {
+        count += 1;
+        Ok(ForEach::Stop)
+    })
+    .unwrap();
+    assert_eq!(count, 1);
+
+    let err = obj
+        .ivar_foreach(|_, _: String| Ok(ForEach::Continue))
+        .unwrap_err();
+    assert!(err.is_kind_of(ruby.exception_type_error()));
+    let err = obj
+        .ivar_foreach(|_, _: i64| Ok(ForEach::Delete))
+        .unwrap_err();
+    assert!(err.is_kind_of(ruby.exception_arg_error()));
+
+    assert_eq!(obj.remove_ivar::<_, i64>("@y").unwrap(), 2);
+    assert!(!obj.ivar_defined("@y"));
+    let err = obj.remove_ivar::<_, i64>("@y").unwrap_err();
+    assert!(err.is_kind_of(ruby.exception_name_error()));
+
+    obj.freeze();
+    let err = obj.remove_ivar::<_, i64>("@x").unwrap_err();
+    assert!(err.is_kind_of(ruby.exception_frozen_error()));
+    let err = obj.ivar_set("@x", 4).unwrap_err();
+    assert!(err.is_kind_of(ruby.exception_frozen_error()));
+    assert!(obj.ivar_defined("@x"));
+}
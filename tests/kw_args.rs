@@ -0,0 +1,30 @@
+use magnus::{block::Proc, embed::init, prelude::*, KwSplat, RClass, Value};
+
+#[test]
+fn it_passes_keyword_arguments() {
+    let ruby = unsafe { init() };
+
+    let kwargs = ruby.hash_new();
+    kwargs.aset(ruby.to_symbol("b"), 2).unwrap();
+
+    let obj: Value = ruby
+        .eval("o = Object.new; def o.test(a, b: 0); [a, b]; end; o")
+        .unwrap();
+    let res: (i64, i64) = obj.funcall("test", (1, KwSplat(kwargs))).unwrap();
+    assert_eq!(res, (1, 2));
+    let res: (i64, i64) = obj
+        .method("test")
+        .unwrap()
+        .call((1, KwSplat(kwargs)))
+        .unwrap();
+    assert_eq!(res, (1, 2));
+
+    let class: RClass = ruby
+        .eval("Class.new { attr_reader :b; def initialize(b:); @b = b; end }")
+        .unwrap();
+    let instance = class.new_instance((KwSplat(kwargs),)).unwrap();
+    assert_eq!(instance.funcall::<_, _, i64>("b", ()).unwrap(), 2);
+
+    let proc: Proc = ruby.eval("proc { |a, b: 0| a + b }").unwrap();
+    assert_eq!(proc.call::<_, i64>((1, KwSplat(kwargs))).unwrap(), 3);
+}
@@ -0,0 +1,76 @@
+use magnus::{
+    embed::init, function, method, prelude::*, rb_assert, scan_args::KwArgs, FromKwArgs, RHash,
+};
+
+#[derive(FromKwArgs)]
+struct Options {
+    width: usize,
+    fill: Option<char>,
+    #[magnus(name = "left", default)]
+    align_left: bool,
+    #[magnus(splat)]
+    rest: RHash,
+}
+
+fn pad(rb_self: String, opts: KwArgs<Options>) -> String {
+    let fill = opts.fill.unwrap_or(' ').to_string();
+    let padding = fill.repeat(opts.width.saturating_sub(rb_self.len()));
+    if opts.align_left {
+        format!("{}{}", rb_self, padding)
+    } else {
+        format!("{}{}", padding, rb_self)
+    }
+}
+
+#[derive(FromKwArgs)]
+struct Strict {
+    a: i64,
+    b: Option<i64>,
+}
+
+fn sum(x: i64, kw: KwArgs<Strict>) -> i64 {
+    x + kw.a + kw.b.unwrap_or(0)
+}
+
+fn rest_len(opts: KwArgs<Options>) -> usize {
+    opts.rest.len()
+}
+
+fn range(a: i64, b: Option<i64>, kw: KwArgs<Strict>) -> i64 {
+    a + b.unwrap_or(0) + kw.a
+}
+
+#[test]
+fn it_converts_kwargs() {
+    let ruby = unsafe { init() };
+
+    ruby.class_string()
+        .define_method("pad", method!(pad, 0))
+        .unwrap();
+    ruby.define_global_function("sum", function!(sum, 1));
+    ruby.define_global_function("rest_len", function!(rest_len, 0));
+    ruby.define_global_function("range", function!(range, 1..=2));
+
+    rb_assert!(ruby, r#""a".pad(width: 3) == "  a""#);
+    rb_assert!(ruby, r#""a".pad(width: 3, fill: "-", left: true) == "a--""#);
+    rb_assert!(ruby, "rest_len(width: 1, x: 1, y: 2) == 2");
+
+    rb_assert!(ruby, "method(:sum).arity == -1");
+    rb_assert!(ruby, "sum(1, a: 2) == 3");
+    rb_assert!(ruby, "sum(1, a: 2, b: 3) == 6");
+    rb_assert!(
+        ruby,
+        r#"(sum(1) rescue $!).message == "missing keyword: :a""#
+    );
+    rb_assert!(
+        ruby,
+        r#"(sum(1, a: 1, c: 2) rescue $!).message == "unknown keyword: :c""#
+    );
+    rb_assert!(
+        ruby,
+        r#"(sum(a: 1) rescue $!).message == "wrong number of arguments (given 0, expected 1)""#
+    );
+
+    rb_assert!(ruby, "range(1, a: 2) == 3");
+    rb_assert!(ruby, "range(1, 2, a: 3) == 6");
+}
@@ -0,0 +1,55 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use magnus::{embed::init, prelude::*, rb_assert, Error, Ruby};
+
+#[test]
+fn it_defines_lazy_constants() {
+    let ruby = unsafe { init() };
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = calls.clone();
+
+    let module = ruby.define_module("Grammar").unwrap();
+    module
+        .define_lazy_const("RULES", move |ruby: &Ruby| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            Ok(ruby.ary_from_vec(vec!["expr", "term", "factor"]))
+        })
+        .unwrap();
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let attempt = attempts.clone();
+    module
+        .define_lazy_const("FLAKY", move |ruby: &Ruby| {
+            if attempt.fetch_add(1, Ordering::SeqCst) == 0 {
+                Err(Error::new(ruby.exception_runtime_error(), "failed"))
+            } else {
+                Ok(42)
+            }
+        })
+        .unwrap();
+
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+    assert!(!module.const_defined("RULES"));
+
+    rb_assert!(ruby, r#"Grammar::RULES.first == "expr""#);
+    rb_assert!(ruby, "module Grammar; RULES.length == 3; end");
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert!(module.const_defined("RULES"));
+
+    rb_assert!(ruby, r#"(Grammar::FLAKY rescue $!).message == "failed""#);
+    assert!(!module.const_defined("FLAKY"));
+    rb_assert!(ruby, "Grammar::FLAKY == 42");
+    rb_assert!(ruby, "Grammar::FLAKY == 42");
+    assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    rb_assert!(ruby, "(Grammar::MISSING rescue $!).is_a?(NameError)");
+
+    module.autoload("Parser", "grammar/parser").unwrap();
+    assert_eq!(
+        module.autoload_path("Parser").unwrap().as_deref(),
+        Some("grammar/parser")
+    );
+    assert!(module.autoload_path("RULES").unwrap().is_none());
+}
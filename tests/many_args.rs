@@ -0,0 +1,99 @@
+use magnus::{embed::init, function, rb_assert};
+
+#[allow(clippy::too_many_arguments)]
+fn sum(
+    a: i64,
+    b: i64,
+    c: i64,
+    d: i64,
+    e: i64,
+    f: i64,
+    g: i64,
+    h: i64,
+    i: i64,
+    j: i64,
+    k: i64,
+    l: i64,
+    m: i64,
+    n: i64,
+    o: i64,
+    p: i64,
+    q: i64,
+    r: i64,
+    s: i64,
+    t: i64,
+) -> i64 {
+    a + b + c + d + e + f + g + h + i + j + k + l + m + n + o + p + q + r + s + t
+}
+
+#[allow(clippy::type_complexity)]
+fn reverse(
+    ary: (
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+    ),
+) -> (
+    i64,
+    i64,
+    i64,
+    i64,
+    i64,
+    i64,
+    i64,
+    i64,
+    i64,
+    i64,
+    i64,
+    i64,
+    i64,
+    i64,
+) {
+    let (a, b, c, d, e, f, g, h, i, j, k, l, m, n) = ary;
+    (n, m, l, k, j, i, h, g, f, e, d, c, b, a)
+}
+
+#[test]
+fn it_supports_many_args() {
+    let ruby = unsafe { init() };
+
+    ruby.define_global_function("sum", function!(sum, 20));
+    ruby.define_global_function("reverse", function!(reverse, 1));
+
+    rb_assert!(ruby, "sum(*(1..20)) == 210");
+    rb_assert!(ruby, "method(:sum).arity == -1");
+    rb_assert!(
+        ruby,
+        r#"(sum(1, 2) rescue $!).message == "wrong number of arguments (given 2, expected 20)""#
+    );
+    rb_assert!(ruby, "reverse((1..14).to_a) == (1..14).to_a.reverse");
+
+    let res: (
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+        i64,
+    ) = ruby.eval("(1..14).to_a").unwrap();
+    assert_eq!(res.13, 14);
+}
@@ -0,0 +1,46 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use magnus::{embed::init, prelude::*, rb_assert, Error, RString, Ruby};
+
+#[test]
+fn it_defines_methods_from_closures() {
+    let ruby = unsafe { init() };
+
+    let class = ruby.define_class("Greeter", ruby.class_object()).unwrap();
+
+    let greeting = String::from("Hello");
+    class
+        .define_method_fn("greet", move |_rb_self: magnus::Value, name: String| {
+            format!("{}, {}!", greeting, name)
+        })
+        .unwrap();
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = calls.clone();
+    class
+        .define_method_fn("count", move |ruby: &Ruby, _rb_self: magnus::Value| {
+            ruby.integer_from_u64(counter.fetch_add(1, Ordering::SeqCst) as u64 + 1)
+        })
+        .unwrap();
+
+    ruby.class_string()
+        .define_method_fn("shout", |s: RString| -> Result<String, Error> {
+            Ok(s.to_string()?.to_uppercase())
+        })
+        .unwrap();
+
+    // make sure the closures survive a GC
+    ruby.gc_start();
+
+    rb_assert!(ruby, r#"Greeter.new.greet("Ruby") == "Hello, Ruby!""#);
+    rb_assert!(ruby, "g = Greeter.new; g.count; g.count == 2");
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    rb_assert!(ruby, r#""abc".shout == "ABC""#);
+    rb_assert!(
+        ruby,
+        r#"(Greeter.new.greet rescue $!).message == "wrong number of arguments (given 0, expected 1)""#
+    );
+}
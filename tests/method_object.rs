@@ -0,0 +1,68 @@
+use magnus::{embed::init, prelude::*, rb_assert, KwSplat, RClass, RMethod, RUnboundMethod, Value};
+
+#[test]
+fn it_wraps_method_objects() {
+    let ruby = unsafe { init() };
+
+    let obj: Value = ruby
+        .eval(
+            r#"
+            o = Object.new
+            def o.add(a, b = 2, *rest, key:)
+              a + b + key
+            end
+            o
+            "#,
+        )
+        .unwrap();
+
+    let method = obj.method("add").unwrap();
+    let kwargs = ruby.hash_new();
+    kwargs.aset(ruby.to_symbol("key"), 3).unwrap();
+    let res: i64 = method.call((1, 2, KwSplat(kwargs))).unwrap();
+    assert_eq!(res, 6);
+    rb_assert!(
+        ruby,
+        "owner == obj.singleton_class",
+        owner = method.owner().unwrap(),
+        obj
+    );
+    assert_eq!(method.arity().unwrap(), -2);
+    assert_eq!(method.name().unwrap().name().unwrap(), "add");
+    assert!(method.receiver().unwrap().equal(obj).unwrap());
+    rb_assert!(
+        ruby,
+        "params == [[:req, :a], [:opt, :b], [:rest, :rest], [:keyreq, :key]]",
+        params = method.parameters().unwrap()
+    );
+    let (_file, line) = method.source_location().unwrap().unwrap();
+    assert_eq!(line, 3);
+
+    assert!(obj.method("missing").is_err());
+    assert!(RMethod::from_value(obj).is_none());
+
+    let class: RClass = ruby
+        .eval("Class.new { def initialize(v); @v = v; end; def value; @v; end }")
+        .unwrap();
+    let unbound = class.instance_method("value").unwrap();
+    assert_eq!(unbound.arity().unwrap(), 0);
+    assert!(unbound.source_location().unwrap().is_some());
+    rb_assert!(
+        ruby,
+        "owner == class",
+        owner = unbound.owner().unwrap(),
+        class
+    );
+
+    let instance: Value = class.new_instance((42,)).unwrap();
+    let bound = unbound.bind(instance).unwrap();
+    let value: i64 = bound.call(()).unwrap();
+    assert_eq!(value, 42);
+    assert!(unbound.bind(ruby.str_new("nope")).is_err());
+
+    let rebound: RUnboundMethod = bound.unbind().unwrap();
+    assert!(RUnboundMethod::from_value(rebound.as_value()).is_some());
+    assert!(RMethod::from_value(rebound.as_value()).is_none());
+
+    assert!(ruby.class_string().instance_method("missing").is_err());
+}
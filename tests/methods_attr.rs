@@ -0,0 +1,69 @@
+use magnus::{embed::init, rb_assert, Error, Ruby, Value};
+
+#[magnus::wrap(class = "Point")]
+struct Point {
+    x: isize,
+    y: isize,
+}
+
+#[magnus::methods]
+impl Point {
+    fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    fn origin() -> Self {
+        Self { x: 0, y: 0 }
+    }
+
+    fn from_args(_ruby: &Ruby, args: &[Value]) -> Result<Self, Error> {
+        let args = magnus::scan_args::scan_args::<(isize, isize), (), (), (), (), ()>(args)?;
+        let (x, y) = args.required;
+        Ok(Self { x, y })
+    }
+
+    fn x(&self) -> isize {
+        self.x
+    }
+
+    fn y(&self) -> isize {
+        self.y
+    }
+
+    #[magnus(name = "==")]
+    fn eq(&self, other: &Point) -> bool {
+        self.x == other.x && self.y == other.y
+    }
+
+    #[magnus(singleton)]
+    fn unit() -> Self {
+        Self { x: 1, y: 1 }
+    }
+
+    #[magnus(private)]
+    fn secret(&self) -> isize {
+        self.x * self.y
+    }
+
+    #[magnus(skip)]
+    #[allow(dead_code)]
+    fn helper(&self) {}
+}
+
+#[test]
+fn it_defines_methods_from_impl() {
+    let ruby = unsafe { init() };
+
+    let class = ruby.define_class("Point", ruby.class_object()).unwrap();
+    Point::define_methods(&class).unwrap();
+
+    rb_assert!(ruby, "Point.new(1, 2).x == 1");
+    rb_assert!(ruby, "Point.new(1, 2).y == 2");
+    rb_assert!(ruby, "Point.origin == Point.new(0, 0)");
+    rb_assert!(ruby, "Point.from_args(3, 4) == Point.new(3, 4)");
+    rb_assert!(ruby, "Point.unit == Point.new(1, 1)");
+    rb_assert!(ruby, "!Point.method_defined?(:origin)");
+    rb_assert!(ruby, "Point.private_method_defined?(:secret)");
+    rb_assert!(ruby, "Point.new(2, 3).send(:secret) == 6");
+    rb_assert!(ruby, "!Point.method_defined?(:helper)");
+}
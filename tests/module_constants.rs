@@ -0,0 +1,57 @@
+use magnus::{embed::init, prelude::*, rb_assert, RModule};
+
+#[test]
+fn it_reflects_over_constants_and_class_variables() {
+    let ruby = unsafe { init() };
+
+    let module: RModule = ruby
+        .eval(
+            r#"
+            module Config
+              HOST = "localhost"
+              PORT = 8080
+            end
+            Config
+            "#,
+        )
+        .unwrap();
+
+    assert!(module.const_defined("HOST"));
+    assert!(!module.const_defined("MISSING"));
+
+    let names = module
+        .constants(false)
+        .unwrap()
+        .into_iter()
+        .map(|sym| sym.name().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["HOST", "PORT"]);
+
+    #[cfg(ruby_gte_2_7)]
+    {
+        let (_file, line) = module.const_source_location("PORT").unwrap().unwrap();
+        assert_eq!(line, 4);
+        assert!(module.const_source_location("MISSING").unwrap().is_none());
+    }
+
+    module.private_constant("PORT").unwrap();
+    rb_assert!(ruby, "(Config::PORT rescue $!).is_a?(NameError)");
+    module.deprecate_constant("HOST").unwrap();
+
+    let port: u16 = module.remove_const("PORT").unwrap();
+    assert_eq!(port, 8080);
+    assert!(!module.const_defined("PORT"));
+    assert!(module.remove_const::<_, u16>("PORT").is_err());
+
+    let class = ruby.define_class("Settings", ruby.class_object()).unwrap();
+    assert!(!class.cvar_defined("@@debug"));
+    assert!(class.cvar_get::<_, bool>("@@debug").is_err());
+    class.cvar_set("@@debug", true).unwrap();
+    assert!(class.cvar_defined("@@debug"));
+    assert!(class.cvar_get::<_, bool>("@@debug").unwrap());
+    assert_eq!(
+        class.class_variables().unwrap()[0].name().unwrap(),
+        "@@debug"
+    );
+    rb_assert!(ruby, "Settings.class_variable_get(:@@debug) == true");
+}
@@ -0,0 +1,89 @@
+use magnus::{embed::init, function, method, prelude::*, rb_assert, RArray, Ruby, TryConvert};
+
+fn search(rb_self: RArray, q: i64, limit: Option<usize>) -> Result<RArray, magnus::Error> {
+    let limit = limit.unwrap_or(usize::MAX);
+    let ruby = Ruby::get_with(rb_self);
+    let res = ruby.ary_new();
+    for v in rb_self.each() {
+        let v = v?;
+        if res.len() < limit && i64::try_convert(v)? == q {
+            res.push(v)?;
+        }
+    }
+    Ok(res)
+}
+
+fn greet(greeting: Option<String>, name: Option<String>) -> String {
+    format!(
+        "{}, {}!",
+        greeting.as_deref().unwrap_or("Hello"),
+        name.as_deref().unwrap_or("world")
+    )
+}
+
+#[magnus::wrap(class = "Counter")]
+struct Counter {
+    start: i64,
+}
+
+#[magnus::methods]
+impl Counter {
+    #[magnus(optional)]
+    fn new(start: Option<i64>) -> Self {
+        Self {
+            start: start.unwrap_or(0),
+        }
+    }
+
+    #[magnus(optional)]
+    fn add(&self, a: i64, b: Option<i64>) -> i64 {
+        self.start + a + b.unwrap_or(0)
+    }
+
+    fn sub(&self, a: i64, b: Option<i64>) -> i64 {
+        self.start - a - b.unwrap_or(0)
+    }
+}
+
+#[test]
+fn it_allows_optional_trailing_args() {
+    let ruby = unsafe { init() };
+
+    ruby.class_array()
+        .define_method("search", method!(search, 1..=2))
+        .unwrap();
+    ruby.define_global_function("greet", function!(greet, 0..=2));
+
+    rb_assert!(ruby, "[1, 2, 1, 1].search(1) == [1, 1, 1]");
+    rb_assert!(ruby, "[1, 2, 1, 1].search(1, 2) == [1, 1]");
+    rb_assert!(ruby, "[].method(:search).arity == -1");
+    rb_assert!(
+        ruby,
+        r#"([].search rescue $!).message == "wrong number of arguments (given 0, expected 1..2)""#
+    );
+    rb_assert!(
+        ruby,
+        r#"([].search(1, 2, 3) rescue $!).message == "wrong number of arguments (given 3, expected 1..2)""#
+    );
+
+    rb_assert!(ruby, r#"greet == "Hello, world!""#);
+    rb_assert!(ruby, r#"greet("Hi") == "Hi, world!""#);
+    rb_assert!(ruby, r#"greet("Hi", "Ruby") == "Hi, Ruby!""#);
+
+    let class = ruby.define_class("Counter", ruby.class_object()).unwrap();
+    Counter::define_methods(&class).unwrap();
+
+    rb_assert!(ruby, "Counter.new.add(1) == 1");
+    rb_assert!(ruby, "Counter.new(10).add(1, 2) == 13");
+    rb_assert!(
+        ruby,
+        r#"(Counter.new.add rescue $!).message == "wrong number of arguments (given 0, expected 1..2)""#
+    );
+
+    rb_assert!(ruby, "Counter.new.method(:sub).arity == 2");
+    rb_assert!(ruby, "Counter.new(10).sub(1, nil) == 9");
+    rb_assert!(
+        ruby,
+        r#"(Counter.new.sub(1) rescue $!).message == "wrong number of arguments (given 1, expected 2)""#
+    );
+}
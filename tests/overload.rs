@@ -0,0 +1,84 @@
+use magnus::{embed::init, overload, rb_assert, Error, RString, Ruby, Symbol, Value};
+
+#[magnus::wrap(class = "Path")]
+struct Path(String);
+
+impl Path {
+    fn from_string(s: String) -> Self {
+        Self(s)
+    }
+
+    fn from_parts(ruby: &Ruby, dir: String, file: String) -> Result<Self, Error> {
+        if file.is_empty() {
+            return Err(Error::new(ruby.exception_arg_error(), "empty file name"));
+        }
+        Ok(Self(format!("{}/{}", dir, file)))
+    }
+
+    fn to_s(&self) -> String {
+        self.0.clone()
+    }
+
+    fn join_str(&self, other: RString) -> Result<Self, Error> {
+        Ok(Self(format!("{}/{}", self.0, other.to_string()?)))
+    }
+
+    fn join_path(&self, other: &Path) -> Self {
+        Self(format!("{}/{}", self.0, other.0))
+    }
+}
+
+fn kind_sym(_: Symbol) -> &'static str {
+    "symbol"
+}
+
+fn kind_any(_: Value) -> &'static str {
+    "other"
+}
+
+fn small(_: i8) -> &'static str {
+    "small"
+}
+
+#[test]
+fn it_dispatches_overloads() {
+    let ruby = unsafe { init() };
+
+    let class = ruby.define_class("Path", ruby.class_object()).unwrap();
+    class
+        .define_singleton_method("new", overload!(Path::from_string, Path::from_parts))
+        .unwrap();
+    class
+        .define_method("to_s", magnus::method!(Path::to_s, 0))
+        .unwrap();
+    class
+        .define_method("join", overload!(method: Path::join_str, Path::join_path))
+        .unwrap();
+    ruby.define_global_function("kind", overload!(kind_sym, kind_any));
+    ruby.define_global_function("size", overload!(small, kind_any));
+
+    rb_assert!(ruby, r#"Path.new("a").to_s == "a""#);
+    rb_assert!(ruby, r#"Path.new("a", "b").to_s == "a/b""#);
+    rb_assert!(ruby, r#"Path.new("a").join("b").to_s == "a/b""#);
+    rb_assert!(ruby, r#"Path.new("a").join(Path.new("c")).to_s == "a/c""#);
+    rb_assert!(ruby, "Path.method(:new).arity == -1");
+    rb_assert!(
+        ruby,
+        r#"(Path.new("a", "") rescue $!).message == "empty file name""#
+    );
+    rb_assert!(
+        ruby,
+        r#"(Path.new(1) rescue $!).message == "wrong argument types (Integer), expected one of: (String), (String, String)""#
+    );
+    rb_assert!(
+        ruby,
+        r#"(Path.new("a").join(1) rescue $!).message == "wrong argument types (Integer), expected one of: (RString), (&Path)""#
+    );
+
+    rb_assert!(ruby, r#"kind(:a) == "symbol""#);
+    rb_assert!(ruby, r#"kind("a") == "other""#);
+
+    rb_assert!(ruby, r#"size(1) == "small""#);
+    rb_assert!(ruby, r#"size("a") == "other""#);
+    rb_assert!(ruby, "(size(1000) rescue $!).is_a?(RangeError)");
+}
@@ -0,0 +1,50 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use magnus::{
+    embed::init,
+    error::{PanicPolicy, PanicReport},
+    function, rb_assert,
+};
+
+static REPORTED: AtomicUsize = AtomicUsize::new(0);
+
+fn report(panic: &PanicReport) {
+    if panic.message() == "unreachable state" && panic.location().is_some() {
+        REPORTED.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+fn explode() -> i64 {
+    panic!("unreachable state")
+}
+
+#[test]
+fn it_applies_the_panic_policy() {
+    let ruby = unsafe { init() };
+
+    let policy = PanicPolicy::new()
+        .exception_class(|ruby| ruby.exception_runtime_error())
+        .hook(report);
+    #[cfg(feature = "backtrace")]
+    let policy = policy.backtrace(true);
+    ruby.set_panic_policy(policy);
+
+    ruby.define_global_function("explode", function!(explode, 0));
+
+    rb_assert!(ruby, "(explode rescue $!).is_a?(RuntimeError)");
+    rb_assert!(
+        ruby,
+        r#"(explode rescue $!).message == "unreachable state""#
+    );
+    assert_eq!(REPORTED.load(Ordering::SeqCst), 2);
+
+    // panics not converted to an exception aren't reported
+    assert!(std::panic::catch_unwind(explode).is_err());
+    assert_eq!(REPORTED.load(Ordering::SeqCst), 2);
+
+    #[cfg(feature = "backtrace")]
+    rb_assert!(
+        ruby,
+        r#"(explode rescue $!).backtrace.first.end_with?("in `panic_policy::explode'")"#
+    );
+}
@@ -0,0 +1,34 @@
+use magnus::{embed::init, method, prelude::*, rb_assert, Error, RString};
+
+fn shout(s: RString) -> Result<String, Error> {
+    Ok(s.to_string()?.to_uppercase())
+}
+
+#[test]
+fn it_defines_refinements() {
+    let ruby = unsafe { init() };
+
+    let module = ruby.define_module("Shouting").unwrap();
+    let refinement = module.refine(ruby.class_string()).unwrap();
+    refinement
+        .define_method("shout", method!(shout, 0))
+        .unwrap();
+    assert!(module
+        .refine(ruby.class_string())
+        .unwrap()
+        .equal(refinement)
+        .unwrap());
+
+    // only active after `using`, and only until the end of the eval string
+    let res: (bool, String) = ruby
+        .eval(
+            r#"
+            before = ("hello".shout rescue $!).is_a?(NoMethodError)
+            using Shouting
+            [before, "hello".shout]
+            "#,
+        )
+        .unwrap();
+    assert_eq!(res, (true, String::from("HELLO")));
+    rb_assert!(ruby, r#"("hello".shout rescue $!).is_a?(NoMethodError)"#);
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "registry")]
+
+use magnus::{embed::init, rb_assert};
+
+#[magnus::wrap(class = "Geometry::Shape", register)]
+struct Shape {}
+
+#[magnus::wrap(class = "Geometry::Circle", superclass = "Geometry::Shape", register)]
+struct Circle {
+    r: f64,
+}
+
+#[magnus::methods(register)]
+impl Circle {
+    fn new(r: f64) -> Self {
+        Self { r }
+    }
+
+    fn r(&self) -> f64 {
+        self.r
+    }
+}
+
+#[magnus::register(module = "Geometry")]
+fn area(circle: &Circle) -> f64 {
+    std::f64::consts::PI * circle.r * circle.r
+}
+
+#[magnus::register(name = "geometry_version")]
+fn version() -> &'static str {
+    "1.0"
+}
+
+#[magnus::wrap(class = "Geometry::Broken", superclass = "Geometry::Missing", register)]
+struct Broken {}
+
+// class not registered or otherwise defined
+#[magnus::wrap(class = "Geometry::Square")]
+struct Square {}
+
+#[magnus::methods(register)]
+impl Square {
+    fn sides(&self) -> i64 {
+        4
+    }
+}
+
+#[test]
+fn it_defines_registered_classes() {
+    let ruby = unsafe { init() };
+
+    let err = ruby.define_registered().unwrap_err();
+    assert!(err.to_string().contains("class `Geometry::Broken`"));
+    assert!(err.to_string().contains("methods for `Square`"));
+
+    rb_assert!(ruby, "Geometry.is_a?(Module)");
+    rb_assert!(ruby, "Geometry::Circle.superclass == Geometry::Shape");
+    rb_assert!(ruby, "Geometry::Circle.new(2.0).r == 2.0");
+    rb_assert!(ruby, "!Geometry.const_defined?(:Broken)");
+    rb_assert!(ruby, "Geometry.area(Geometry::Circle.new(1.0)) == Math::PI");
+    rb_assert!(ruby, r#"geometry_version == "1.0""#);
+}
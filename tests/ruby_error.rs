@@ -0,0 +1,121 @@
+use std::fmt;
+
+use magnus::{embed::init, error::RubyError, function, prelude::*, rb_assert, Error};
+
+#[derive(Debug, magnus::RubyError)]
+#[magnus(class = "Gem::Config::Error", superclass = "ArgumentError")]
+enum ConfigError {
+    Missing {
+        key: String,
+    },
+    Invalid {
+        key: String,
+        #[magnus(attr = "lineno")]
+        line: usize,
+        #[magnus(skip)]
+        source: std::num::ParseIntError,
+    },
+    #[magnus(class = "Locked")]
+    ReadOnly,
+    Io(
+        #[magnus(skip)] std::io::Error,
+        #[magnus(attr = "path")] String,
+    ),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing { key } => write!(f, "missing key {}", key),
+            Self::Invalid { key, line, source } => {
+                write!(f, "invalid {} on line {}: {}", key, line, source)
+            }
+            Self::ReadOnly => write!(f, "config is read-only"),
+            Self::Io(e, path) => write!(f, "{}: {}", path, e),
+        }
+    }
+}
+
+#[derive(Debug, magnus::RubyError)]
+#[magnus(class = "Clash", superclass = "ArgumentError")]
+enum ClashError {
+    Boom,
+}
+
+impl fmt::Display for ClashError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "boom")
+    }
+}
+
+fn load(kind: String) -> Result<(), Error> {
+    let err = match kind.as_str() {
+        "missing" => ConfigError::Missing {
+            key: String::from("name"),
+        },
+        "invalid" => ConfigError::Invalid {
+            key: String::from("port"),
+            line: 3,
+            source: "x".parse::<u16>().unwrap_err(),
+        },
+        "read_only" => ConfigError::ReadOnly,
+        _ => ConfigError::Io(
+            std::io::Error::new(std::io::ErrorKind::Other, "unreadable"),
+            String::from("/etc/gem.conf"),
+        ),
+    };
+    Err(err.into())
+}
+
+#[test]
+fn it_maps_error_enums_to_exception_classes() {
+    let ruby = unsafe { init() };
+
+    ConfigError::define_exceptions(&ruby).unwrap();
+    rb_assert!(ruby, "Gem::Config.is_a?(Module)");
+    rb_assert!(ruby, "Gem::Config::Error.superclass == ArgumentError");
+    rb_assert!(
+        ruby,
+        "Gem::Config::Error::Missing.superclass == Gem::Config::Error"
+    );
+    rb_assert!(ruby, "Gem::Config::Error::Locked < Gem::Config::Error");
+    assert!(ConfigError::base_class(&ruby)
+        .unwrap()
+        .is_inherited(ruby.exception_arg_error()));
+
+    ruby.define_global_function("load", function!(load, 1));
+
+    rb_assert!(
+        ruby,
+        r#"(load("missing") rescue $!).is_a?(Gem::Config::Error::Missing)"#
+    );
+    rb_assert!(ruby, r#"(load("missing") rescue $!).key == "name""#);
+    rb_assert!(
+        ruby,
+        r#"(load("missing") rescue $!).message == "missing key name""#
+    );
+
+    rb_assert!(ruby, r#"(load("invalid") rescue $!).key == "port""#);
+    rb_assert!(ruby, r#"(load("invalid") rescue $!).lineno == 3"#);
+    rb_assert!(ruby, r#"!(load("invalid") rescue $!).respond_to?(:source)"#);
+
+    rb_assert!(
+        ruby,
+        r#"(load("read_only") rescue $!).is_a?(Gem::Config::Error::Locked)"#
+    );
+    rb_assert!(ruby, r#"(load("io") rescue $!).path == "/etc/gem.conf""#);
+    rb_assert!(
+        ruby,
+        r#"(load("io") rescue $!).message == "/etc/gem.conf: unreadable""#
+    );
+
+    let err = Error::from(ConfigError::ReadOnly);
+    assert!(err.is_kind_of(ConfigError::base_class(&ruby).unwrap()));
+    assert!(err.is_kind_of(ruby.exception_arg_error()));
+
+    // a class of the same name with another superclass is a TypeError
+    let _: magnus::Value = ruby.eval("class Clash < StandardError; end").unwrap();
+    let err = Error::from(ClashError::Boom);
+    assert!(err.is_kind_of(ruby.exception_type_error()));
+    assert!(ClashError::base_class(&ruby).is_err());
+}
@@ -0,0 +1,84 @@
+use magnus::{
+    block::Proc, embed::init, function, method, prelude::*, rb_assert, FromKwArgs, RArray, RHash,
+    Ruby, ScanArgs, Value,
+};
+
+#[derive(FromKwArgs)]
+struct Options {
+    sep: Option<String>,
+}
+
+#[derive(ScanArgs)]
+struct JoinArgs {
+    #[magnus(required)]
+    first: String,
+    #[magnus(optional, default = String::from("b"))]
+    second: String,
+    #[magnus(splat)]
+    rest: Vec<String>,
+    #[magnus(required)]
+    last: String,
+    #[magnus(kw)]
+    opts: Options,
+}
+
+fn join(args: JoinArgs) -> String {
+    let mut parts = vec![args.first, args.second];
+    parts.extend(args.rest);
+    parts.push(args.last);
+    parts.join(args.opts.sep.as_deref().unwrap_or(","))
+}
+
+#[derive(ScanArgs)]
+struct EachArgs {
+    #[magnus(optional)]
+    count: Option<usize>,
+    #[magnus(kw)]
+    kw: RHash,
+    #[magnus(block)]
+    block: Option<Proc>,
+}
+
+fn each(ruby: &Ruby, rb_self: RArray, args: EachArgs) -> Result<Value, magnus::Error> {
+    let count = args.count.unwrap_or_else(|| rb_self.len());
+    match args.block {
+        Some(block) => {
+            for v in rb_self.each().take(count) {
+                block.call::<_, Value>((v?,))?;
+            }
+            Ok(ruby.into_value(args.kw.len()))
+        }
+        None => Ok(ruby.qnil().as_value()),
+    }
+}
+
+#[test]
+fn it_scans_args_into_struct() {
+    let ruby = unsafe { init() };
+
+    ruby.define_global_function("join", function!(join, -1));
+    ruby.class_array()
+        .define_method("take_each", method!(each, -1))
+        .unwrap();
+
+    rb_assert!(ruby, r#"join("a", "z") == "a,b,z""#);
+    rb_assert!(ruby, r#"join("a", "c", "z") == "a,c,z""#);
+    rb_assert!(
+        ruby,
+        r#"join("a", "c", "d", "e", "z", sep: "-") == "a-c-d-e-z""#
+    );
+    rb_assert!(
+        ruby,
+        r#"(join("a") rescue $!).message == "wrong number of arguments (given 1, expected 2+)""#
+    );
+
+    rb_assert!(
+        ruby,
+        "a = []; [1, 2, 3].take_each(2, x: 1) { |v| a << v } == 1 && a == [1, 2]"
+    );
+    rb_assert!(
+        ruby,
+        "a = []; [1, 2, 3].take_each { |v| a << v }; a == [1, 2, 3]"
+    );
+    rb_assert!(ruby, "[1, 2, 3].take_each.nil?");
+}
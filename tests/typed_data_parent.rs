@@ -0,0 +1,60 @@
+use magnus::{embed::init, function, method, prelude::*, rb_assert, typed_data::Obj};
+
+#[magnus::wrap(class = "Animal")]
+struct Animal {
+    name: String,
+}
+
+impl Animal {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+#[magnus::wrap(class = "Dog", parent = "Animal")]
+#[repr(C)]
+struct Dog {
+    animal: Animal,
+    tricks: usize,
+}
+
+impl Dog {
+    fn new(name: String, tricks: usize) -> Self {
+        Self {
+            animal: Animal { name },
+            tricks,
+        }
+    }
+
+    fn tricks(&self) -> usize {
+        self.tricks
+    }
+}
+
+#[test]
+fn it_converts_child_to_parent() {
+    let ruby = unsafe { init() };
+
+    let animal = ruby.define_class("Animal", ruby.class_object()).unwrap();
+    animal
+        .define_method("name", method!(Animal::name, 0))
+        .unwrap();
+
+    let dog = ruby.define_class("Dog", animal).unwrap();
+    dog.define_singleton_method("new", function!(Dog::new, 2))
+        .unwrap();
+    dog.define_method("tricks", method!(Dog::tricks, 0))
+        .unwrap();
+
+    rb_assert!(ruby, r#"Dog.new("Rex", 3).name == "Rex""#);
+    rb_assert!(ruby, r#"Dog.new("Rex", 3).tricks == 3"#);
+
+    let rex = ruby.obj_wrap(Dog::new("Rex".to_owned(), 3));
+    let animal: Obj<Animal> = TryConvert::try_convert(rex.as_value()).unwrap();
+    assert_eq!(animal.name, "Rex");
+
+    let animal = ruby.obj_wrap(Animal {
+        name: "Felix".to_owned(),
+    });
+    assert!(<&Dog>::try_convert(animal.as_value()).is_err());
+}
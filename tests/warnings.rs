@@ -0,0 +1,69 @@
+use magnus::{embed::init, error::Category, function, rb_assert, Error, Ruby, Value};
+
+fn plain(ruby: &Ruby, msg: String) -> Result<(), Error> {
+    ruby.warn(&msg)
+}
+
+fn deprecated(ruby: &Ruby) -> Result<(), Error> {
+    ruby.warn_category(Category::Deprecated, "deprecated is deprecated")
+}
+
+fn deprecated_once(ruby: &Ruby) -> Result<(), Error> {
+    ruby.warn_once(Category::Deprecated, "deprecated_once is deprecated")
+}
+
+#[test]
+fn it_outputs_warnings() {
+    let ruby = unsafe { init() };
+
+    let _: Value = ruby
+        .eval(
+            r#"
+            $warnings = []
+            module Warning
+              def self.warn(msg, category: nil)
+                $warnings << [msg, category]
+              end
+            end
+            "#,
+        )
+        .unwrap();
+
+    ruby.define_global_function("plain", function!(plain, 1));
+    ruby.define_global_function("deprecated", function!(deprecated, 0));
+    ruby.define_global_function("deprecated_once", function!(deprecated_once, 0));
+
+    rb_assert!(
+        ruby,
+        r#"plain("100% plain"); $warnings.last[0].end_with?("warning: 100% plain\n")"#
+    );
+    rb_assert!(ruby, r#"(plain("nul\0") rescue $!).is_a?(ArgumentError)"#);
+
+    let _: bool = ruby.eval("Warning[:deprecated] = false").unwrap();
+    assert!(!ruby.warning_category_enabled(Category::Deprecated));
+    rb_assert!(ruby, "$warnings.clear; deprecated; $warnings.empty?");
+    rb_assert!(ruby, "$warnings.clear; deprecated_once; $warnings.empty?");
+
+    let _: bool = ruby.eval("Warning[:deprecated] = true").unwrap();
+    assert!(ruby.warning_category_enabled(Category::Deprecated));
+    rb_assert!(ruby, "$warnings.clear; deprecated; $warnings.length == 1");
+    rb_assert!(
+        ruby,
+        r#"$warnings.last[0].end_with?("warning: deprecated is deprecated\n")"#
+    );
+    if ruby.eval::<bool>("RUBY_VERSION >= '3.0'").unwrap() {
+        rb_assert!(ruby, "$warnings.last[1] == :deprecated");
+    }
+
+    rb_assert!(
+        ruby,
+        "$warnings.clear; 3.times { deprecated_once }; $warnings.length == 1"
+    );
+    rb_assert!(ruby, "$warnings.clear; deprecated_once; $warnings.empty?");
+
+    let _: Value = ruby.eval("$VERBOSE = nil").unwrap();
+    rb_assert!(
+        ruby,
+        r#"$warnings.clear; plain("quiet"); deprecated; $warnings.empty?"#
+    );
+}